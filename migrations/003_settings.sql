@@ -0,0 +1,5 @@
+-- Player preferences (key/value, values stored as text)
+CREATE TABLE IF NOT EXISTS settings (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Map-level options (ambient audio, ...) stored as JSON, like placements
+ALTER TABLE maps ADD COLUMN options_json TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,2 @@
+-- Per-slot locks for template/challenge builds, stored as JSON (`SlotLocks`)
+ALTER TABLE builds ADD COLUMN locks_json TEXT NOT NULL DEFAULT '{}';
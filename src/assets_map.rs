@@ -1,5 +1,90 @@
 use bevy::prelude::*;
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Folder the `AssetServer` reads from, resolved the way Bevy's file reader
+/// does: `BEVY_ASSET_ROOT`, else `CARGO_MANIFEST_DIR` (cargo run), else the
+/// executable's directory — not the working directory.
+pub fn assets_root() -> PathBuf {
+    let base = std::env::var_os("BEVY_ASSET_ROOT")
+        .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::current_exe().ok().and_then(|exe| exe.parent().map(PathBuf::from)))
+        .unwrap_or_default();
+    base.join("assets")
+}
+
+/// File on disk behind an asset path such as `"tops/my_top.png"`.
+pub fn asset_file(asset_path: &str) -> PathBuf {
+    assets_root().join(asset_path)
+}
+
+/// Asset path of a part's default image; `dir` is the part folder
+/// ("tops", "weapons", "shafts", "chassis", "screws").
+pub fn part_image_path(dir: &str, id: &str) -> String {
+    format!("{}/{}.png", dir, id)
+}
+
+/// Asset path of a ranged weapon's default projectile image.
+pub fn projectile_image_path(weapon_id: &str) -> String {
+    format!("projectiles/{}_projectile.png", weapon_id)
+}
+
+/// Asset path of a map's looping ambient track.
+pub fn ambient_audio_path(map_id: &str) -> String {
+    format!("audio/ambient/{}.ogg", map_id)
+}
+
+/// Asset path of a weapon's own sound; `prefix` is "hit" or "fire".
+pub fn weapon_sfx_path(prefix: &str, weapon_id: &str) -> String {
+    format!("audio/sfx/{}_{}.ogg", prefix, weapon_id)
+}
+
+/// Copy an external file to `asset_path`, creating its folder.
+pub fn copy_into_assets(from: &std::path::Path, asset_path: &str) -> std::io::Result<()> {
+    let dest = asset_file(asset_path);
+    if let Some(dir) = dest.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::copy(from, dest).map(|_| ())
+}
+
+/// Extensions offered by the part image pickers.
+pub const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp"];
+
+/// Longest side an imported image is scaled down to.
+pub const MAX_IMAGE_DIM: u32 = 1024;
+
+/// Import a picked image as a PNG at `asset_path`. The format is sniffed
+/// from the file contents (a renamed JPG still decodes), and anything larger
+/// than `MAX_IMAGE_DIM` is scaled down keeping its aspect ratio.
+pub fn import_image(from: &std::path::Path, asset_path: &str) -> Result<(), String> {
+    let mut img = image::ImageReader::open(from)
+        .and_then(|r| r.with_guessed_format())
+        .map_err(|e| e.to_string())?
+        .decode()
+        .map_err(|e| e.to_string())?;
+    if img.width() > MAX_IMAGE_DIM || img.height() > MAX_IMAGE_DIM {
+        img = img.resize(MAX_IMAGE_DIM, MAX_IMAGE_DIM, image::imageops::FilterType::Triangle);
+    }
+    let dest = asset_file(asset_path);
+    if let Some(dir) = dest.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    img.to_rgba8()
+        .save_with_format(dest, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())
+}
+
+/// Delete the file behind `asset_path`. A missing file is fine (the part
+/// never had a custom image); other failures are logged.
+pub fn remove_asset_file(asset_path: &str) {
+    match std::fs::remove_file(asset_file(asset_path)) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => warn!("Failed to remove asset '{}': {}", asset_path, e),
+    }
+}
 
 /// Centralized asset handles for all game visuals and audio.
 /// Loaded at startup, read-only during gameplay.
@@ -27,6 +112,8 @@ pub struct SfxHandles {
     pub melee_hit: Handle<AudioSource>,
     pub ranged_fire: Handle<AudioSource>,
     pub projectile_hit: Handle<AudioSource>,
+    /// Played once when a top's spin runs out.
+    pub top_defeated: Handle<AudioSource>,
     /// Per-weapon fire sounds: `audio/sfx/fire_{weapon_id}.ogg`.
     /// Falls back to `ranged_fire` when weapon ID has no entry.
     pub weapon_fire_sfx: HashMap<String, Handle<AudioSource>>,
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Opt-in gameplay diagnostics (zone activations, damage breakdowns, pickups).
+/// Off by default so normal play keeps a clean console; seeded from
+/// `tuning.debug_logging` and toggled at runtime with F8.
+#[derive(Resource, Default)]
+pub struct DebugLogging {
+    pub enabled: bool,
+}
+
+impl DebugLogging {
+    /// Log at info level, only while diagnostics are enabled.
+    /// Call with `format_args!` so nothing is formatted when disabled.
+    pub fn info(&self, args: std::fmt::Arguments) {
+        if self.enabled {
+            info!("{}", args);
+        }
+    }
+}
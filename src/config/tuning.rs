@@ -18,6 +18,9 @@ pub struct Tuning {
     pub spin_drain_on_top_hit: f32,
     pub collision_damage_k: f32,
     pub wall_damage_k: f32,
+    /// Extra wall damage per unit of impact speed on maps with spiked walls.
+    #[serde(default = "default_wall_spike_k")]
+    pub wall_spike_k: f32,
     pub size_damage_k: f32,
     pub size_radius_ref: f32,
     pub max_speed: f32,
@@ -30,8 +33,161 @@ pub struct Tuning {
     pub aim_speed: f32,
     /// Visual spin rate multiplier (velocity → visual rotation speed).
     pub spin_visual_k: f32,
+    /// What projectiles do when they hit a map obstacle (weapons with
+    /// `bounces_off_obstacles` always bounce).
+    #[serde(default)]
+    pub projectile_obstacle: ProjectileObstacleMode,
+    /// Projectiles from different tops destroy each other on contact.
+    #[serde(default = "default_projectiles_cancel")]
+    pub projectiles_cancel: bool,
+    /// Fixed seed for `GameRng`. With the same seed, builds, map and inputs,
+    /// battles play out identically (for reproducing physics bugs).
+    #[serde(default)]
+    pub rng_seed: Option<u64>,
+    /// Enable the F6 per-tick battle readout in release builds.
+    #[serde(default)]
+    pub debug_tools: bool,
+    /// Start with gameplay diagnostics logging on (see `DebugLogging`).
+    #[serde(default)]
+    pub debug_logging: bool,
+    /// Max `power_score` a build may have when fair mode is on.
+    #[serde(default = "default_power_budget")]
+    pub power_budget: f32,
+    /// Last stand (when enabled in Game Setup): the first time a top drops
+    /// below this fraction of max spin HP it gets a temporary buff.
+    #[serde(default = "default_last_stand_hp_frac")]
+    pub last_stand_hp_frac: f32,
+    /// Last stand buff length (seconds).
+    #[serde(default = "default_last_stand_duration")]
+    pub last_stand_duration: f32,
+    /// Last stand movement speed multiplier.
+    #[serde(default = "default_last_stand_speed_mult")]
+    pub last_stand_speed_mult: f32,
+    /// Last stand outgoing damage multiplier.
+    #[serde(default = "default_last_stand_damage_mult")]
+    pub last_stand_damage_mult: f32,
+    /// Width of the band inside the wall (world units) that counts as wall-hugging.
+    #[serde(default = "default_wall_hug_band")]
+    pub wall_hug_band: f32,
+    /// Consecutive ticks in the band before the inward nudge starts.
+    #[serde(default = "default_wall_hug_ticks")]
+    pub wall_hug_ticks: u32,
+    /// Inward acceleration applied to a wall-hugging top. 0 = disabled.
+    #[serde(default = "default_wall_hug_nudge")]
+    pub wall_hug_nudge: f32,
+    /// Outgoing speed multiplier when a top bounces off a bumper (capped at `max_speed`).
+    #[serde(default = "default_bumper_gain")]
+    pub bumper_gain: f32,
+    /// Seconds a speed boost lasts after the top's last contact with the zone.
+    #[serde(default = "default_speed_boost_duration")]
+    pub speed_boost_duration: f32,
+    /// Weapon pickup: ranged fire rate / melee damage multiplier while surging.
+    #[serde(default = "default_weapon_pickup_multiplier")]
+    pub weapon_pickup_multiplier: f32,
+    /// Weapon pickup surge length (seconds).
+    #[serde(default = "default_weapon_pickup_duration")]
+    pub weapon_pickup_duration: f32,
+    /// Seconds before a taken weapon pickup reappears.
+    #[serde(default = "default_weapon_pickup_respawn")]
+    pub weapon_pickup_respawn: f32,
+    /// Spin HP a repair pack restores (clamped to max).
+    #[serde(default = "default_repair_pack_amount")]
+    pub repair_pack_amount: f32,
+    /// Seconds before a taken repair pack reappears.
+    #[serde(default = "default_repair_pack_respawn")]
+    pub repair_pack_respawn: f32,
+    /// How hard AI tops steer during battle, as a fraction of their `accel`
+    /// (0 = launch and coast, 1 = full acceleration toward the target).
+    #[serde(default = "default_ai_aggression")]
+    pub ai_aggression: f32,
+    /// Below this fraction of max spin HP an AI top steers away from its enemy.
+    #[serde(default = "default_ai_retreat_hp_frac")]
+    pub ai_retreat_hp_frac: f32,
+    /// PvAI difficulty settings (see `AiDifficulty`).
+    #[serde(default = "default_ai_easy")]
+    pub ai_easy: AiDifficultyTuning,
+    #[serde(default = "default_ai_medium")]
+    pub ai_medium: AiDifficultyTuning,
+    #[serde(default = "default_ai_hard")]
+    pub ai_hard: AiDifficultyTuning,
+    /// Battle length in seconds before the higher spin HP wins (0 = no limit).
+    #[serde(default = "default_match_time_limit")]
+    pub match_time_limit: f32,
+    /// Sudden death: extra idle spin drain (HP/s) added per second of overtime.
+    #[serde(default = "default_sudden_death_drain_ramp")]
+    pub sudden_death_drain_ramp: f32,
+    /// Seconds an `OnTakeHit` screw's dropped obstacle lasts.
+    #[serde(default = "default_hook_obstacle_ttl")]
+    pub hook_obstacle_ttl: f32,
+    /// Radius of an `OnTakeHit` dropped obstacle (world units).
+    #[serde(default = "default_hook_obstacle_radius")]
+    pub hook_obstacle_radius: f32,
+    /// Minimum seconds between obstacle drops from the same top.
+    #[serde(default = "default_hook_obstacle_cooldown")]
+    pub hook_obstacle_cooldown: f32,
 }
 
+/// How well a PvAI opponent plays at one `AiDifficulty`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiDifficultyTuning {
+    /// Seconds after launch, or after a stun wears off, before steering starts.
+    pub reaction_delay: f32,
+    /// Seconds between refreshes of the remembered target position (0 = every tick).
+    pub retarget_interval: f32,
+    /// Multiplier on `ai_aggression`.
+    pub steer_scale: f32,
+    /// Extra random error (radians, either side) on the launch angle.
+    pub aim_error: f32,
+    /// Whether seeking ranged weapons aim where the target will be.
+    pub leads_shots: bool,
+}
+
+/// Default projectile-vs-obstacle interaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectileObstacleMode {
+    /// Obstacles act as cover: the projectile is destroyed with a spark.
+    #[default]
+    Block,
+    /// Projectiles reflect off obstacles.
+    Bounce,
+    /// Legacy behavior: projectiles ignore obstacles.
+    PassThrough,
+}
+
+fn default_projectiles_cancel() -> bool { true }
+fn default_wall_spike_k() -> f32 { 0.4 }
+fn default_power_budget() -> f32 { 130.0 }
+fn default_last_stand_hp_frac() -> f32 { 0.2 }
+fn default_last_stand_duration() -> f32 { 4.0 }
+fn default_last_stand_speed_mult() -> f32 { 1.3 }
+fn default_last_stand_damage_mult() -> f32 { 1.5 }
+fn default_wall_hug_band() -> f32 { 0.3 }
+fn default_wall_hug_ticks() -> u32 { 30 }
+fn default_wall_hug_nudge() -> f32 { 4.0 }
+fn default_bumper_gain() -> f32 { 1.5 }
+fn default_speed_boost_duration() -> f32 { 3.0 }
+fn default_weapon_pickup_multiplier() -> f32 { 2.0 }
+fn default_weapon_pickup_duration() -> f32 { 4.0 }
+fn default_weapon_pickup_respawn() -> f32 { 8.0 }
+fn default_repair_pack_amount() -> f32 { 20.0 }
+fn default_repair_pack_respawn() -> f32 { 10.0 }
+fn default_ai_aggression() -> f32 { 0.5 }
+fn default_ai_retreat_hp_frac() -> f32 { 0.25 }
+fn default_ai_easy() -> AiDifficultyTuning {
+    AiDifficultyTuning { reaction_delay: 1.0, retarget_interval: 1.0, steer_scale: 0.3, aim_error: 0.5, leads_shots: false }
+}
+fn default_ai_medium() -> AiDifficultyTuning {
+    AiDifficultyTuning { reaction_delay: 0.4, retarget_interval: 0.3, steer_scale: 1.0, aim_error: 0.15, leads_shots: false }
+}
+fn default_ai_hard() -> AiDifficultyTuning {
+    AiDifficultyTuning { reaction_delay: 0.1, retarget_interval: 0.0, steer_scale: 1.6, aim_error: 0.0, leads_shots: true }
+}
+fn default_match_time_limit() -> f32 { 90.0 }
+fn default_sudden_death_drain_ramp() -> f32 { 2.0 }
+fn default_hook_obstacle_ttl() -> f32 { 3.0 }
+fn default_hook_obstacle_radius() -> f32 { 0.25 }
+fn default_hook_obstacle_cooldown() -> f32 { 1.5 }
+
 impl Default for Tuning {
     fn default() -> Self {
         Self {
@@ -48,6 +204,7 @@ impl Default for Tuning {
             spin_drain_on_top_hit: 1.0,
             collision_damage_k: 0.5,
             wall_damage_k: 0.3,
+            wall_spike_k: default_wall_spike_k(),
             size_damage_k: 0.0,
             size_radius_ref: 1.0,
             max_speed: 30.0,
@@ -56,6 +213,36 @@ impl Default for Tuning {
             obstacle_damage: 2.0,
             aim_speed: 3.0,
             spin_visual_k: 2.0,
+            projectile_obstacle: ProjectileObstacleMode::Block,
+            projectiles_cancel: default_projectiles_cancel(),
+            rng_seed: None,
+            debug_tools: false,
+            debug_logging: false,
+            power_budget: default_power_budget(),
+            last_stand_hp_frac: default_last_stand_hp_frac(),
+            last_stand_duration: default_last_stand_duration(),
+            last_stand_speed_mult: default_last_stand_speed_mult(),
+            last_stand_damage_mult: default_last_stand_damage_mult(),
+            wall_hug_band: default_wall_hug_band(),
+            wall_hug_ticks: default_wall_hug_ticks(),
+            wall_hug_nudge: default_wall_hug_nudge(),
+            bumper_gain: default_bumper_gain(),
+            speed_boost_duration: default_speed_boost_duration(),
+            weapon_pickup_multiplier: default_weapon_pickup_multiplier(),
+            weapon_pickup_duration: default_weapon_pickup_duration(),
+            weapon_pickup_respawn: default_weapon_pickup_respawn(),
+            repair_pack_amount: default_repair_pack_amount(),
+            repair_pack_respawn: default_repair_pack_respawn(),
+            ai_aggression: default_ai_aggression(),
+            ai_retreat_hp_frac: default_ai_retreat_hp_frac(),
+            ai_easy: default_ai_easy(),
+            ai_medium: default_ai_medium(),
+            ai_hard: default_ai_hard(),
+            match_time_limit: default_match_time_limit(),
+            sudden_death_drain_ramp: default_sudden_death_drain_ramp(),
+            hook_obstacle_ttl: default_hook_obstacle_ttl(),
+            hook_obstacle_radius: default_hook_obstacle_radius(),
+            hook_obstacle_cooldown: default_hook_obstacle_cooldown(),
         }
     }
 }
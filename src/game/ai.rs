@@ -0,0 +1,205 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::rng::GameRng;
+use crate::config::tuning::{AiDifficultyTuning, Tuning};
+
+/// Profile used when none is selected or the selected one is gone.
+pub const DEFAULT_AI_PROFILE_ID: &str = "wild";
+
+/// How well a PvAI opponent plays, on top of its personality. The numbers
+/// behind each level live in `Tuning` (`ai_easy` / `ai_medium` / `ai_hard`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AiDifficulty {
+    /// Slow to react and barely tracks the player.
+    Easy,
+    #[default]
+    Medium,
+    /// Reacts fast, re-targets every tick and leads its ranged shots.
+    Hard,
+}
+
+impl AiDifficulty {
+    pub const ALL: [AiDifficulty; 3] = [AiDifficulty::Easy, AiDifficulty::Medium, AiDifficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AiDifficulty::Easy => "Easy",
+            AiDifficulty::Medium => "Medium",
+            AiDifficulty::Hard => "Hard",
+        }
+    }
+
+    /// Settings value (`UserSettings.ai_difficulty`).
+    pub fn key(self) -> &'static str {
+        match self {
+            AiDifficulty::Easy => "easy",
+            AiDifficulty::Medium => "medium",
+            AiDifficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL.into_iter().find(|d| d.key() == key).unwrap_or_default()
+    }
+
+    /// This difficulty's settings from `tuning.ron`.
+    pub fn tuning(self, tuning: &Tuning) -> &AiDifficultyTuning {
+        match self {
+            AiDifficulty::Easy => &tuning.ai_easy,
+            AiDifficulty::Medium => &tuning.ai_medium,
+            AiDifficulty::Hard => &tuning.ai_hard,
+        }
+    }
+}
+
+/// Angle to fire a projectile of `speed` from `pos` so it meets a target at
+/// `target` moving with `target_vel`; the direct angle if it can't catch up.
+pub fn lead_angle(pos: Vec2, target: Vec2, target_vel: Vec2, speed: f32) -> f32 {
+    let to_target = target - pos;
+    // |to_target + target_vel * t| = speed * t
+    let a = target_vel.length_squared() - speed * speed;
+    let b = 2.0 * to_target.dot(target_vel);
+    let c = to_target.length_squared();
+    let t = if a.abs() < 1e-6 {
+        (b.abs() > 1e-6).then(|| -c / b)
+    } else {
+        let disc = b * b - 4.0 * a * c;
+        (disc >= 0.0).then(|| {
+            let root = disc.sqrt();
+            let (t1, t2) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+            if t1 > 0.0 && (t1 < t2 || t2 <= 0.0) { t1 } else { t2 }
+        })
+    };
+    match t.filter(|t| *t > 0.0) {
+        Some(t) => (to_target + target_vel * t).to_angle(),
+        None => to_target.to_angle(),
+    }
+}
+
+/// Personality of a PvAI opponent. Weights are in `[0, 1]`.
+///
+/// A profile shapes where the AI launches and when its ranged weapon fires;
+/// in-battle steering (`physics::ai_battle_steer`) is set by `Tuning::ai_aggression`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiProfile {
+    pub id: String,
+    pub name: String,
+    /// How directly the launch is aimed at the opponent (0 = any direction).
+    pub aggression: f32,
+    /// How far the launch veers off the line to the opponent (1 = a 90° flank).
+    pub evasion: f32,
+    /// How closely a ranged weapon must face the opponent before firing
+    /// (0 = fire whenever the weapon is ready).
+    pub fire_discipline: f32,
+}
+
+impl Default for AiProfile {
+    /// The original behavior: random launch, fire whenever ready.
+    fn default() -> Self {
+        Self::new(DEFAULT_AI_PROFILE_ID, "Wild", 0.0, 0.0, 0.0)
+    }
+}
+
+impl AiProfile {
+    pub fn new(id: &str, name: &str, aggression: f32, evasion: f32, fire_discipline: f32) -> Self {
+        Self { id: id.into(), name: name.into(), aggression, evasion, fire_discipline }
+    }
+
+    /// Built-in roster.
+    pub fn presets() -> Vec<AiProfile> {
+        vec![
+            Self::default(),
+            Self::new("brawler", "Brawler", 0.9, 0.0, 0.1),
+            Self::new("skirmisher", "Skirmisher", 0.6, 0.7, 0.5),
+            Self::new("sniper", "Sniper", 0.5, 0.4, 0.9),
+        ]
+    }
+
+    /// Launch direction given the angle toward the opponent.
+    pub fn launch_angle(&self, toward: f32, rng: &mut GameRng) -> f32 {
+        let side = if rng.next_f32() < 0.5 { -1.0 } else { 1.0 };
+        let veer = side * self.evasion.clamp(0.0, 1.0) * FRAC_PI_2;
+        let jitter = rng.range_f32(-PI, PI) * (1.0 - self.aggression.clamp(0.0, 1.0));
+        toward + veer + jitter
+    }
+
+    /// Whether a ready ranged weapon aimed along `aim` should wait: true while
+    /// the target lies outside the profile's firing cone.
+    pub fn holds_fire(&self, pos: Vec2, aim: f32, target: Option<Vec2>) -> bool {
+        let Some(target) = target else {
+            return false;
+        };
+        let cone = PI * (1.0 - self.fire_discipline.clamp(0.0, 1.0)).max(0.05);
+        let to_target = target - pos;
+        let off = Vec2::from_angle(aim).angle_to(to_target).abs();
+        off > cone
+    }
+
+    /// Optional user roster next to `tuning.ron`.
+    pub fn file_path() -> PathBuf {
+        Tuning::data_dir().join("ai_profiles.ron")
+    }
+
+    /// Profiles from `ai_profiles.ron` (a list of `AiProfile`), empty if the
+    /// file is missing or invalid.
+    pub fn load_file() -> Vec<AiProfile> {
+        let path = Self::file_path();
+        if !path.exists() {
+            return Vec::new();
+        }
+        match std::fs::read_to_string(&path).map(|s| ron::from_str::<Vec<AiProfile>>(&s)) {
+            Ok(Ok(profiles)) => profiles,
+            Ok(Err(e)) => {
+                warn!("Failed to parse ai_profiles.ron: {e}");
+                Vec::new()
+            }
+            Err(e) => {
+                warn!("Failed to read ai_profiles.ron: {e}");
+                Vec::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn angles(seed: u64) -> Vec<u32> {
+        let profile = AiProfile::presets()[2].clone();
+        let mut rng = GameRng::from_seed(seed);
+        (0..8).map(|_| profile.launch_angle(0.5, &mut rng).to_bits()).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_same_launch_angles() {
+        assert_eq!(angles(7), angles(7));
+        assert_ne!(angles(7), angles(8));
+    }
+
+    #[test]
+    fn lead_angle_hits_stationary_target_directly() {
+        let angle = lead_angle(Vec2::ZERO, Vec2::new(0.0, 5.0), Vec2::ZERO, 10.0);
+        assert!((angle - FRAC_PI_2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn lead_angle_meets_crossing_target() {
+        let (pos, target, target_vel, speed) = (Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(0.0, 5.0), 10.0);
+        let angle = lead_angle(pos, target, target_vel, speed);
+        // |(10, 5t)| = 10t  =>  t = sqrt(100 / 75)
+        let t = (100.0f32 / 75.0).sqrt();
+        let shot = pos + Vec2::from_angle(angle) * speed * t;
+        assert!(shot.distance(target + target_vel * t) < 1e-3);
+    }
+
+    #[test]
+    fn lead_angle_falls_back_to_direct_when_target_outruns_shot() {
+        let angle = lead_angle(Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(20.0, 0.0), 10.0);
+        assert!(angle.abs() < 1e-5);
+    }
+}
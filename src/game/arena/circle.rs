@@ -4,18 +4,19 @@ use crate::config::tuning::Tuning;
 use crate::game::components::*;
 use crate::game::events::GameEvent;
 
-/// Despawn projectiles that leave the arena boundary.
+/// Return projectiles that leave the arena boundary to the pool.
 pub fn despawn_projectiles_outside_arena(
     mut commands: Commands,
     tuning: Res<Tuning>,
     arena_r_res: Option<Res<ArenaRadius>>,
+    mut pool: ResMut<ProjectilePool>,
     query: Query<(Entity, &Transform, &CollisionRadius), With<ProjectileMarker>>,
 ) {
     let arena_r = arena_r_res.map(|r| r.0).unwrap_or(tuning.arena_radius);
     for (entity, transform, radius) in &query {
         let pos = transform.translation.truncate();
         if pos.length() > arena_r + radius.0 {
-            commands.entity(entity).despawn();
+            pool.release(&mut commands, entity);
         }
     }
 }
@@ -25,9 +26,11 @@ pub fn despawn_projectiles_outside_arena(
 pub fn wall_reflection(
     tuning: Res<Tuning>,
     arena_r_res: Option<Res<ArenaRadius>>,
+    hazards: Option<Res<ArenaHazards>>,
     mut query: Query<(Entity, &mut Transform, &mut Velocity, &TopEffectiveStats), With<Top>>,
     mut events: MessageWriter<GameEvent>,
 ) {
+    let spikes = hazards.is_some_and(|h| h.wall_spikes);
     let arena_r = arena_r_res.map(|r| r.0).unwrap_or(tuning.arena_radius);
     let damping = tuning.wall_bounce_damping.clamp(0.0, 1.0);
 
@@ -51,9 +54,13 @@ pub fn wall_reflection(
                 vel.0 -= 2.0 * dot * normal;
                 vel.0 *= damping;
 
-                // Generate wall damage event (fixed amount, not speed-scaled)
-                if tuning.wall_damage_k > 0.0 {
-                    let wall_dmg = tuning.wall_damage_k;
+                // Generate wall damage event: fixed amount, plus an impact-speed
+                // term when the map has a spiked wall
+                let mut wall_dmg = tuning.wall_damage_k;
+                if spikes {
+                    wall_dmg += tuning.wall_spike_k * dot;
+                }
+                if wall_dmg > 0.0 {
                     events.write(GameEvent::DealDamage {
                         src: None,
                         dst: entity,
@@ -68,3 +75,32 @@ pub fn wall_reflection(
         }
     }
 }
+
+/// Nudge tops that keep sliding along the wall back toward the interior.
+/// Only kicks in after `tuning.wall_hug_ticks` consecutive ticks within
+/// `tuning.wall_hug_band` of the boundary, so ordinary bounces are untouched.
+pub fn wall_hug_nudge(
+    tuning: Res<Tuning>,
+    arena_r_res: Option<Res<ArenaRadius>>,
+    mut query: Query<(&Transform, &mut Velocity, &TopEffectiveStats, &mut WallHugTicks), With<Top>>,
+) {
+    let arena_r = arena_r_res.map(|r| r.0).unwrap_or(tuning.arena_radius);
+
+    for (transform, mut vel, stats, mut ticks) in &mut query {
+        let pos = transform.translation.truncate();
+        let dist = pos.length();
+        let boundary = arena_r - stats.0.radius.0;
+
+        if dist <= 0.0 || dist < boundary - tuning.wall_hug_band {
+            ticks.0 = 0;
+            continue;
+        }
+        ticks.0 = ticks.0.saturating_add(1);
+        if ticks.0 < tuning.wall_hug_ticks {
+            continue;
+        }
+
+        let inward = -pos / dist;
+        vel.0 += inward * tuning.wall_hug_nudge * tuning.dt;
+    }
+}
@@ -1,20 +1,29 @@
 use bevy::prelude::*;
 
+use crate::config::logging::DebugLogging;
 use crate::game::components::*;
+use crate::config::tuning::{ProjectileObstacleMode, Tuning};
 use crate::game::events::GameEvent;
+use crate::game::spatial::{GridHits, ObstacleGrid};
+use crate::game::stats::types::CollisionBehavior;
 
 /// Bounce tops off static obstacles (elastic reflection + push-out physics only).
+/// Bumpers also scale the outgoing speed by `bumper_gain`, up to `max_speed`.
 /// Damage is handled by detect_collisions via ObstacleMarker/DamageOnHit.
 /// Runs in PhysicsSet so it can mutate Transform/Velocity.
 pub fn static_obstacle_bounce(
+    tuning: Res<Tuning>,
+    grid: Res<ObstacleGrid>,
+    mut hits: Local<GridHits>,
     mut tops: Query<(&mut Transform, &mut Velocity, &TopEffectiveStats), With<Top>>,
-    obstacles: Query<(&Transform, &CollisionRadius), (With<StaticObstacle>, Without<Top>)>,
+    obstacles: Query<(&Transform, &CollisionRadius, Option<&ObstacleBehavior>), (With<StaticObstacle>, Without<Top>)>,
 ) {
     for (mut top_tf, mut vel, stats) in &mut tops {
         let top_pos = top_tf.translation.truncate();
         let top_radius = stats.0.radius.0;
 
-        for (obs_tf, obs_radius) in &obstacles {
+        grid.query(top_pos, top_radius, &mut hits);
+        for (obs_tf, obs_radius, behavior) in obstacles.iter_many(hits.entities()) {
             let obs_pos = obs_tf.translation.truncate();
             let dist = top_pos.distance(obs_pos);
             let min_dist = top_radius + obs_radius.0;
@@ -31,17 +40,140 @@ pub fn static_obstacle_bounce(
                 let dot = vel.0.dot(-normal);
                 if dot > 0.0 {
                     vel.0 = vel.0 - 2.0 * vel.0.dot(-normal) * (-normal);
+                    if behavior.is_some_and(|b| b.0 == CollisionBehavior::Bumper) {
+                        vel.0 = (vel.0 * tuning.bumper_gain).clamp_length_max(tuning.max_speed);
+                    }
                 }
             }
         }
     }
 }
 
+/// Resolve projectile contact with static map obstacles.
+/// Bouncing projectiles (weapon attribute or `ProjectileObstacleMode::Bounce`) reflect
+/// and re-face their travel direction; blocked ones get a small spark and a
+/// `DespawnEntity` event, so `handle_despawn_events` returns them to the pool.
+/// Obstacles a top dropped itself never stop that top's own shots.
+/// Runs in PhysicsSet after `integrate_projectiles`.
+pub fn projectile_obstacle_contact(
+    mut commands: Commands,
+    tuning: Res<Tuning>,
+    grid: Res<ObstacleGrid>,
+    mut hits: Local<GridHits>,
+    mut events: MessageWriter<GameEvent>,
+    mut projectiles: Query<
+        (Entity, &mut Transform, &mut Velocity, &CollisionRadius, &ProjectileOwner, Has<ProjectileBounce>),
+        With<ProjectileMarker>,
+    >,
+    obstacles: Query<
+        (&Transform, &CollisionRadius, Option<&ObstacleDroppedBy>),
+        (With<StaticObstacle>, Without<ProjectileMarker>),
+    >,
+) {
+    for (entity, mut tf, mut vel, radius, owner, weapon_bounce) in &mut projectiles {
+        let bounce = weapon_bounce || tuning.projectile_obstacle == ProjectileObstacleMode::Bounce;
+        if !bounce && tuning.projectile_obstacle == ProjectileObstacleMode::PassThrough {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+
+        grid.query(pos, radius.0, &mut hits);
+        for (obs_tf, obs_radius, dropped_by) in obstacles.iter_many(hits.entities()) {
+            if dropped_by.is_some_and(|d| d.0 == owner.0) {
+                continue;
+            }
+            let obs_pos = obs_tf.translation.truncate();
+            let dist = pos.distance(obs_pos);
+            let min_dist = radius.0 + obs_radius.0;
+            if dist >= min_dist || dist <= 0.0 {
+                continue;
+            }
+
+            let normal = (pos - obs_pos) / dist;
+            if bounce {
+                tf.translation += (normal * (min_dist - dist)).extend(0.0);
+                if vel.0.dot(normal) < 0.0 {
+                    let along = vel.0.dot(normal);
+                    vel.0 -= 2.0 * along * normal;
+                    tf.rotation = Quat::from_rotation_z(vel.0.y.atan2(vel.0.x));
+                }
+            } else {
+                spawn_spark(&mut commands, obs_pos + normal * obs_radius.0, normal);
+                events.write(GameEvent::DespawnEntity { entity });
+            }
+            break;
+        }
+    }
+}
+
+/// A few quick shards fanning out from a blocked projectile.
+fn spawn_spark(commands: &mut Commands, pos: Vec2, normal: Vec2) {
+    const SHARDS: usize = 5;
+    let base = normal.y.atan2(normal.x);
+    for i in 0..SHARDS {
+        let angle = base + (i as f32 / (SHARDS - 1) as f32 - 0.5) * 1.6;
+        let dir = Vec2::new(angle.cos(), angle.sin());
+        commands.spawn((
+            InGame,
+            BurstParticle { velocity: dir * 5.0, ttl: BurstParticle::TTL * 0.4 },
+            Sprite {
+                color: Color::srgb(1.0, 0.95, 0.6),
+                custom_size: Some(Vec2::splat(0.15)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(2.0)),
+        ));
+    }
+}
+
+/// Spawn temporary obstacles from SpawnObstacle events. They bounce tops like
+/// map obstacles (`StaticObstacle`) and are despawned by `cleanup_ttl`.
+/// Without `ObstacleAssets` (headless runs) they get no visual.
+pub fn spawn_obstacles(
+    mut commands: Commands,
+    time: Res<Time>,
+    log: Res<DebugLogging>,
+    obstacle_assets: Option<Res<ObstacleAssets>>,
+    mut events: MessageReader<GameEvent>,
+) {
+    let now = time.elapsed_secs_f64();
+    for event in events.read() {
+        if let GameEvent::SpawnObstacle { src, position, radius, ttl, behavior } = event {
+            let mut entity = commands.spawn((
+                InGame,
+                ObstacleMarker,
+                StaticObstacle,
+                ObstacleOwner,
+                ObstacleDroppedBy(*src),
+                CollisionRadius(*radius),
+                ObstacleBehavior(*behavior),
+                ExpiresAt(now + *ttl as f64),
+                Transform::from_translation(position.extend(0.0)),
+            ));
+            if let Some(assets) = &obstacle_assets {
+                entity.insert((
+                    Sprite {
+                        image: assets.image.clone(),
+                        color: assets.color,
+                        custom_size: Some(Vec2::splat(*radius * 2.0)),
+                        ..default()
+                    },
+                    Visibility::Visible,
+                ));
+            }
+            log.info(format_args!("[Hook] {:?} dropped an obstacle at {:?} for {:.1}s", src, position, ttl));
+        }
+    }
+}
+
 /// Spawn projectile entities from SpawnProjectile events (with visible mesh or sprite).
+/// Pooled entities are reactivated first; a new entity is spawned only when the pool is empty.
+/// Without `ProjectileAssets` (headless runs) projectiles get no visual.
 pub fn spawn_projectiles(
     mut commands: Commands,
     mut events: MessageReader<GameEvent>,
-    proj_assets: Res<ProjectileAssets>,
+    proj_assets: Option<Res<ProjectileAssets>>,
+    mut pool: ResMut<ProjectilePool>,
 ) {
     for event in events.read() {
         if let GameEvent::SpawnProjectile {
@@ -55,6 +187,7 @@ pub fn spawn_projectiles(
             weapon_id,
             visual_len,
             visual_thick,
+            bounce,
         } = event
         {
             // Rotate projectile sprite to face its travel direction.
@@ -62,17 +195,35 @@ pub fn spawn_projectiles(
             let tf = Transform::from_translation(Vec3::new(position.x, position.y, 0.5))
                 .with_rotation(Quat::from_rotation_z(travel_angle));
 
-            let mut entity = commands.spawn((
+            let mut entity = match pool.take() {
+                Some(pooled) => {
+                    let mut entity = commands.entity(pooled);
+                    entity.remove::<PooledProjectile>();
+                    entity
+                }
+                None => commands.spawn(InGame),
+            };
+            entity.insert((
                 ProjectileMarker,
                 Velocity(*direction * *speed),
                 CollisionRadius(*radius),
                 ProjectileOwner(*src),
                 ProjectileDamage(*damage),
                 Lifetime(crate::game::stats::types::Seconds(*lifetime)),
+                ProjectileTrail::default(),
+                Visibility::Visible,
             ));
+            if *bounce {
+                entity.insert(ProjectileBounce);
+            }
 
+            let Some(proj_assets) = proj_assets.as_deref() else {
+                entity.insert(tf);
+                continue;
+            };
+            // A reused entity may carry the other visual from its last shot
             if let Some(sprite_handle) = proj_assets.sprites.get(weapon_id) {
-                entity.insert((
+                entity.remove::<(Mesh2d, MeshMaterial2d<ColorMaterial>)>().insert((
                     Sprite {
                         image: sprite_handle.clone(),
                         custom_size: Some(Vec2::new(*visual_len, *visual_thick)),
@@ -81,7 +232,7 @@ pub fn spawn_projectiles(
                     tf,
                 ));
             } else {
-                entity.insert((
+                entity.remove::<Sprite>().insert((
                     Mesh2d(proj_assets.mesh.clone()),
                     MeshMaterial2d(proj_assets.material.clone()),
                     tf.with_scale(Vec3::splat(*radius)),
@@ -91,10 +242,11 @@ pub fn spawn_projectiles(
     }
 }
 
-/// CleanupSet: despawn obstacles and projectiles that have expired.
+/// CleanupSet: despawn expired obstacles; return expired projectiles to the pool.
 pub fn cleanup_ttl(
     mut commands: Commands,
     time: Res<Time>,
+    mut pool: ResMut<ProjectilePool>,
     obstacles: Query<(Entity, &ExpiresAt), With<ObstacleMarker>>,
     projectiles: Query<(Entity, &Lifetime), With<ProjectileMarker>>,
 ) {
@@ -108,16 +260,64 @@ pub fn cleanup_ttl(
 
     for (entity, lifetime) in &projectiles {
         if lifetime.0.is_expired() {
-            commands.entity(entity).despawn();
+            pool.release(&mut commands, entity);
         }
     }
 }
 
-/// Handle DespawnEntity events.
-pub fn handle_despawn_events(mut commands: Commands, mut events: MessageReader<GameEvent>) {
+/// Handle DespawnEntity events. Projectiles go back to the pool; ones that
+/// were already released (e.g. expired this tick) are left alone, since
+/// despawning them would leave a dead entity in the pool.
+pub fn handle_despawn_events(
+    mut commands: Commands,
+    mut events: MessageReader<GameEvent>,
+    mut pool: ResMut<ProjectilePool>,
+    projectiles: Query<(), With<ProjectileMarker>>,
+    pooled: Query<(), With<PooledProjectile>>,
+) {
     for event in events.read() {
         if let GameEvent::DespawnEntity { entity } = event {
-            commands.entity(*entity).try_despawn();
+            if pool.is_pending(*entity) || pooled.contains(*entity) {
+                continue;
+            }
+            if projectiles.contains(*entity) {
+                pool.release(&mut commands, *entity);
+            } else {
+                commands.entity(*entity).try_despawn();
+            }
         }
     }
 }
+
+/// End of CleanupSet: this tick's released projectiles become reusable.
+pub fn recycle_projectiles(mut commands: Commands, mut pool: ResMut<ProjectilePool>) {
+    pool.recycle(&mut commands);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::stats::types::Seconds;
+
+    #[test]
+    fn despawn_of_released_projectile_keeps_it_pooled() {
+        let mut app = App::new();
+        app.add_message::<GameEvent>()
+            .init_resource::<Time>()
+            .init_resource::<ProjectilePool>()
+            .add_systems(Update, (cleanup_ttl, handle_despawn_events, recycle_projectiles).chain());
+
+        // Expires this tick and is hit in the same tick
+        let world = app.world_mut();
+        let projectile = world.spawn((ProjectileMarker, Lifetime(Seconds(0.0)))).id();
+        world.write_message(GameEvent::DespawnEntity { entity: projectile });
+
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.get::<PooledProjectile>(projectile).is_some(), "projectile should be parked, not despawned");
+        let mut pool = world.resource_mut::<ProjectilePool>();
+        assert_eq!(pool.take(), Some(projectile));
+        assert_eq!(pool.take(), None, "projectile must be pooled exactly once");
+    }
+}
@@ -2,12 +2,27 @@ use bevy::prelude::*;
 
 use super::components::*;
 use super::events::{CollisionMessage, GameEvent};
+use super::spatial::{GridHits, ObstacleGrid, SpatialGrid};
 use super::stats::types::DamageKind;
 use crate::config::tuning::Tuning;
 
-/// Collision detection: Top–Top, Top–Wall, Top–Obstacle, Projectile–Top.
+/// Broad-phase grids of tops and projectiles, rebuilt on every run of
+/// `detect_collisions` but kept in a `Local` so cells stay allocated.
+#[derive(Default)]
+pub struct CollisionGrids {
+    tops: SpatialGrid,
+    projectiles: SpatialGrid,
+    hits: GridHits,
+}
+
+/// Collision detection: Top–Top, Top–Wall, Top–Obstacle, Projectile–Top,
+/// Projectile–Projectile. (Projectile–Obstacle is `projectile_obstacle_contact`.)
+/// Pairs are found through spatial grids and visited in the same order as a
+/// full scan, so the emitted events are unchanged.
 pub fn detect_collisions(
     tuning: Res<Tuning>,
+    grid: Res<ObstacleGrid>,
+    mut grids: Local<CollisionGrids>,
     tops: Query<(Entity, &Transform, &Velocity, &TopEffectiveStats), With<Top>>,
     obstacles: Query<
         (Entity, &Transform, &CollisionRadius, &ObstacleBehavior),
@@ -20,12 +35,18 @@ pub fn detect_collisions(
     mut collision_events: MessageWriter<CollisionMessage>,
     mut events: MessageWriter<GameEvent>,
 ) {
+    let CollisionGrids { tops: top_grid, projectiles: proj_grid, hits } = &mut *grids;
     let top_list: Vec<_> = tops.iter().collect();
+    top_grid.clear();
+    for (entity, tf, _, stats) in &top_list {
+        top_grid.insert(*entity, tf.translation.truncate(), stats.0.radius.0);
+    }
 
     // Top–Top collisions
     for i in 0..top_list.len() {
-        for j in (i + 1)..top_list.len() {
-            let (e_a, tf_a, vel_a, stats_a) = &top_list[i];
+        let (e_a, tf_a, vel_a, stats_a) = &top_list[i];
+        top_grid.query(tf_a.translation.truncate(), stats_a.0.radius.0, hits);
+        for j in hits.indices().filter(|&j| j > i) {
             let (e_b, tf_b, vel_b, stats_b) = &top_list[j];
 
             let pos_a = tf_a.translation.truncate();
@@ -54,8 +75,9 @@ pub fn detect_collisions(
 
         // Top–Obstacle collisions
         let (entity, tf, _vel, stats) = &top_list[i];
-        for (obs_entity, obs_tf, obs_radius, obs_behavior) in &obstacles {
-            let pos_top = tf.translation.truncate();
+        let pos_top = tf.translation.truncate();
+        grid.query(pos_top, stats.0.radius.0, hits);
+        for (obs_entity, obs_tf, obs_radius, obs_behavior) in obstacles.iter_many(hits.entities()) {
             let pos_obs = obs_tf.translation.truncate();
             let dist = pos_top.distance(pos_obs);
             let min_dist = stats.0.radius.0 + obs_radius.0;
@@ -80,7 +102,8 @@ pub fn detect_collisions(
     for (proj_entity, proj_tf, proj_radius, proj_owner, proj_dmg) in &projectiles {
         let proj_pos = proj_tf.translation.truncate();
 
-        for (top_entity, top_tf, _, top_stats) in &top_list {
+        top_grid.query(proj_pos, proj_radius.0, hits);
+        for (top_entity, top_tf, _, top_stats) in hits.indices().map(|j| &top_list[j]) {
             // Don't hit owner
             if *top_entity == proj_owner.0 {
                 continue;
@@ -103,4 +126,81 @@ pub fn detect_collisions(
             }
         }
     }
+
+    // Projectile–Projectile: shots from different tops cancel each other out
+    if !tuning.projectiles_cancel {
+        return;
+    }
+    let proj_list: Vec<_> = projectiles.iter().collect();
+    proj_grid.clear();
+    for (entity, tf, radius, ..) in &proj_list {
+        proj_grid.insert(*entity, tf.translation.truncate(), radius.0);
+    }
+    for i in 0..proj_list.len() {
+        let (e_a, tf_a, radius_a, owner_a, _) = proj_list[i];
+        proj_grid.query(tf_a.translation.truncate(), radius_a.0, hits);
+        for j in hits.indices().filter(|&j| j > i) {
+            let (e_b, tf_b, radius_b, owner_b, _) = proj_list[j];
+            if owner_a.0 == owner_b.0 {
+                continue;
+            }
+            let dist = tf_a.translation.truncate().distance(tf_b.translation.truncate());
+            if dist < radius_a.0 + radius_b.0 {
+                events.write(GameEvent::DespawnEntity { entity: e_a });
+                events.write(GameEvent::DespawnEntity { entity: e_b });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projectile(world: &mut World, owner: Entity, x: f32) -> Entity {
+        world
+            .spawn((
+                ProjectileMarker,
+                Transform::from_xyz(x, 0.0, 0.5),
+                CollisionRadius(0.1),
+                ProjectileOwner(owner),
+                ProjectileDamage(1.0),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn projectiles_from_different_tops_cancel() {
+        let mut app = App::new();
+        app.add_message::<GameEvent>()
+            .add_message::<CollisionMessage>()
+            .init_resource::<Tuning>()
+            .init_resource::<ObstacleGrid>()
+            .add_systems(Update, detect_collisions);
+
+        let world = app.world_mut();
+        let (p1, p2) = (world.spawn_empty().id(), world.spawn_empty().id());
+        let a = projectile(world, p1, 0.0);
+        let b = projectile(world, p2, 0.15);
+        // Overlaps both `a` (same owner, so no cancel) and `b`
+        let c = projectile(world, p1, 0.05);
+        let far = projectile(world, p2, 5.0);
+
+        app.update();
+
+        let mut despawned: Vec<Entity> = app
+            .world()
+            .resource::<Messages<GameEvent>>()
+            .iter_current_update_messages()
+            .filter_map(|event| match event {
+                GameEvent::DespawnEntity { entity } => Some(*entity),
+                _ => None,
+            })
+            .collect();
+        despawned.sort();
+        let mut expected = vec![a, b, b, c];
+        expected.sort();
+        assert_eq!(despawned, expected);
+        assert!(!despawned.contains(&far));
+    }
 }
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 
 use super::components::*;
-use super::events::{CollisionMessage, GameEvent};
+use super::events::{CollisionMessage, DamageTakenMessage, GameEvent};
 use super::stats::types::DamageKind;
+use crate::config::logging::DebugLogging;
 use crate::config::tuning::Tuning;
 
 /// EventGenerateSet: convert collisions into DealDamage events (base damage only).
@@ -30,41 +31,69 @@ pub fn generate_collision_damage(
     }
 }
 
-/// EventApplySet: apply DealDamage events to SpinHp.
+/// EventApplySet: apply DealDamage events to SpinHp and report the HP each
+/// top actually lost as `DamageTakenMessage`.
 pub fn apply_damage_events(
+    log: Res<DebugLogging>,
     mut events: MessageReader<GameEvent>,
-    mut tops: Query<(&mut SpinHpCurrent, &TopEffectiveStats, &DamageBoostActive), With<Top>>,
+    mut taken: MessageWriter<DamageTakenMessage>,
+    mut tops: Query<(&mut SpinHpCurrent, &TopEffectiveStats, &DamageBoostActive, &WeaponSurgeEffect), With<Top>>,
 ) {
     for event in events.read() {
         if let GameEvent::DealDamage {
             src,
             dst,
             amount,
-            kind: _,
+            kind,
         } = event
         {
             let mut amount = *amount;
 
-            // Apply source damage output multiplier + damage boost zone
+            // Apply source damage output multiplier + damage boost zone (+ melee surge)
             if let Some(src_entity) = src {
-                if let Ok((_, src_stats, dmg_boost)) = tops.get(*src_entity) {
+                if let Ok((_, src_stats, dmg_boost, surge)) = tops.get(*src_entity) {
                     let before = amount;
                     amount *= src_stats.0.damage_out_mult.0;
                     amount *= dmg_boost.multiplier;
+                    if *kind == DamageKind::Melee {
+                        amount *= surge.multiplier;
+                    }
                     if dmg_boost.multiplier > 1.001 {
-                        info!(
+                        log.info(format_args!(
                             "[DamageBoost] base={:.2} * out_mult={:.2} * boost={:.2} = {:.2}",
                             before, src_stats.0.damage_out_mult.0, dmg_boost.multiplier, amount
-                        );
+                        ));
                     }
                 }
             }
 
-            // Apply destination damage intake multiplier
-            if let Ok((mut spin, dst_stats, _)) = tops.get_mut(*dst) {
+            // Apply destination damage intake multiplier (overall, then for this kind)
+            if let Ok((mut spin, dst_stats, _, _)) = tops.get_mut(*dst) {
                 amount *= dst_stats.0.damage_in_mult.0;
+                amount *= dst_stats.0.damage_in_by_kind.get(*kind).0;
                 amount = amount.max(0.0);
+                let before = spin.0 .0;
                 spin.0 = spin.0.sub_clamped(amount);
+                let lost = before - spin.0 .0;
+                if lost > 0.0 {
+                    taken.write(DamageTakenMessage { dst: *dst, amount: lost, kind: *kind });
+                }
+            }
+        }
+    }
+}
+
+/// EventApplySet: apply Heal events to SpinHp. A top already at 0 stays down.
+pub fn apply_heal_events(
+    mut events: MessageReader<GameEvent>,
+    mut tops: Query<(&mut SpinHpCurrent, &TopEffectiveStats), With<Top>>,
+) {
+    for event in events.read() {
+        if let GameEvent::Heal { dst, amount } = event {
+            if let Ok((mut spin, stats)) = tops.get_mut(*dst) {
+                if spin.0 .0 > 0.0 {
+                    spin.0 = spin.0.add_clamped(*amount, stats.0.spin_hp_max);
+                }
             }
         }
     }
@@ -73,12 +102,14 @@ pub fn apply_damage_events(
 /// EventApplySet: apply control effects.
 pub fn apply_control_events(
     mut events: MessageReader<GameEvent>,
-    mut tops: Query<(&mut ControlState, &TopEffectiveStats), With<Top>>,
+    mut tops: Query<(&mut ControlState, &TopEffectiveStats, &Transform), With<Top>>,
 ) {
     for event in events.read() {
-        if let GameEvent::ApplyControl { dst, control } = event {
-            if let Ok((mut ctrl_state, stats)) = tops.get_mut(*dst) {
-                ctrl_state.apply_control(*control, stats.0.control_multiplier);
+        if let GameEvent::ApplyControl { src, dst, control } = event {
+            let src_pos = src.and_then(|src| tops.get(src).ok()).map(|(_, _, tf)| tf.translation.truncate());
+            if let Ok((mut ctrl_state, stats, tf)) = tops.get_mut(*dst) {
+                let away = src_pos.map_or(Vec2::ZERO, |p| (tf.translation.truncate() - p).normalize_or_zero());
+                ctrl_state.apply_control(*control, stats.0.control_multiplier, stats.0.stun_cap, away);
             }
         }
     }
@@ -156,12 +187,17 @@ pub fn fire_ranged_weapons(
             &TopEffectiveStats,
             &mut RangedFireTimer,
             Option<&super::components::WeaponAimAngle>,
+            &WeaponSurgeEffect,
+            Option<&AiBehavior>,
+            Option<&AiSteer>,
         ),
-        With<Top>,
+        // Ghost tops fire on their recorded schedule instead
+        (With<Top>, Without<GhostTop>),
     >,
+    targets: Query<(Entity, &Transform, &Velocity), With<Top>>,
     mut events: MessageWriter<GameEvent>,
 ) {
-    for (entity, transform, angle, build, stats, mut timer, aim_angle) in &mut query {
+    for (entity, transform, angle, build, stats, mut timer, aim_angle, surge, behavior, steer) in &mut query {
         timer.0 -= tuning.dt;
 
         if timer.0 > 0.0 {
@@ -169,35 +205,56 @@ pub fn fire_ranged_weapons(
         }
 
         if let Some(ranged) = &build.0.weapon.ranged {
-            let fire_rate = ranged.fire_rate * stats.0.fire_rate_mult.0;
-            timer.0 = 1.0 / fire_rate.max(0.1);
-
             let pos = transform.translation.truncate();
-            let wid = build.0.weapon.id.clone();
-            let (vis_len, vis_thick) = build.0.weapon.projectile_dims();
 
             // Choose base fire direction based on aim mode.
+            let nearest = || {
+                targets
+                    .iter()
+                    .filter(|(e, _, _)| *e != entity)
+                    .map(|(_, tf, vel)| (tf.translation.truncate(), vel.0))
+                    .min_by(|a, b| a.0.distance_squared(pos).total_cmp(&b.0.distance_squared(pos)))
+            };
             let base_angle = match ranged.aim_mode {
                 crate::game::stats::types::AimMode::SeekNearestTarget => {
-                    aim_angle.map(|a| a.0).unwrap_or(angle.0 .0)
+                    // A hard AI aims where its target will be
+                    let leads = steer.is_some_and(|s| s.difficulty.tuning(&tuning).leads_shots);
+                    match nearest() {
+                        Some((target, target_vel)) if leads => {
+                            crate::game::ai::lead_angle(pos, target, target_vel, ranged.projectile_speed)
+                        }
+                        Some((target, _)) if target.distance_squared(pos) > 1e-6 => {
+                            let to_target = target - pos;
+                            to_target.y.atan2(to_target.x)
+                        }
+                        _ => aim_angle.map(|a| a.0).unwrap_or(angle.0 .0),
+                    }
                 }
                 crate::game::stats::types::AimMode::FollowSpin => angle.0 .0,
+                crate::game::stats::types::AimMode::FixedForward => {
+                    // Standing still: no heading, so fall back to the spin angle
+                    match targets.get(entity) {
+                        Ok((_, _, vel)) if vel.0.length_squared() > 1e-4 => vel.0.y.atan2(vel.0.x),
+                        _ => angle.0 .0,
+                    }
+                }
             };
+
+            // A disciplined AI keeps a ready shot until it faces its opponent
+            if let Some(behavior) = behavior {
+                let target = nearest().map(|(target, _)| target);
+                if behavior.0.holds_fire(pos, base_angle, target) {
+                    continue;
+                }
+            }
+
+            let fire_rate = ranged.fire_rate * stats.0.fire_rate_mult.0 * surge.multiplier;
+            timer.0 = 1.0 / fire_rate.max(0.1);
+
             let dir = Vec2::new(base_angle.cos(), base_angle.sin());
 
             if ranged.burst_count <= 1 && ranged.spread_angle <= 0.0 {
-                events.write(GameEvent::SpawnProjectile {
-                    src: entity,
-                    position: pos + dir * stats.0.radius.0,
-                    direction: dir,
-                    speed: ranged.projectile_speed,
-                    damage: ranged.projectile_damage,
-                    radius: ranged.projectile_radius,
-                    lifetime: ranged.lifetime.0,
-                    weapon_id: wid,
-                    visual_len: vis_len,
-                    visual_thick: vis_thick,
-                });
+                events.write(projectile_event(entity, pos, dir, build, stats.0.radius.0));
             } else {
                 let count = ranged.burst_count.max(1);
                 let total_spread = ranged.spread_angle;
@@ -211,24 +268,35 @@ pub fn fire_ranged_weapons(
                 for i in 0..count {
                     let a = start_angle + step * i as f32;
                     let d = Vec2::new(a.cos(), a.sin());
-                    events.write(GameEvent::SpawnProjectile {
-                        src: entity,
-                        position: pos + d * stats.0.radius.0,
-                        direction: d,
-                        speed: ranged.projectile_speed,
-                        damage: ranged.projectile_damage,
-                        radius: ranged.projectile_radius,
-                        lifetime: ranged.lifetime.0,
-                        weapon_id: wid.clone(),
-                        visual_len: vis_len,
-                        visual_thick: vis_thick,
-                    });
+                    events.write(projectile_event(entity, pos, d, build, stats.0.radius.0));
                 }
             }
         }
     }
 }
 
+/// SpawnProjectile event for one shot of `build`'s ranged weapon, fired from the
+/// rim of a top at `pos` toward `dir`. Builds without a ranged weapon use
+/// `RangedSpec::default()`.
+pub fn projectile_event(src: Entity, pos: Vec2, dir: Vec2, build: &TopBuild, top_radius: f32) -> GameEvent {
+    let weapon = &build.0.weapon;
+    let ranged = weapon.ranged.clone().unwrap_or_default();
+    let (visual_len, visual_thick) = weapon.projectile_dims();
+    GameEvent::SpawnProjectile {
+        src,
+        position: pos + dir * top_radius,
+        direction: dir,
+        speed: ranged.projectile_speed,
+        damage: ranged.projectile_damage,
+        radius: ranged.projectile_radius,
+        lifetime: ranged.lifetime.0,
+        weapon_id: weapon.id.clone(),
+        visual_len,
+        visual_thick,
+        bounce: ranged.bounces_off_obstacles,
+    }
+}
+
 /// Component to track ranged weapon fire cooldown.
 #[derive(Component)]
 pub struct RangedFireTimer(pub f32);
@@ -311,6 +379,7 @@ pub fn detect_melee_hits(
 
             if let Some(control) = melee.hit_control {
                 events.write(GameEvent::ApplyControl {
+                    src: Some(atk_entity),
                     dst: tgt_entity,
                     control,
                 });
@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 
+use super::ai::{AiDifficulty, AiProfile};
 use super::parts::Build;
 use super::stats::effective::EffectiveStats;
 use super::stats::types::{AngleRad, CollisionBehavior, ControlEffect, Seconds, SpinHp};
+use crate::config::tuning::Tuning;
 
 // ── Marker components ───────────────────────────────────────────────
 
@@ -21,6 +23,44 @@ pub struct PlayerControlled;
 #[derive(Component)]
 pub struct AiControlled;
 
+/// Consecutive fixed ticks a top has spent within `tuning.wall_hug_band` of the wall.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WallHugTicks(pub u32);
+
+/// Battle time (seconds) before which a top's trait screw hooks can't fire again.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct HookCooldown {
+    pub obstacle_ready_at: f64,
+}
+
+/// Set once a top has triggered its last stand; the buff lasts until `expires_at`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LastStandUsed {
+    pub expires_at: f64,
+}
+
+/// Personality of an AI top (PvAI only); absent = `AiProfile::default()`.
+#[derive(Component, Debug, Clone)]
+pub struct AiBehavior(pub AiProfile);
+
+/// In-battle steering state of an AI top (see `physics::ai_battle_steer`).
+#[derive(Component, Debug, Clone)]
+pub struct AiSteer {
+    pub difficulty: AiDifficulty,
+    /// Enemy position last picked as the steering target.
+    pub target: Option<Vec2>,
+    /// Seconds until the target position is refreshed.
+    pub retarget_in: f32,
+    /// Seconds before steering (re)starts, after launch or a stun.
+    pub react_in: f32,
+}
+
+impl AiSteer {
+    pub fn new(difficulty: AiDifficulty, tuning: &Tuning) -> Self {
+        Self { difficulty, target: None, retarget_in: 0.0, react_in: difficulty.tuning(tuning).reaction_delay }
+    }
+}
+
 // ── Game phase state ────────────────────────────────────────────────
 
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -33,6 +73,8 @@ pub enum GamePhase {
     Aiming,
     Battle,
     GameOver,
+    Settings,
+    Controls,
     // ── Design flow ──
     DesignHub,
     EditWheel,
@@ -43,6 +85,7 @@ pub enum GamePhase {
     ManageParts,
     AssembleBuild,
     PickDesignPart,
+    CompareBuilds,
     // ── Map design flow ──
     DesignMapHub,
     EditMap,
@@ -56,10 +99,133 @@ pub struct InGame;
 #[derive(Resource)]
 pub struct ArenaRadius(pub f32);
 
+/// Runtime arena hazards of the current map (inserted by `setup_arena`).
+#[derive(Resource, Default)]
+pub struct ArenaHazards {
+    pub wall_spikes: bool,
+    /// Constant acceleration applied to every top (map tilt); zero when flat.
+    pub tilt: Vec2,
+}
+
+/// Rounds won so far in a best-of series (`GameSelection.best_of`); reset
+/// once the series ends.
+#[derive(Resource, Default)]
+pub struct RoundScore {
+    pub p1: u32,
+    pub p2: u32,
+}
+
+impl RoundScore {
+    pub fn round(&self) -> u32 {
+        self.p1 + self.p2 + 1
+    }
+
+    /// Whether a side has won a majority of `best_of` rounds.
+    pub fn clinched(&self, best_of: u32) -> bool {
+        self.p1.max(self.p2) > best_of / 2
+    }
+}
+
+/// Battle clock, counting down from `Tuning::match_time_limit` each round.
+/// When it runs out the top with more spin HP wins on decision; if they are
+/// level, sudden death escalates idle spin drain until one drops.
+#[derive(Resource, Default)]
+pub struct MatchTimer {
+    /// Seconds left; meaningless when the limit is 0 (no timer).
+    pub remaining: f32,
+    pub sudden_death: bool,
+    /// Seconds spent in sudden death so far.
+    pub overtime: f32,
+    /// Time ran out with one top ahead: the battle ends on spin HP.
+    pub decided: bool,
+}
+
+/// How the last round ended, written by `check_game_over` so the GameOver
+/// overlay names the winner from the KO itself rather than re-reading HP.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq)]
+pub struct MatchResult {
+    /// Top that lost the round (KO'd, or behind on spin HP at time up).
+    pub loser: Option<Entity>,
+    /// Double KO: every top reached 0 spin HP on the same tick.
+    pub draw: bool,
+}
+
+impl MatchResult {
+    /// Settle a round from each top's spin HP, or `None` while it's still on.
+    /// With nobody out, `decided_on_time` hands the loss to the lowest HP.
+    pub fn from_spin_hp(tops: &[(Entity, f32)], decided_on_time: bool) -> Option<Self> {
+        let mut out = tops.iter().filter(|(_, hp)| *hp <= 0.0);
+        if let Some(&(loser, _)) = out.next() {
+            let draw = tops.iter().all(|(_, hp)| *hp <= 0.0);
+            return Some(Self { loser: (!draw).then_some(loser), draw });
+        }
+        if !decided_on_time {
+            return None;
+        }
+        let lowest = tops.iter().min_by(|a, b| a.1.total_cmp(&b.1))?;
+        Some(Self { loser: Some(lowest.0), draw: false })
+    }
+}
+
+/// Player volume preferences, mirrored from `UserSettings.audio` (Settings
+/// screen). While muted, battle sounds are not spawned at all.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AudioSettings {
+    /// Linear master volume, 0.0–1.0.
+    pub master: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self { master: 1.0, muted: false }
+    }
+}
+
+impl AudioSettings {
+    /// Master volume as a linear gain (0 while muted).
+    pub fn gain(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master.clamp(0.0, 1.0) }
+    }
+}
+
+/// Runtime SFX profile of the current map (reset to `Normal` after a match).
+#[derive(Resource, Default)]
+pub struct ArenaAudio(pub super::map::AudioProfile);
+
+impl ArenaAudio {
+    /// Settings for a pooled `SfxVoice`: kept alive after playback, and
+    /// slightly louder when `layers` identical sounds were merged into it.
+    pub fn voice(&self, layers: u32, settings: &AudioSettings) -> PlaybackSettings {
+        let boost = (1.0 + 0.2 * layers.saturating_sub(1) as f32).min(1.6);
+        PlaybackSettings::REMOVE
+            .with_volume(bevy::audio::Volume::Linear(self.0.volume() * boost * settings.gain()))
+            .with_speed(self.0.speed())
+    }
+}
+
+/// One of a fixed set of reusable battle SFX players. A voice is free while it
+/// has no `AudioPlayer` (`PlaybackSettings::REMOVE` strips it when done).
+#[derive(Component)]
+pub struct SfxVoice;
+
+/// Marker: the map's looping ambient track.
+#[derive(Component)]
+pub struct AmbientLoop;
+
 /// Marker for Player 2 (local PvP).
 #[derive(Component)]
 pub struct Player2Controlled;
 
+/// Non-interactive opponent replaying a recorded run (`GhostRecording`).
+#[derive(Component, Default)]
+pub struct GhostTop {
+    /// Battle time replayed so far (seconds).
+    pub elapsed: f32,
+    /// First recorded frame whose shots haven't been fired yet.
+    pub next_shot: usize,
+}
+
 // ── Launch aiming ───────────────────────────────────────────────────
 
 #[derive(Component)]
@@ -81,6 +247,10 @@ impl Default for LaunchAim {
 #[derive(Component)]
 pub struct AimArrow;
 
+/// Marker for the degree readout shown next to an aim arrow during Aiming.
+#[derive(Component)]
+pub struct AimAngleLabel;
+
 /// Marker for weapon visual child entities.
 #[derive(Component)]
 pub struct WeaponVisual;
@@ -94,6 +264,84 @@ pub struct ProjectileAssets {
     pub sprites: std::collections::HashMap<String, Handle<Image>>,
 }
 
+/// Sprite for obstacles spawned mid-battle (`GameEvent::SpawnObstacle`).
+#[derive(Resource)]
+pub struct ObstacleAssets {
+    pub image: Handle<Image>,
+    /// Tint that sets temporary obstacles apart from map obstacles.
+    pub color: Color,
+}
+
+/// Recent positions of a projectile, drawn as a fading gizmo line.
+#[derive(Component, Default)]
+pub struct ProjectileTrail {
+    pub points: std::collections::VecDeque<Vec2>,
+}
+
+impl ProjectileTrail {
+    /// Number of past positions kept per projectile.
+    pub const LEN: usize = 6;
+
+    pub fn push(&mut self, point: Vec2) {
+        if self.points.len() == Self::LEN {
+            self.points.pop_front();
+        }
+        self.points.push_back(point);
+    }
+}
+
+/// Recent positions of a top, drawn as a fading motion trail when enabled in Settings.
+#[derive(Component, Default)]
+pub struct TopTrail {
+    pub points: std::collections::VecDeque<Vec2>,
+}
+
+impl TopTrail {
+    /// Number of past positions kept per top (fixed ticks).
+    pub const LEN: usize = 40;
+
+    pub fn push(&mut self, point: Vec2) {
+        if self.points.len() == Self::LEN {
+            self.points.pop_front();
+        }
+        self.points.push_back(point);
+    }
+}
+
+/// Spin HP bar floating above a top during battle. Not a child of the top,
+/// so it doesn't spin with it; `update_hp_bars` follows `owner`.
+#[derive(Component)]
+pub struct HpBar {
+    pub owner: Entity,
+}
+
+/// Colored fill sprite of an `HpBar`, scaled to the HP ratio.
+#[derive(Component)]
+pub struct HpBarFill;
+
+/// Short-lived shard of a burst effect (top elimination, projectile sparks).
+#[derive(Component)]
+pub struct BurstParticle {
+    pub velocity: Vec2,
+    pub ttl: f32,
+}
+
+impl BurstParticle {
+    pub const TTL: f32 = 0.8;
+}
+
+/// Floating damage readout over a top that just lost spin HP; rises and
+/// fades out over `TTL` (spawned by `spawn_damage_numbers` from `DamageTakenMessage`).
+#[derive(Component)]
+pub struct DamageNumber {
+    pub ttl: f32,
+    pub velocity: Vec2,
+}
+
+impl DamageNumber {
+    pub const TTL: f32 = 0.7;
+}
+
 // ── Top runtime state ───────────────────────────────────────────────
 
 #[derive(Component)]
@@ -117,6 +365,8 @@ pub struct ControlState {
     pub stun_remaining: Seconds,
     pub slow_remaining: Seconds,
     pub slow_ratio: f32,
+    /// Knockback waiting for `integrate_physics`: unit direction and strength.
+    pub pending_knockback: Option<(Vec2, f32)>,
 }
 
 impl ControlState {
@@ -125,10 +375,25 @@ impl ControlState {
         self.slow_remaining = self.slow_remaining.dec(dt);
     }
 
-    pub fn apply_control(&mut self, control: ControlEffect, control_multiplier: f32) {
+    pub fn is_stunned(&self) -> bool {
+        self.stun_remaining.0 > 0.0
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        self.slow_remaining.0 > 0.0
+    }
+
+    /// Apply `control` reduced by `control_multiplier`; a stun is then clamped
+    /// to `stun_cap` (tenacity) when there is one. `away` is the unit vector
+    /// from the source to this top (zero if unknown), used by knockback.
+    pub fn apply_control(&mut self, control: ControlEffect, control_multiplier: f32, stun_cap: Option<f32>, away: Vec2) {
         let reduced = control.apply_reduction(control_multiplier);
         match reduced {
             ControlEffect::Stun { duration } => {
+                let duration = match stun_cap {
+                    Some(cap) => Seconds(duration.0.min(cap)),
+                    None => duration,
+                };
                 if duration.0 > self.stun_remaining.0 {
                     self.stun_remaining = duration;
                 }
@@ -139,8 +404,11 @@ impl ControlState {
                     self.slow_ratio = ratio;
                 }
             }
-            ControlEffect::Knockback { .. } => {
-                // Knockback is applied as velocity impulse, handled in physics
+            ControlEffect::Knockback { distance } => {
+                // Applied as a velocity impulse by integrate_physics
+                if away != Vec2::ZERO && distance > 0.0 {
+                    self.pending_knockback = Some((away, distance));
+                }
             }
         }
     }
@@ -154,6 +422,76 @@ pub struct ProjectileDamage(pub f32);
 #[derive(Component)]
 pub struct ProjectileOwner(pub Entity);
 
+/// Marker: projectile ricochets off map obstacles regardless of `Tuning::projectile_obstacle`.
+#[derive(Component)]
+pub struct ProjectileBounce;
+
+/// Marker: inactive projectile parked in `ProjectilePool` (hidden, no `ProjectileMarker`).
+#[derive(Component)]
+pub struct PooledProjectile;
+
+/// Reserve of inactive projectile entities, reused by `spawn_projectiles`
+/// instead of spawning/despawning one entity per shot.
+///
+/// Released entities wait in `pending` until the end of the tick
+/// (`recycle_projectiles`), so nothing reuses them while this tick's events
+/// still name them. `handle_despawn_events` ignores `DespawnEntity` for
+/// entities that are already released (pending or `PooledProjectile`).
+#[derive(Resource, Default)]
+pub struct ProjectilePool {
+    free: Vec<Entity>,
+    pending: Vec<Entity>,
+}
+
+impl ProjectilePool {
+    /// Inactive entities kept beyond this are despawned.
+    pub const MAX_POOLED: usize = 256;
+
+    /// Take an inactive entity to reactivate, if any.
+    pub fn take(&mut self) -> Option<Entity> {
+        self.free.pop()
+    }
+
+    /// Deactivate a live projectile: strip what makes systems see it and hide it.
+    /// Releasing the same entity twice in a tick is a no-op.
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.pending.contains(&entity) {
+            return;
+        }
+        self.pending.push(entity);
+        commands
+            .entity(entity)
+            .remove::<(ProjectileMarker, ProjectileBounce, ProjectileTrail)>()
+            .insert((PooledProjectile, Visibility::Hidden));
+    }
+
+    /// Make this tick's releases available; entities over the cap are despawned.
+    pub fn recycle(&mut self, commands: &mut Commands) {
+        for entity in self.pending.drain(..) {
+            if self.free.len() < Self::MAX_POOLED {
+                self.free.push(entity);
+            } else {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    /// Released this tick; its `PooledProjectile` marker may not be applied yet.
+    pub fn is_pending(&self, entity: Entity) -> bool {
+        self.pending.contains(&entity)
+    }
+
+    pub fn len(&self) -> usize {
+        self.free.len() + self.pending.len()
+    }
+
+    /// Forget every entity (they are despawned with the rest of the battle).
+    pub fn clear(&mut self) {
+        self.free.clear();
+        self.pending.clear();
+    }
+}
+
 #[derive(Component)]
 pub struct Lifetime(pub Seconds);
 
@@ -165,6 +503,10 @@ pub struct CollisionRadius(pub f32);
 #[derive(Component)]
 pub struct ObstacleOwner;
 
+/// The top whose hook dropped this obstacle; its own projectiles pass through.
+#[derive(Component)]
+pub struct ObstacleDroppedBy(pub Entity);
+
 #[derive(Component)]
 pub struct ObstacleBehavior(pub CollisionBehavior);
 
@@ -177,6 +519,36 @@ pub struct ExpiresAt(pub f64);
 #[derive(Component)]
 pub struct StaticObstacle;
 
+/// Moves a map obstacle along its editor-drawn path each Battle tick.
+#[derive(Component)]
+pub struct PathFollower {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub mode: crate::game::map::PathMode,
+    pub period: f32,
+    /// Seconds since the battle started, wrapped to `period`.
+    pub elapsed: f32,
+}
+
+impl PathFollower {
+    /// Position along the path at the current `elapsed` time.
+    pub fn position(&self) -> Vec2 {
+        let phase = self.elapsed / self.period.max(0.01);
+        match self.mode {
+            crate::game::map::PathMode::PingPong => {
+                // 0 → 1 → 0 over one period
+                let s = 1.0 - (1.0 - 2.0 * phase).abs();
+                self.start.lerp(self.end, s)
+            }
+            crate::game::map::PathMode::Circle => {
+                let center = (self.start + self.end) * 0.5;
+                let offset = self.start - center;
+                center + Vec2::from_angle(phase * std::f32::consts::TAU).rotate(offset)
+            }
+        }
+    }
+}
+
 /// Gravity device: continuously steers tops toward itself while in range.
 #[derive(Component)]
 pub struct GravityDevice {
@@ -196,6 +568,43 @@ pub struct DamageBoostZone {
     pub multiplier: f32,
 }
 
+/// Rectangular footprint of a boost zone (one entity per editor area).
+#[derive(Component)]
+pub struct ZoneArea {
+    pub half_extents: Vec2,
+}
+
+impl ZoneArea {
+    /// Circle-vs-rectangle overlap test for a top at `pos` with `radius`.
+    pub fn overlaps(&self, center: Vec2, pos: Vec2, radius: f32) -> bool {
+        let closest = pos.clamp(center - self.half_extents, center + self.half_extents);
+        pos.distance_squared(closest) < radius * radius
+    }
+}
+
+/// Weapon pickup: grants a temporary weapon surge, then respawns after a cooldown.
+#[derive(Component)]
+pub struct WeaponPickup {
+    pub multiplier: f32,
+    pub duration: f32,
+    pub respawn: f32,
+    /// Elapsed time at which the pickup is available again (0 = available).
+    pub ready_at: f64,
+}
+
+/// Repair pack: restores spin HP to a damaged top, then respawns after a cooldown.
+#[derive(Component)]
+pub struct RepairPack {
+    pub amount: f32,
+    pub respawn: f32,
+    /// Elapsed time at which the pack is available again (0 = available).
+    pub ready_at: f64,
+}
+
+/// Tags a spawned map placement with its item type (minimap, debug).
+#[derive(Component)]
+pub struct MapItemTag(pub crate::game::map::MapItem);
+
 /// Active speed boost effect on a top.
 #[derive(Component)]
 pub struct SpeedBoostEffect {
@@ -203,6 +612,13 @@ pub struct SpeedBoostEffect {
     pub multiplier: f32,
 }
 
+/// Active weapon surge from a pickup: ranged fire rate and melee damage are multiplied.
+#[derive(Component)]
+pub struct WeaponSurgeEffect {
+    pub expires_at: f64,
+    pub multiplier: f32,
+}
+
 /// Active damage boost effect on a top (while in zone).
 #[derive(Component)]
 pub struct DamageBoostActive {
@@ -239,3 +655,21 @@ impl MeleeHitTracker {
         self.cooldowns.retain(|(_, t)| *t > 0.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simultaneous_ko_is_a_draw() {
+        let mut world = World::new();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+
+        let result = MatchResult::from_spin_hp(&[(a, 0.0), (b, -2.5)], false);
+        assert_eq!(result, Some(MatchResult { loser: None, draw: true }));
+
+        let result = MatchResult::from_spin_hp(&[(a, 12.0), (b, 0.0)], false);
+        assert_eq!(result, Some(MatchResult { loser: Some(b), draw: false }));
+        assert_eq!(MatchResult::from_spin_hp(&[(a, 12.0), (b, 3.0)], false), None);
+    }
+}
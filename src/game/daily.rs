@@ -0,0 +1,97 @@
+//! Daily challenge: one opponent build, map, AI profile and RNG seed per
+//! calendar day (UTC), the same for every attempt that day.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use super::ai::DEFAULT_AI_PROFILE_ID;
+use super::parts::registry::{PartRegistry, DEFAULT_BUILD_ID};
+use super::rng::GameRng;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Days since the Unix epoch (UTC).
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / SECS_PER_DAY
+}
+
+/// Today's challenge. Present as a resource only while a daily battle is
+/// being played; `setup_arena` seeds `GameRng` from it.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DailyChallenge {
+    pub day: u64,
+    pub seed: u64,
+    pub opponent_build_id: String,
+    pub map_id: String,
+    pub ai_profile_id: String,
+}
+
+impl DailyChallenge {
+    /// Draw the day's opponent, map and profile from the registry. Ids are
+    /// sorted first, so the same library always gives the same challenge.
+    pub fn for_day(day: u64, registry: &PartRegistry) -> Self {
+        let seed = day.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ 0xDA11_C4A1_1E46_E000;
+        let mut rng = GameRng::from_seed(seed);
+        let builds = registry
+            .builds
+            .keys()
+            .filter(|id| registry.resolve_build_id(id).is_some());
+        Self {
+            day,
+            seed,
+            opponent_build_id: pick(&mut rng, builds).unwrap_or_else(|| DEFAULT_BUILD_ID.into()),
+            map_id: pick(&mut rng, registry.maps.keys()).unwrap_or_else(|| "default_arena".into()),
+            ai_profile_id: pick(&mut rng, registry.ai_profiles.keys())
+                .unwrap_or_else(|| DEFAULT_AI_PROFILE_ID.into()),
+        }
+    }
+}
+
+fn pick<'a>(rng: &mut GameRng, ids: impl Iterator<Item = &'a String>) -> Option<String> {
+    let mut ids: Vec<_> = ids.collect();
+    if ids.is_empty() {
+        return None;
+    }
+    ids.sort();
+    Some(ids[(rng.next_u64() % ids.len() as u64) as usize].clone())
+}
+
+/// The player's results for the most recent day they played the challenge.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DailyRecord {
+    pub day: u64,
+    pub attempts: u32,
+    /// Most spin HP left after a win (`None` = no win yet that day).
+    pub best_hp: Option<f32>,
+}
+
+impl DailyRecord {
+    /// Count an attempt on `day`; `won_with_hp` is the player's spin HP left
+    /// after a win. A new day starts a fresh record.
+    pub fn record(&mut self, day: u64, won_with_hp: Option<f32>) {
+        if self.day != day {
+            *self = Self { day, ..default() };
+        }
+        self.attempts += 1;
+        if let Some(hp) = won_with_hp {
+            self.best_hp = Some(self.best_hp.map_or(hp, |best| best.max(hp)));
+        }
+    }
+
+    /// One-line summary for `day`, e.g. "Best: 42 HP left (3 tries)".
+    pub fn summary(&self, day: u64) -> String {
+        if self.day != day || self.attempts == 0 {
+            return "Not played today".into();
+        }
+        let tries = if self.attempts == 1 { "try" } else { "tries" };
+        match self.best_hp {
+            Some(hp) => format!("Best: {:.0} HP left ({} {})", hp, self.attempts, tries),
+            None => format!("No win yet ({} {})", self.attempts, tries),
+        }
+    }
+}
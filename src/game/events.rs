@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::stats::types::{ControlEffect, DamageKind};
+use super::stats::types::{CollisionBehavior, ControlEffect, DamageKind};
 
 /// Top–Top collision event (separate message type to avoid Res/ResMut conflict).
 #[derive(Message, Debug, Clone)]
@@ -11,6 +11,15 @@ pub struct CollisionMessage {
     pub normal: Vec2,
 }
 
+/// Spin HP a top actually lost to one `DealDamage`, after every multiplier.
+/// Written by `apply_damage_events` for presentation (damage numbers).
+#[derive(Message, Debug, Clone)]
+pub struct DamageTakenMessage {
+    pub dst: Entity,
+    pub amount: f32,
+    pub kind: DamageKind,
+}
+
 /// All game events processed through the event pipeline.
 #[derive(Message, Debug, Clone)]
 pub enum GameEvent {
@@ -21,6 +30,8 @@ pub enum GameEvent {
         kind: DamageKind,
     },
     ApplyControl {
+        /// Knockback pushes `dst` directly away from `src`.
+        src: Option<Entity>,
         dst: Entity,
         control: ControlEffect,
     },
@@ -37,6 +48,21 @@ pub enum GameEvent {
         visual_len: f32,
         /// Sprite visual thickness (world units).
         visual_thick: f32,
+        /// Ricochet off map obstacles (see `RangedSpec::bounces_off_obstacles`).
+        bounce: bool,
+    },
+    /// Temporary obstacle (trait screw hooks); despawned after `ttl` seconds.
+    SpawnObstacle {
+        src: Entity,
+        position: Vec2,
+        radius: f32,
+        ttl: f32,
+        behavior: CollisionBehavior,
+    },
+    /// Restore spin HP (repair packs), capped at the top's max.
+    Heal {
+        dst: Entity,
+        amount: f32,
     },
     DespawnEntity {
         entity: Entity,
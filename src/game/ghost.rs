@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::combat::projectile_event;
+use super::components::*;
+use super::events::GameEvent;
+use super::stats::types::AngleRad;
+use crate::config::tuning::Tuning;
+
+/// One fixed tick of a recorded top.
+#[derive(Debug, Clone)]
+pub struct GhostFrame {
+    pub pos: Vec2,
+    pub angle: f32,
+    /// Direction (radians) of every projectile fired this tick.
+    pub shots: Vec<f32>,
+}
+
+/// The last winning run, replayed by the "vs Ghost" mode.
+/// Kept in memory for the session; a new win overwrites it.
+#[derive(Resource, Debug, Clone)]
+pub struct GhostRecording {
+    pub build_id: String,
+    pub map_id: String,
+    pub frames: Vec<GhostFrame>,
+    /// Tick length the run was recorded at; replay follows time, not ticks,
+    /// so a ghost keeps its pace at any physics rate.
+    pub dt: f32,
+    /// Length of the run in seconds.
+    pub duration: f32,
+}
+
+impl GhostRecording {
+    /// Index of the frame recorded at battle time `elapsed`.
+    fn frame_at(&self, elapsed: f32) -> usize {
+        ((elapsed / self.dt.max(1e-4)).round() as usize).saturating_sub(1)
+    }
+
+    /// Where the ghost starts (its first recorded position).
+    pub fn start(&self) -> Vec2 {
+        self.frames.first().map(|f| f.pos).unwrap_or(Vec2::new(3.0, 0.0))
+    }
+}
+
+/// Per-top tracks of the battle in progress. Ghost tops aren't recorded.
+#[derive(Resource, Default)]
+pub struct BattleRecorder {
+    pub tracks: HashMap<Entity, Vec<GhostFrame>>,
+}
+
+/// OnEnter(Battle): start fresh tracks.
+pub fn reset_recorder(mut recorder: ResMut<BattleRecorder>) {
+    recorder.tracks.clear();
+}
+
+/// CleanupSet: append this tick's position, spin angle and shots for every live top.
+pub fn record_ghost_frames(
+    mut recorder: ResMut<BattleRecorder>,
+    mut events: MessageReader<GameEvent>,
+    tops: Query<(Entity, &Transform, &RotationAngle), (With<Top>, Without<GhostTop>)>,
+) {
+    let mut shots: HashMap<Entity, Vec<f32>> = HashMap::new();
+    for event in events.read() {
+        if let GameEvent::SpawnProjectile { src, direction, .. } = event {
+            shots.entry(*src).or_default().push(direction.y.atan2(direction.x));
+        }
+    }
+    for (entity, tf, angle) in &tops {
+        recorder.tracks.entry(entity).or_default().push(GhostFrame {
+            pos: tf.translation.truncate(),
+            angle: angle.0 .0,
+            shots: shots.remove(&entity).unwrap_or_default(),
+        });
+    }
+}
+
+/// PhysicsSet (after `integrate_physics`): snap ghost tops to the frame recorded
+/// at the current battle time. Velocity is derived from the step so collisions
+/// still see motion. A finished recording leaves the ghost parked on its last frame.
+pub fn drive_ghost_tops(
+    tuning: Res<Tuning>,
+    recording: Option<Res<GhostRecording>>,
+    mut ghosts: Query<(&mut GhostTop, &mut Transform, &mut Velocity, &mut RotationAngle)>,
+) {
+    let Some(recording) = recording else {
+        return;
+    };
+    for (mut ghost, mut tf, mut vel, mut angle) in &mut ghosts {
+        ghost.elapsed += tuning.dt;
+        if let Some(frame) = recording.frames.get(recording.frame_at(ghost.elapsed)) {
+            let prev = tf.translation.truncate();
+            vel.0 = (frame.pos - prev) / tuning.dt.max(1e-4);
+            tf.translation.x = frame.pos.x;
+            tf.translation.y = frame.pos.y;
+            angle.0 = AngleRad::new(frame.angle);
+        } else {
+            vel.0 = Vec2::ZERO;
+        }
+    }
+}
+
+/// EventGenerateSet (after `fire_ranged_weapons`): replay every shot recorded up
+/// to the frame `drive_ghost_tops` just applied (several per tick when the
+/// replay runs at a lower rate than the recording).
+pub fn fire_ghost_shots(
+    recording: Option<Res<GhostRecording>>,
+    mut ghosts: Query<(Entity, &mut GhostTop, &Transform, &TopBuild, &TopEffectiveStats)>,
+    mut events: MessageWriter<GameEvent>,
+) {
+    let Some(recording) = recording else {
+        return;
+    };
+    for (entity, mut ghost, tf, build, stats) in &mut ghosts {
+        let upto = (recording.frame_at(ghost.elapsed) + 1).min(recording.frames.len());
+        let pos = tf.translation.truncate();
+        for frame in recording.frames.get(ghost.next_shot..upto).unwrap_or_default() {
+            for &shot in &frame.shots {
+                let dir = Vec2::new(shot.cos(), shot.sin());
+                events.write(projectile_event(entity, pos, dir, build, stats.0.radius.0));
+            }
+        }
+        ghost.next_shot = ghost.next_shot.max(upto);
+    }
+}
@@ -1,13 +1,130 @@
 use bevy::prelude::*;
 
+use super::components::*;
 use super::events::GameEvent;
+use super::parts::trait_screw::TraitHookKind;
+use super::stats::types::{CollisionBehavior, DamageKind};
+use crate::config::tuning::Tuning;
 
-/// Hook pipeline: processes events through part hooks, status hooks, etc.
-/// For v0 this is a pass-through; hooks will be added in v0.2.
+/// Gap (world units) left between a top and the obstacle it drops.
+const DROP_GAP: f32 = 0.05;
+
+/// Hook pipeline: runs trait screw hooks on this tick's events and writes
+/// the events they produce, so EventApplySet handles them the same tick.
+///
+/// `OnTakeHit`: a top that takes damage drops a temporary solid obstacle
+/// behind itself (opposite its velocity), at most once per
+/// `tuning.hook_obstacle_cooldown`. Drops that would leave the arena are skipped.
+///
+/// `Thorns`: a fraction of each collision hit taken goes back to its source.
+/// `OnHitDealBonus`: each collision, melee or projectile hit landed deals a flat
+/// extra amount. Both write sourceless damage, so hooks never trigger each other.
 pub fn process_hooks(
-    mut _events: MessageReader<GameEvent>,
-    // In future: query for TraitScrew hooks, status effects, floor zones, etc.
+    time: Res<Time>,
+    tuning: Res<Tuning>,
+    arena: Res<ArenaRadius>,
+    mut events: ParamSet<(MessageReader<GameEvent>, MessageWriter<GameEvent>)>,
+    mut tops: Query<(&Transform, &Velocity, &TopBuild, &TopEffectiveStats, &mut HookCooldown), With<Top>>,
 ) {
-    // v0: no-op pass-through
-    // v0.2: iterate events, run through trait screw on_hit, on_tick hooks, etc.
+    let now = time.elapsed_secs_f64();
+    let mut produced = Vec::new();
+    for event in events.p0().read() {
+        let GameEvent::DealDamage { src, dst, amount, kind } = event else {
+            continue;
+        };
+        if *amount <= 0.0 {
+            continue;
+        }
+
+        if let Some(src) = src.filter(|src| src != dst) {
+            let dealer_bonus = tops.get(src).map_or(0.0, |(_, _, build, _, _)| build.0.screw.hit_bonus());
+            if dealer_bonus > 0.0 && matches!(kind, DamageKind::Collision | DamageKind::Melee | DamageKind::Projectile) {
+                produced.push(GameEvent::DealDamage { src: None, dst: *dst, amount: dealer_bonus, kind: *kind });
+            }
+            let thorns = tops.get(*dst).map_or(0.0, |(_, _, build, _, _)| build.0.screw.thorns());
+            if thorns > 0.0 && *kind == DamageKind::Collision && tops.contains(src) {
+                produced.push(GameEvent::DealDamage { src: None, dst: src, amount: amount * thorns, kind: DamageKind::Collision });
+            }
+        }
+
+        let Ok((tf, vel, build, stats, mut cooldown)) = tops.get_mut(*dst) else {
+            continue;
+        };
+        if !build.0.screw.hooks.contains(&TraitHookKind::OnTakeHit) || now < cooldown.obstacle_ready_at {
+            continue;
+        }
+        let back = -vel.0.normalize_or_zero();
+        if back == Vec2::ZERO {
+            continue;
+        }
+        let radius = tuning.hook_obstacle_radius;
+        let position = tf.translation.truncate() + back * (stats.0.radius.0 + radius + DROP_GAP);
+        if position.length() + radius >= arena.0 {
+            continue;
+        }
+        produced.push(GameEvent::SpawnObstacle {
+            src: *dst,
+            position,
+            radius,
+            ttl: tuning.hook_obstacle_ttl,
+            behavior: CollisionBehavior::Solid,
+        });
+        cooldown.obstacle_ready_at = now + tuning.hook_obstacle_cooldown as f64;
+    }
+
+    let mut writer = events.p1();
+    for event in produced {
+        writer.write(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::logging::DebugLogging;
+    use crate::game::combat::apply_damage_events;
+    use crate::game::events::DamageTakenMessage;
+    use crate::game::parts::registry::PartRegistry;
+    use crate::plugins::game_plugin::spawn_top;
+
+    #[test]
+    fn thorns_screw_reflects_collision_damage() {
+        let tuning = Tuning::default();
+        let registry = PartRegistry::with_defaults();
+        let attacker_build = registry.resolve_build_id("default_blade").expect("default build resolves");
+        let mut thorny_build = attacker_build.clone();
+        thorny_build.screw.hooks = vec![TraitHookKind::Thorns { fraction: 0.5 }];
+
+        let mut app = App::new();
+        app.add_message::<GameEvent>()
+            .add_message::<DamageTakenMessage>()
+            .insert_resource(Time::<()>::default())
+            .insert_resource(DebugLogging::default())
+            .insert_resource(ArenaRadius(tuning.arena_radius));
+
+        let world = app.world_mut();
+        let attacker_stats = attacker_build.combined_modifiers().compute_effective(&attacker_build.wheel, &tuning);
+        let thorny_stats = thorny_build.combined_modifiers().compute_effective(&thorny_build.wheel, &tuning);
+        let mut commands = world.commands();
+        let attacker = spawn_top(&mut commands, &attacker_build, attacker_stats.clone(), Vec2::new(-2.0, 0.0), 0.0).id();
+        let thorny = spawn_top(&mut commands, &thorny_build, thorny_stats, Vec2::new(2.0, 0.0), 0.0).id();
+        world.insert_resource(tuning);
+        world.flush();
+
+        let hit = move |mut writer: MessageWriter<GameEvent>| {
+            writer.write(GameEvent::DealDamage { src: Some(attacker), dst: thorny, amount: 10.0, kind: DamageKind::Collision });
+        };
+        app.add_systems(Update, (hit, process_hooks, apply_damage_events).chain());
+        app.update();
+
+        let world = app.world();
+        let lost = |top: Entity| {
+            let max = world.get::<TopEffectiveStats>(top).expect("top has stats").0.spin_hp_max.0;
+            max - world.get::<SpinHpCurrent>(top).expect("top has spin HP").0 .0
+        };
+        assert!(lost(thorny) > 0.0, "the thorny top still takes the hit");
+        let reflected = lost(attacker);
+        let expected = 5.0 * attacker_stats.damage_in_mult.0 * attacker_stats.damage_in_by_kind.collision.0;
+        assert!((reflected - expected).abs() < 1e-3, "attacker should take half back, lost {reflected}");
+    }
 }
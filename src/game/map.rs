@@ -7,6 +7,9 @@ pub struct MapSpec {
     pub name: String,
     pub arena_radius: f32,
     pub placements: Vec<MapPlacement>,
+    /// Map-level options (persisted as `options_json`).
+    #[serde(default)]
+    pub options: MapOptions,
 }
 
 impl MapSpec {
@@ -16,6 +19,98 @@ impl MapSpec {
             name: "Default Arena".into(),
             arena_radius: 12.0,
             placements: vec![],
+            options: MapOptions::default(),
+        }
+    }
+
+    /// Maps shipped with the game (not editable or deletable).
+    pub fn is_builtin(&self) -> bool {
+        self.id == "default_arena"
+    }
+}
+
+/// Map-wide settings that aren't tied to a grid cell.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapOptions {
+    /// Looping ambient track, relative to `assets/` (e.g. "audio/ambient/{map_id}.ogg").
+    pub ambient_path: Option<String>,
+    /// How battle SFX sound on this map.
+    pub audio_profile: AudioProfile,
+    /// Spiked wall: wall hits deal extra damage scaled by impact speed
+    /// (`tuning.wall_spike_k`).
+    pub wall_spikes: bool,
+    /// Tilted arena: a constant pull on every top toward one side.
+    pub tilt: Option<ArenaTilt>,
+}
+
+/// Pull strengths (world units / s²) offered by the map editor.
+pub const TILT_STRENGTHS: [f32; 3] = [1.0, 2.5, 5.0];
+
+/// Arena slope: every top accelerates toward `angle_deg` (0 = +x, CCW)
+/// at `strength` world units / s².
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ArenaTilt {
+    pub angle_deg: f32,
+    pub strength: f32,
+}
+
+impl ArenaTilt {
+    pub fn accel(&self) -> bevy::math::Vec2 {
+        bevy::math::Vec2::from_angle(self.angle_deg.to_radians()) * self.strength
+    }
+
+    /// Compass label of the downhill direction (screen up = N).
+    pub fn direction_name(&self) -> &'static str {
+        const NAMES: [&str; 8] = ["E", "NE", "N", "NW", "W", "SW", "S", "SE"];
+        NAMES[((self.angle_deg.rem_euclid(360.0) / 45.0).round() as usize) % 8]
+    }
+}
+
+/// Playback profile applied to every battle SFX on a map.
+/// bevy_audio has no reverb, so profiles shape volume and playback speed only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioProfile {
+    #[default]
+    Normal,
+    /// Big open arena: slightly louder and lower.
+    Hall,
+    /// Enclosed / padded: quieter.
+    Muffled,
+}
+
+impl AudioProfile {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Hall => "Hall",
+            Self::Muffled => "Muffled",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Normal => Self::Hall,
+            Self::Hall => Self::Muffled,
+            Self::Muffled => Self::Normal,
+        }
+    }
+
+    /// Linear volume applied to SFX.
+    pub fn volume(self) -> f32 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Hall => 1.1,
+            Self::Muffled => 0.6,
+        }
+    }
+
+    /// Playback speed applied to SFX (<1.0 lowers pitch).
+    pub fn speed(self) -> f32 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Hall => 0.92,
+            Self::Muffled => 0.97,
         }
     }
 }
@@ -26,6 +121,206 @@ pub struct MapPlacement {
     pub grid_x: i32,
     pub grid_y: i32,
     pub item: MapItem,
+    /// Footprint in cells, extending +x / −y from (grid_x, grid_y).
+    /// Only area zones (speed / damage boost) use more than 1×1.
+    #[serde(default = "one_cell")]
+    pub width: i32,
+    #[serde(default = "one_cell")]
+    pub height: i32,
+    /// Route for a moving obstacle; `None` for every other item.
+    #[serde(default)]
+    pub path: Option<ObstaclePath>,
+    /// Tuned effect values; unset fields use the item's defaults.
+    #[serde(default)]
+    pub params: MapItemParams,
+}
+
+/// Per-placement overrides for area items, set in the map editor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapItemParams {
+    /// Gravity device pull radius (world units).
+    pub effect_radius: Option<f32>,
+    /// Speed / damage boost zone multiplier.
+    pub multiplier: Option<f32>,
+}
+
+/// Gravity device pull radius when not tuned (world units).
+pub const DEFAULT_GRAVITY_RADIUS: f32 = 3.0;
+
+/// Speed and damage boost zone multiplier when not tuned.
+pub const DEFAULT_BOOST_MULTIPLIER: f32 = 1.5;
+
+fn one_cell() -> i32 {
+    1
+}
+
+impl MapPlacement {
+    /// Single-cell placement.
+    pub fn cell(grid_x: i32, grid_y: i32, item: MapItem) -> Self {
+        Self { grid_x, grid_y, item, width: 1, height: 1, path: None, params: MapItemParams::default() }
+    }
+
+    /// In-battle effect radius: the tuned gravity radius, else the item default.
+    pub fn effect_radius(&self) -> Option<f32> {
+        match self.item {
+            MapItem::GravityDevice => Some(self.params.effect_radius.unwrap_or(DEFAULT_GRAVITY_RADIUS)),
+            item => item.effect_radius(),
+        }
+    }
+
+    /// Speed / damage boost zone multiplier.
+    pub fn boost_multiplier(&self) -> f32 {
+        self.params.multiplier.unwrap_or(DEFAULT_BOOST_MULTIPLIER)
+    }
+
+    /// The same placement shifted by (dx, dy) cells, path included.
+    pub fn translated(&self, dx: i32, dy: i32) -> Self {
+        Self {
+            grid_x: self.grid_x + dx,
+            grid_y: self.grid_y + dy,
+            path: self.path.map(|path| ObstaclePath {
+                end_x: path.end_x + dx,
+                end_y: path.end_y + dy,
+                ..path
+            }),
+            ..self.clone()
+        }
+    }
+
+    pub fn is_area(&self) -> bool {
+        self.width > 1 || self.height > 1
+    }
+
+    /// True if the footprint includes grid cell (gx, gy).
+    pub fn covers(&self, gx: i32, gy: i32) -> bool {
+        gx >= self.grid_x
+            && gx < self.grid_x + self.width
+            && gy <= self.grid_y
+            && gy > self.grid_y - self.height
+    }
+
+    /// True if the two footprints share at least one cell.
+    pub fn overlaps(&self, other: &MapPlacement) -> bool {
+        self.grid_x < other.grid_x + other.width
+            && other.grid_x < self.grid_x + self.width
+            && self.grid_y - self.height < other.grid_y
+            && other.grid_y - other.height < self.grid_y
+    }
+
+    /// True if every cell of the footprint (and any obstacle path) is inside the arena.
+    pub fn is_valid(&self, arena_radius: f32) -> bool {
+        let footprint = (0..self.width).all(|dx| {
+            (0..self.height).all(|dy| is_valid_placement(self.grid_x + dx, self.grid_y - dy, arena_radius))
+        });
+        footprint
+            && self
+                .path
+                .is_none_or(|path| path.is_valid(self.grid_x, self.grid_y, arena_radius))
+    }
+
+    /// World-space center of the footprint.
+    pub fn world_center(&self) -> (f32, f32) {
+        (
+            (self.grid_x as f32 + (self.width - 1) as f32 * 0.5) * GRID_CELL_SIZE,
+            (self.grid_y as f32 - (self.height - 1) as f32 * 0.5) * GRID_CELL_SIZE,
+        )
+    }
+
+    /// World-space size of the footprint.
+    pub fn world_size(&self) -> (f32, f32) {
+        (self.width as f32 * GRID_CELL_SIZE, self.height as f32 * GRID_CELL_SIZE)
+    }
+}
+
+/// How a moving obstacle travels between its placement cell and the end cell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PathMode {
+    /// Back and forth along the straight line.
+    #[default]
+    PingPong,
+    /// Around the circle whose diameter is the start–end segment.
+    Circle,
+}
+
+/// Route of a `MapItem::MovingObstacle`, starting at the placement cell.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ObstaclePath {
+    pub end_x: i32,
+    pub end_y: i32,
+    #[serde(default)]
+    pub mode: PathMode,
+    /// Seconds for one full cycle (there and back, or once around).
+    #[serde(default = "default_path_period")]
+    pub period: f32,
+}
+
+/// Moving obstacle cycle length when not set (seconds).
+pub const DEFAULT_PATH_PERIOD: f32 = 4.0;
+
+pub fn default_path_period() -> f32 {
+    DEFAULT_PATH_PERIOD
+}
+
+impl ObstaclePath {
+    pub fn new(end_x: i32, end_y: i32, mode: PathMode) -> Self {
+        Self { end_x, end_y, mode, period: default_path_period() }
+    }
+
+    /// True if the obstacle stays inside the arena for the whole cycle.
+    /// A straight segment between two valid cells never leaves the circle.
+    pub fn is_valid(&self, start_x: i32, start_y: i32, arena_radius: f32) -> bool {
+        if !is_valid_placement(self.end_x, self.end_y, arena_radius) {
+            return false;
+        }
+        match self.mode {
+            PathMode::PingPong => true,
+            PathMode::Circle => {
+                let cx = (start_x + self.end_x) as f32 * 0.5 * GRID_CELL_SIZE;
+                let cy = (start_y + self.end_y) as f32 * 0.5 * GRID_CELL_SIZE;
+                let dx = (self.end_x - start_x) as f32 * GRID_CELL_SIZE;
+                let dy = (self.end_y - start_y) as f32 * GRID_CELL_SIZE;
+                let orbit = (dx * dx + dy * dy).sqrt() * 0.5;
+                (cx * cx + cy * cy).sqrt() + orbit + GRID_CELL_SIZE * 0.5 < arena_radius
+            }
+        }
+    }
+}
+
+/// Map item palette for the editor and map cards (`UserSettings.color_blind`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorBlindMode {
+    #[default]
+    Off,
+    /// High-contrast colors plus a letter glyph on every placed cell.
+    HighContrast,
+}
+
+impl ColorBlindMode {
+    pub const ALL: [ColorBlindMode; 2] = [ColorBlindMode::Off, ColorBlindMode::HighContrast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorBlindMode::Off => "Standard",
+            ColorBlindMode::HighContrast => "High Contrast",
+        }
+    }
+
+    /// Settings value (`UserSettings.color_blind`).
+    pub fn key(self) -> &'static str {
+        match self {
+            ColorBlindMode::Off => "off",
+            ColorBlindMode::HighContrast => "high_contrast",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL.into_iter().find(|m| m.key() == key).unwrap_or_default()
+    }
+
+    pub fn glyphs(self) -> bool {
+        self == ColorBlindMode::HighContrast
+    }
 }
 
 /// Types of items that can be placed on the map grid.
@@ -35,15 +330,78 @@ pub enum MapItem {
     GravityDevice,
     SpeedBoost,
     DamageBoost,
+    WeaponPickup,
+    RepairPack,
+    MovingObstacle,
+    Bumper,
 }
 
 impl MapItem {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Obstacle => "Obstacle",
+            Self::GravityDevice => "Gravity Device",
+            Self::SpeedBoost => "Speed Boost",
+            Self::DamageBoost => "Damage Boost",
+            Self::WeaponPickup => "Weapon Pickup",
+            Self::RepairPack => "Repair Pack",
+            Self::MovingObstacle => "Moving Obstacle",
+            Self::Bumper => "Bumper",
+        }
+    }
+
     pub fn color(self) -> bevy::prelude::Color {
         match self {
             Self::Obstacle => bevy::prelude::Color::srgba(0.5, 0.5, 0.5, 1.0),
             Self::GravityDevice => bevy::prelude::Color::srgba(0.6, 0.2, 0.8, 1.0),
             Self::SpeedBoost => bevy::prelude::Color::srgba(0.2, 0.8, 0.3, 1.0),
             Self::DamageBoost => bevy::prelude::Color::srgba(0.8, 0.2, 0.2, 1.0),
+            Self::WeaponPickup => bevy::prelude::Color::srgba(1.0, 0.8, 0.1, 1.0),
+            Self::RepairPack => bevy::prelude::Color::srgba(0.3, 0.9, 0.9, 1.0),
+            Self::MovingObstacle => bevy::prelude::Color::srgba(0.8, 0.55, 0.3, 1.0),
+            Self::Bumper => bevy::prelude::Color::srgba(1.0, 0.35, 0.75, 1.0),
+        }
+    }
+
+    /// Editor color under the player's `ColorBlindMode`. High contrast uses
+    /// the Okabe–Ito palette, which stays distinct under common color blindness.
+    pub fn display_color(self, mode: ColorBlindMode) -> bevy::prelude::Color {
+        use bevy::prelude::Color;
+        match mode {
+            ColorBlindMode::Off => self.color(),
+            ColorBlindMode::HighContrast => match self {
+                Self::Obstacle => Color::srgba(0.6, 0.6, 0.6, 1.0),
+                Self::GravityDevice => Color::srgba(0.0, 0.45, 0.70, 1.0),
+                Self::SpeedBoost => Color::srgba(0.0, 0.62, 0.45, 1.0),
+                Self::DamageBoost => Color::srgba(0.84, 0.37, 0.0, 1.0),
+                Self::WeaponPickup => Color::srgba(0.94, 0.89, 0.26, 1.0),
+                Self::RepairPack => Color::srgba(0.34, 0.71, 0.91, 1.0),
+                Self::MovingObstacle => Color::srgba(0.90, 0.62, 0.0, 1.0),
+                Self::Bumper => Color::srgba(0.80, 0.47, 0.65, 1.0),
+            },
+        }
+    }
+
+    /// One-letter tag drawn over the item's cells in high-contrast mode.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            Self::Obstacle => "O",
+            Self::GravityDevice => "G",
+            Self::SpeedBoost => "S",
+            Self::DamageBoost => "D",
+            Self::WeaponPickup => "W",
+            Self::RepairPack => "R",
+            Self::MovingObstacle => "M",
+            Self::Bumper => "B",
+        }
+    }
+    /// In-battle radius (world units) within which the item affects tops,
+    /// for items that act as an area. `None` for solid blocks and pickups.
+    pub fn effect_radius(self) -> Option<f32> {
+        match self {
+            Self::GravityDevice => Some(DEFAULT_GRAVITY_RADIUS),
+            Self::SpeedBoost | Self::DamageBoost => Some(GRID_CELL_SIZE * 0.5),
+            Self::Obstacle | Self::WeaponPickup | Self::RepairPack | Self::MovingObstacle | Self::Bumper => None,
         }
     }
 }
@@ -58,3 +416,41 @@ pub fn is_valid_placement(grid_x: i32, grid_y: i32, arena_radius: f32) -> bool {
     let dist = (wx * wx + wy * wy).sqrt();
     dist + GRID_CELL_SIZE * 0.5 < arena_radius
 }
+
+/// Fewest open cells a saved map must keep: in the arena and not under a
+/// solid block. A minimum-radius arena has roughly 400 cells.
+pub const MIN_OPEN_CELLS: usize = 200;
+
+/// Arena cells tops can move through: inside `arena_radius` and not covered
+/// by an obstacle or bumper. Only placements still valid at that radius count.
+pub fn open_cell_count(arena_radius: f32, placements: &[MapPlacement]) -> usize {
+    let half = (arena_radius / GRID_CELL_SIZE).ceil() as i32;
+    let solids: Vec<&MapPlacement> = placements
+        .iter()
+        .filter(|p| matches!(p.item, MapItem::Obstacle | MapItem::Bumper) && p.is_valid(arena_radius))
+        .collect();
+    (-half..=half)
+        .flat_map(|x| (-half..=half).map(move |y| (x, y)))
+        .filter(|&(x, y)| is_valid_placement(x, y, arena_radius))
+        .filter(|&(x, y)| !solids.iter().any(|p| p.covers(x, y)))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimum_radius_arena_is_playable() {
+        assert!(open_cell_count(6.0, &[]) >= MIN_OPEN_CELLS);
+    }
+
+    #[test]
+    fn solid_blocks_close_cells_and_pruned_ones_do_not() {
+        let empty = open_cell_count(6.0, &[]);
+        let wall = MapPlacement { width: 4, height: 4, ..MapPlacement::cell(0, 0, MapItem::Obstacle) };
+        let boost = MapPlacement::cell(2, 2, MapItem::SpeedBoost);
+        let outside = MapPlacement::cell(20, 0, MapItem::Obstacle);
+        assert_eq!(open_cell_count(6.0, &[wall, boost, outside]), empty - 16);
+    }
+}
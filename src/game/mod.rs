@@ -1,12 +1,17 @@
+pub mod ai;
 pub mod arena;
 pub mod collision;
 pub mod combat;
 pub mod components;
+pub mod daily;
 pub mod events;
+pub mod ghost;
 pub mod hooks;
 pub mod map;
 pub mod parts;
 pub mod physics;
+pub mod rng;
+pub mod spatial;
 pub mod stats;
 pub mod status;
 pub mod tick;
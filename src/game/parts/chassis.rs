@@ -7,6 +7,9 @@ use crate::game::stats::modifier::ModifierSet;
 pub struct ChassisSpec {
     pub id: String,
     pub name: String,
+    /// Card blurb describing the handling, e.g. "Nimble but fragile".
+    #[serde(default)]
+    pub description: String,
     /// Additive bonus to move speed.
     pub move_speed_add: f32,
     /// Multiplier to move speed.
@@ -26,6 +29,7 @@ impl Default for ChassisSpec {
         Self {
             id: "standard_chassis".into(),
             name: "Standard Chassis".into(),
+            description: String::new(),
             move_speed_add: 0.0,
             move_speed_mul: 1.0,
             accel_add: 0.0,
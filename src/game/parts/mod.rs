@@ -11,6 +11,7 @@ use self::shaft::ShaftSpec;
 use self::trait_screw::TraitScrewSpec;
 use self::weapon_wheel::WeaponWheelSpec;
 use crate::game::stats::base::BaseStats;
+use crate::game::stats::effective::EffectiveStats;
 use crate::game::stats::modifier::ModifierSet;
 
 /// A complete build: top + 4 parts.
@@ -30,11 +31,39 @@ impl Build {
     /// Combine all part modifiers into a single ModifierSet.
     pub fn combined_modifiers(&self) -> ModifierSet {
         let mut mods = ModifierSet::new();
-        mods.merge(&self.shaft.to_modifiers());
-        mods.merge(&self.chassis.to_modifiers());
-        mods.merge(&self.screw.to_modifiers());
+        for (_, part_mods) in self.part_modifiers() {
+            mods.merge(&part_mods);
+        }
         mods
     }
+
+    /// Weapon damage per second if every hit lands: melee damage once per
+    /// `hit_cooldown` plus every projectile of every burst, scaled by the
+    /// build's outgoing damage and fire rate multipliers.
+    pub fn peak_dps(&self, eff: &EffectiveStats) -> f32 {
+        let melee = self
+            .weapon
+            .melee
+            .as_ref()
+            .map(|m| m.base_damage / m.hit_cooldown.max(0.01))
+            .unwrap_or(0.0);
+        let ranged = self
+            .weapon
+            .ranged
+            .as_ref()
+            .map(|r| r.projectile_damage * r.burst_count as f32 * r.fire_rate * eff.fire_rate_mult.0)
+            .unwrap_or(0.0);
+        (melee + ranged) * eff.damage_out_mult.0
+    }
+
+    /// Each stat-modifying part's own ModifierSet, labeled by slot, in merge order.
+    pub fn part_modifiers(&self) -> [(&'static str, ModifierSet); 3] {
+        [
+            ("Shaft", self.shaft.to_modifiers()),
+            ("Chassis", self.chassis.to_modifiers()),
+            ("Screw", self.screw.to_modifiers()),
+        ]
+    }
 }
 
 impl Default for Build {
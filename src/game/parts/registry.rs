@@ -1,16 +1,23 @@
 use std::collections::HashMap;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::chassis::ChassisSpec;
 use super::shaft::ShaftSpec;
 use super::trait_screw::TraitScrewSpec;
 use super::weapon_wheel::{MeleeSpec, RangedSpec, WeaponWheelSpec};
 use super::Build;
+use crate::config::tuning::Tuning;
+use crate::game::ai::AiProfile;
 use crate::game::map::MapSpec;
 use crate::game::stats::base::BaseStats;
+use crate::game::stats::modifier::power_score;
 use crate::game::stats::types::WeaponKind;
 
+/// Build used when a selected build can't be resolved.
+pub const DEFAULT_BUILD_ID: &str = "default_blade";
+
 /// Lightweight reference to a build (stores part IDs, not resolved specs).
 #[derive(Clone, Debug)]
 pub struct BuildRef {
@@ -21,6 +28,20 @@ pub struct BuildRef {
     pub shaft_id: String,
     pub chassis_id: String,
     pub screw_id: String,
+    /// Slots that can't be changed in Assemble Build.
+    pub locks: SlotLocks,
+}
+
+/// Per-slot locks for guided or template builds ("fixed chassis, free weapon").
+/// A locked slot's "Change..." button is disabled in Assemble Build.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlotLocks {
+    pub wheel: bool,
+    pub weapon: bool,
+    pub shaft: bool,
+    pub chassis: bool,
+    pub screw: bool,
 }
 
 /// Registry of all available parts and tops, indexed by ID.
@@ -35,6 +56,7 @@ pub struct PartRegistry {
     pub screws: HashMap<String, TraitScrewSpec>,
     pub builds: HashMap<String, BuildRef>,
     pub maps: HashMap<String, MapSpec>,
+    pub ai_profiles: HashMap<String, AiProfile>,
 }
 
 impl PartRegistry {
@@ -51,6 +73,7 @@ impl PartRegistry {
             WeaponWheelSpec {
                 id: "basic_blade".into(),
                 name: "Standard Blade".into(),
+                description: String::new(),
                 kind: WeaponKind::Sword,
                 melee: Some(MeleeSpec::default()),
                 ranged: None,
@@ -64,6 +87,7 @@ impl PartRegistry {
             WeaponWheelSpec {
                 id: "basic_blaster".into(),
                 name: "Standard Blaster".into(),
+                description: String::new(),
                 kind: WeaponKind::Gun,
                 melee: None,
                 ranged: Some(RangedSpec::default()),
@@ -72,6 +96,21 @@ impl PartRegistry {
             },
         );
 
+        // Pure-collision builds: no melee, no ranged
+        reg.weapons.insert(
+            "no_weapon".into(),
+            WeaponWheelSpec {
+                id: "no_weapon".into(),
+                name: "No Weapon".into(),
+                description: String::new(),
+                kind: WeaponKind::Unarmed,
+                melee: None,
+                ranged: None,
+                sprite_path: None,
+                projectile_sprite_path: None,
+            },
+        );
+
         // ── Shafts ─────────────────────────────────────────────────
         reg.shafts
             .insert("standard_shaft".into(), ShaftSpec::default());
@@ -104,6 +143,7 @@ impl PartRegistry {
                 shaft_id: "standard_shaft".into(),
                 chassis_id: "standard_chassis".into(),
                 screw_id: "standard_screw".into(),
+                locks: SlotLocks::default(),
             },
         );
         reg.builds.insert(
@@ -116,6 +156,7 @@ impl PartRegistry {
                 shaft_id: "standard_shaft".into(),
                 chassis_id: "standard_chassis".into(),
                 screw_id: "standard_screw".into(),
+                locks: SlotLocks::default(),
             },
         );
 
@@ -123,9 +164,21 @@ impl PartRegistry {
         let default_map = MapSpec::default_arena();
         reg.maps.insert(default_map.id.clone(), default_map);
 
+        // ── AI Profiles ──────────────────────────────────────────────
+        for profile in AiProfile::presets() {
+            reg.ai_profiles.insert(profile.id.clone(), profile);
+        }
+
         reg
     }
 
+    /// Add (or override) AI profiles from the user's `ai_profiles.ron`.
+    pub fn merge_ai_profiles_file(&mut self) {
+        for profile in AiProfile::load_file() {
+            self.ai_profiles.insert(profile.id.clone(), profile);
+        }
+    }
+
     /// Load custom user-created parts from SQLite into the registry.
     pub fn merge_custom_parts(
         &mut self,
@@ -142,7 +195,12 @@ impl PartRegistry {
         if let Ok(parts) = repo.load_parts_by_slot_sync(rt, "weapon") {
             for (id, _kind, json) in parts {
                 match serde_json::from_str::<WeaponWheelSpec>(&json) {
-                    Ok(spec) => { self.weapons.insert(id, spec); }
+                    Ok(mut spec) => {
+                        if spec.normalize() {
+                            bevy::log::warn!("Weapon '{}': specs didn't match kind {:?}, normalized", id, spec.kind);
+                        }
+                        self.weapons.insert(id, spec);
+                    }
                     Err(e) => { bevy::log::error!("Failed to deserialize weapon '{}': {}", id, e); }
                 }
             }
@@ -177,11 +235,12 @@ impl PartRegistry {
         rt: &tokio::runtime::Runtime,
     ) {
         if let Ok(rows) = repo.load_all_builds_sync(rt) {
-            for (id, wheel_id, weapon_id, shaft_id, chassis_id, screw_id, note) in rows {
+            for (id, wheel_id, weapon_id, shaft_id, chassis_id, screw_id, note, locks_json) in rows {
                 let name = if note.is_empty() { id.clone() } else { note };
+                let locks = serde_json::from_str(&locks_json).unwrap_or_default();
                 self.builds.insert(
                     id.clone(),
-                    BuildRef { id, name, wheel_id, weapon_id, shaft_id, chassis_id, screw_id },
+                    BuildRef { id, name, wheel_id, weapon_id, shaft_id, chassis_id, screw_id, locks },
                 );
             }
         }
@@ -194,9 +253,11 @@ impl PartRegistry {
         rt: &tokio::runtime::Runtime,
     ) {
         if let Ok(rows) = repo.load_all_maps_sync(rt) {
-            for (id, name, arena_radius, placements_json) in rows {
+            for (id, name, arena_radius, placements_json, options_json) in rows {
                 let placements: Vec<crate::game::map::MapPlacement> =
                     serde_json::from_str(&placements_json).unwrap_or_default();
+                let options: crate::game::map::MapOptions =
+                    serde_json::from_str(&options_json).unwrap_or_default();
                 self.maps.insert(
                     id.clone(),
                     MapSpec {
@@ -204,12 +265,20 @@ impl PartRegistry {
                         name,
                         arena_radius: arena_radius as f32,
                         placements,
+                        options,
                     },
                 );
             }
         }
     }
 
+    /// Power-budget score of a saved build (see `power_score`).
+    /// Returns `None` if the build or any of its parts is missing.
+    pub fn build_power_score(&self, build_id: &str, tuning: &Tuning) -> Option<f32> {
+        let build = self.resolve_build_id(build_id)?;
+        Some(power_score(&build.combined_modifiers().compute_effective(&build.wheel, tuning)))
+    }
+
     /// Assemble a `Build` by looking up each part ID in the registry.
     /// Returns `None` if any part ID is not found.
     pub fn resolve_build(
@@ -226,10 +295,11 @@ impl PartRegistry {
             bevy::log::error!("resolve_build '{}': wheel '{}' not in registry", build_id, wheel_id);
             None
         })?.clone();
-        let weapon = self.weapons.get(weapon_id).or_else(|| {
+        let mut weapon = self.weapons.get(weapon_id).or_else(|| {
             bevy::log::error!("resolve_build '{}': weapon '{}' not in registry", build_id, weapon_id);
             None
         })?.clone();
+        weapon.normalize();
         let shaft = self.shafts.get(shaft_id).or_else(|| {
             bevy::log::error!("resolve_build '{}': shaft '{}' not in registry", build_id, shaft_id);
             None
@@ -254,4 +324,102 @@ impl PartRegistry {
             note: None,
         })
     }
+
+    /// Whether any registered part, build or map already uses `id`.
+    pub fn id_in_use(&self, id: &str) -> bool {
+        self.wheels.contains_key(id)
+            || self.weapons.contains_key(id)
+            || self.shafts.contains_key(id)
+            || self.chassis.contains_key(id)
+            || self.screws.contains_key(id)
+            || self.builds.contains_key(id)
+            || self.maps.contains_key(id)
+    }
+
+    /// Resolve a saved build by its ID. `None` if the build or any part is missing.
+    pub fn resolve_build_id(&self, build_id: &str) -> Option<Build> {
+        let r = self.builds.get(build_id).or_else(|| {
+            bevy::log::error!("resolve_build_id: build '{}' not in registry", build_id);
+            None
+        })?;
+        self.resolve_build(&r.id, &r.name, &r.wheel_id, &r.weapon_id, &r.shaft_id, &r.chassis_id, &r.screw_id)
+    }
+
+    /// Resolve a build for battle without failing: falls back to
+    /// `DEFAULT_BUILD_ID`, then to `Build::default()` if even that is broken.
+    pub fn resolve_build_or_default(&self, build_id: &str) -> Build {
+        self.resolve_build_id(build_id)
+            .or_else(|| {
+                bevy::log::warn!("Build '{}' unusable — falling back to '{}'", build_id, DEFAULT_BUILD_ID);
+                self.resolve_build_id(DEFAULT_BUILD_ID)
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry_with(edit: impl FnOnce(&mut BuildRef)) -> PartRegistry {
+        let mut reg = PartRegistry::with_defaults();
+        let mut r = reg.builds["default_blaster"].clone();
+        r.id = "custom".into();
+        edit(&mut r);
+        reg.builds.insert(r.id.clone(), r);
+        reg
+    }
+
+    #[test]
+    fn resolves_valid_build() {
+        let reg = registry_with(|_| {});
+        let build = reg.resolve_build_id("custom").expect("custom build resolves");
+        assert_eq!(build.id, "custom");
+        assert_eq!(build.wheel.id, "default_top");
+        assert_eq!(build.weapon.id, "basic_blaster");
+        assert!(reg.resolve_build_id(DEFAULT_BUILD_ID).is_some());
+    }
+
+    #[test]
+    fn missing_part_returns_none() {
+        let broken: [fn(&mut BuildRef); 5] = [
+            |r| r.wheel_id = "gone".into(),
+            |r| r.weapon_id = "gone".into(),
+            |r| r.shaft_id = "gone".into(),
+            |r| r.chassis_id = "gone".into(),
+            |r| r.screw_id = "gone".into(),
+        ];
+        for edit in broken {
+            let reg = registry_with(edit);
+            assert!(reg.resolve_build_id("custom").is_none());
+        }
+        assert!(PartRegistry::with_defaults().resolve_build_id("no_such_build").is_none());
+    }
+
+    #[test]
+    fn fallback_is_playable() {
+        let tuning = Tuning::default();
+        let reg = registry_with(|r| r.weapon_id = "gone".into());
+        let build = reg.resolve_build_or_default("custom");
+        assert_eq!(build.id, DEFAULT_BUILD_ID);
+        let stats = build.combined_modifiers().compute_effective(&build.wheel, &tuning);
+        assert!(stats.spin_hp_max.0 > 0.0);
+        assert!(stats.move_speed.0 > 0.0);
+
+        // Even with the default build broken, a battle still gets a build
+        let mut reg = PartRegistry::with_defaults();
+        reg.wheels.clear();
+        let build = reg.resolve_build_or_default(DEFAULT_BUILD_ID);
+        assert_eq!(build.id, Build::default().id);
+        assert!(build.wheel.spin_hp_max.0 > 0.0);
+    }
+
+    #[test]
+    fn id_in_use_covers_parts_builds_and_maps() {
+        let reg = PartRegistry::with_defaults();
+        assert!(reg.id_in_use("standard_shaft"));
+        assert!(reg.id_in_use("default_blaster"));
+        assert!(reg.id_in_use("default_arena"));
+        assert!(!reg.id_in_use("custom_00000000"));
+    }
 }
@@ -7,6 +7,9 @@ use crate::game::stats::modifier::ModifierSet;
 pub struct ShaftSpec {
     pub id: String,
     pub name: String,
+    /// Short note on the shaft's feel (stability vs. spin life) for its card.
+    #[serde(default)]
+    pub description: String,
     /// Reduces collision displacement / knockback.
     pub stability: f32,
     /// Multiplier for idle spin drain (higher = less drain).
@@ -18,6 +21,7 @@ impl Default for ShaftSpec {
         Self {
             id: "standard_shaft".into(),
             name: "Standard Shaft".into(),
+            description: String::new(),
             stability: 0.5,
             spin_efficiency: 1.0,
         }
@@ -25,6 +29,10 @@ impl Default for ShaftSpec {
 }
 
 impl ShaftSpec {
+    /// Range the shaft editor clamps `spin_efficiency` to on save.
+    pub const MIN_EFFICIENCY: f32 = 0.25;
+    pub const MAX_EFFICIENCY: f32 = 4.0;
+
     pub fn to_modifiers(&self) -> ModifierSet {
         let mut mods = ModifierSet::new();
         mods.stability.add = self.stability;
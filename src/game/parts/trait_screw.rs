@@ -1,15 +1,21 @@
 use serde::{Deserialize, Serialize};
 
 use crate::game::stats::modifier::ModifierSet;
-use crate::game::stats::types::Multiplier;
+use crate::game::stats::types::{DamageIntake, Multiplier};
 
 /// Which events a trait screw can hook into.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TraitHookKind {
     OnHit,
     OnTick,
     OnWallCollision,
     OnFireProjectile,
+    /// When this top takes damage: drop a temporary obstacle behind it.
+    OnTakeHit,
+    /// Send `fraction` of each collision hit taken back at the top that dealt it.
+    Thorns { fraction: f32 },
+    /// Each collision, melee or projectile hit this top lands deals `amount` extra.
+    OnHitDealBonus { amount: f32 },
 }
 
 /// Passive stat changes from a trait screw.
@@ -23,6 +29,13 @@ pub struct TraitPassive {
     pub damage_out_mult: f32,
     /// Damage intake multiplier.
     pub damage_in_mult: f32,
+    /// Extra intake multiplier per damage kind.
+    #[serde(default)]
+    pub damage_in_by_kind: DamageIntake,
+    /// Tenacity: longest stun this top can suffer, in seconds, after control
+    /// reduction. `None` = uncapped.
+    #[serde(default)]
+    pub stun_cap: Option<f32>,
 }
 
 impl Default for TraitPassive {
@@ -32,6 +45,8 @@ impl Default for TraitPassive {
             control_reduction: 0.0,
             damage_out_mult: 1.0,
             damage_in_mult: 1.0,
+            damage_in_by_kind: DamageIntake::default(),
+            stun_cap: None,
         }
     }
 }
@@ -41,6 +56,9 @@ impl Default for TraitPassive {
 pub struct TraitScrewSpec {
     pub id: String,
     pub name: String,
+    /// Card text explaining the passive or hook in words.
+    #[serde(default)]
+    pub description: String,
     pub passive: TraitPassive,
     pub hooks: Vec<TraitHookKind>,
 }
@@ -50,6 +68,7 @@ impl Default for TraitScrewSpec {
         Self {
             id: "standard_screw".into(),
             name: "Standard Screw".into(),
+            description: String::new(),
             passive: TraitPassive::default(),
             hooks: Vec::new(),
         }
@@ -66,6 +85,24 @@ impl TraitScrewSpec {
         }
         mods.damage_out_mult = Multiplier::new(self.passive.damage_out_mult);
         mods.damage_in_mult = Multiplier::new(self.passive.damage_in_mult);
+        mods.damage_in_by_kind = self.passive.damage_in_by_kind;
+        mods.stun_cap = self.passive.stun_cap;
         mods
     }
+
+    /// Fraction of collision damage reflected by a `Thorns` hook (0 = none).
+    pub fn thorns(&self) -> f32 {
+        self.hooks.iter().map(|hook| match hook {
+            TraitHookKind::Thorns { fraction } => fraction.max(0.0),
+            _ => 0.0,
+        }).sum()
+    }
+
+    /// Flat extra damage per landed hit from an `OnHitDealBonus` hook (0 = none).
+    pub fn hit_bonus(&self) -> f32 {
+        self.hooks.iter().map(|hook| match hook {
+            TraitHookKind::OnHitDealBonus { amount } => amount.max(0.0),
+            _ => 0.0,
+        }).sum()
+    }
 }
@@ -71,6 +71,9 @@ pub struct RangedSpec {
     /// Projectile sprite visual thickness (world units). Independent of collision radius.
     #[serde(default = "default_proj_visual_thick")]
     pub projectile_visual_thick: f32,
+    /// Projectiles ricochet off map obstacles instead of being blocked.
+    #[serde(default)]
+    pub bounces_off_obstacles: bool,
 }
 
 fn default_proj_visual_len() -> f32 { 1.0 }
@@ -94,6 +97,7 @@ impl Default for RangedSpec {
             barrel_thick: 0.3,
             projectile_visual_len: 1.0,
             projectile_visual_thick: 0.3,
+            bounces_off_obstacles: false,
         }
     }
 }
@@ -103,6 +107,9 @@ impl Default for RangedSpec {
 pub struct WeaponWheelSpec {
     pub id: String,
     pub name: String,
+    /// One-line pitch for the weapon card, e.g. "Fast jabs, short reach".
+    #[serde(default)]
+    pub description: String,
     pub kind: WeaponKind,
     pub melee: Option<MeleeSpec>,
     pub ranged: Option<RangedSpec>,
@@ -126,6 +133,46 @@ impl WeaponWheelSpec {
         }
     }
 
+    /// Returns (len, thick) of the weapon body: blade if there is a melee spec, else barrel.
+    /// `None` for unarmed weapons (no visual).
+    pub fn visual_dims(&self) -> Option<(f32, f32)> {
+        match (&self.melee, &self.ranged) {
+            (Some(m), _) => Some((m.blade_len, m.blade_thick)),
+            (None, Some(r)) => Some((r.barrel_len, r.barrel_thick)),
+            (None, None) => None,
+        }
+    }
+
+    /// Short stat summary for cards: kind, then one line per active spec.
+    pub fn stats_line(&self) -> String {
+        let mut lines = vec![self.kind.display_name().to_string()];
+        if let Some(m) = &self.melee {
+            lines.push(format!("Melee:{:.1} CD:{:.1}", m.base_damage, m.hit_cooldown));
+        }
+        if let Some(r) = &self.ranged {
+            lines.push(format!("Shot:{:.1} Rate:{:.1}", r.projectile_damage, r.fire_rate));
+        }
+        lines.join("\n")
+    }
+
+    /// Make the specs match `kind`: fill a default spec the kind needs but lacks,
+    /// and drop one it doesn't use. Hand-edited or imported JSON can disagree
+    /// with the kind; the editor never does. Returns true if anything changed.
+    pub fn normalize(&mut self) -> bool {
+        let before = (self.melee.is_some(), self.ranged.is_some());
+        if self.kind.has_melee() {
+            self.melee.get_or_insert_with(MeleeSpec::default);
+        } else {
+            self.melee = None;
+        }
+        if self.kind.has_ranged() {
+            self.ranged.get_or_insert_with(RangedSpec::default);
+        } else {
+            self.ranged = None;
+        }
+        before != (self.melee.is_some(), self.ranged.is_some())
+    }
+
     /// Returns (visual_len, visual_thick) for the projectile sprite, derived from weapon kind.
     pub fn projectile_dims(&self) -> (f32, f32) {
         self.kind.projectile_dims()
@@ -137,6 +184,7 @@ impl Default for WeaponWheelSpec {
         Self {
             id: "default_sword".into(),
             name: "Standard Blade".into(),
+            description: String::new(),
             kind: WeaponKind::Sword,
             melee: Some(MeleeSpec::default()),
             ranged: None,
@@ -1,29 +1,112 @@
 use bevy::prelude::*;
 
 use super::components::*;
+use crate::config::logging::DebugLogging;
 use crate::config::tuning::Tuning;
 use crate::game::stats::types::AimMode;
 
+/// PhysicsSet: AI tops steer toward the nearest live enemy top, or away from
+/// it when low on spin HP. Each tick the velocity turns toward the desired
+/// direction by at most `accel * ai_aggression * steer_scale * dt`, keeping
+/// at least the top's move speed. The difficulty (`AiSteer`) sets how long
+/// the top waits after launch or a stun and how stale its idea of the
+/// target's position gets. Ghost tops replay instead.
+pub fn ai_battle_steer(
+    tuning: Res<Tuning>,
+    mut ai: Query<
+        (&Transform, &mut Velocity, &SpinHpCurrent, &TopEffectiveStats, &ControlState, &mut AiSteer),
+        (With<AiControlled>, Without<GhostTop>),
+    >,
+    enemies: Query<(&Transform, &SpinHpCurrent), (With<Top>, Without<AiControlled>)>,
+) {
+    let dt = tuning.dt;
+    for (tf, mut vel, hp, stats, control, mut steer) in &mut ai {
+        if hp.0 .0 <= 0.0 {
+            continue;
+        }
+        let difficulty = steer.difficulty.tuning(&tuning);
+        if control.is_stunned() {
+            steer.react_in = difficulty.reaction_delay;
+            continue;
+        }
+        if steer.react_in > 0.0 {
+            steer.react_in -= dt;
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        steer.retarget_in -= dt;
+        if steer.retarget_in <= 0.0 || steer.target.is_none() {
+            steer.target = enemies
+                .iter()
+                .filter(|(_, enemy_hp)| enemy_hp.0 .0 > 0.0)
+                .map(|(enemy, _)| enemy.translation.truncate())
+                .min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos)));
+            steer.retarget_in = difficulty.retarget_interval;
+        }
+        let blend = (tuning.ai_aggression * difficulty.steer_scale).clamp(0.0, 1.0);
+        let Some(mut dir) = steer.target.and_then(|target| (target - pos).try_normalize()) else {
+            continue;
+        };
+        if hp.0 .0 < stats.0.spin_hp_max.0 * tuning.ai_retreat_hp_frac {
+            dir = -dir;
+        }
+        let desired = dir * vel.0.length().max(stats.0.move_speed.0);
+        let max_change = stats.0.accel * blend * dt;
+        vel.0 = (vel.0 + (desired - vel.0).clamp_length_max(max_change)).clamp_length_max(tuning.max_speed);
+    }
+}
+
 /// PhysicsSet: integrate velocity → position, update rotation angle.
 pub fn integrate_physics(
     tuning: Res<Tuning>,
-    mut query: Query<(&mut Transform, &Velocity, &mut RotationAngle, &TopBuild, &SpeedBoostEffect), With<Top>>,
+    log: Res<DebugLogging>,
+    hazards: Option<Res<ArenaHazards>>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &mut RotationAngle,
+            &TopBuild,
+            &SpeedBoostEffect,
+            &mut ControlState,
+            &TopEffectiveStats,
+        ),
+        With<Top>,
+    >,
     mut tick: Local<u32>,
 ) {
     *tick = tick.wrapping_add(1);
     let log_this_tick = *tick % 60 == 0;
 
     let dt = tuning.dt;
-    for (mut transform, vel, mut angle, build, speed_boost) in &mut query {
-        let eff_vel = vel.0 * speed_boost.multiplier;
+    let tilt = hazards.map_or(Vec2::ZERO, |h| h.tilt);
+    for (mut transform, mut vel, mut angle, build, speed_boost, mut control, stats) in &mut query {
+        // One-shot knockback; stability makes a top heavier, as in collisions
+        if let Some((dir, strength)) = control.pending_knockback.take() {
+            let inv_mass = 1.0 / (1.0 + stats.0.stability.max(0.0));
+            vel.0 = (vel.0 + dir * strength * inv_mass).clamp_length_max(tuning.max_speed);
+        }
+
+        // Tilted arena: steady pull toward the low side
+        if tilt != Vec2::ZERO {
+            vel.0 = (vel.0 + tilt * dt).clamp_length_max(tuning.max_speed);
+        }
+
+        // A top's own drive is its speed boost: a stunned top only coasts,
+        // a slowed one moves at `slow_ratio` of its speed
+        let mut drive = if control.is_stunned() { 1.0 } else { speed_boost.multiplier };
+        if control.is_slowed() {
+            drive *= control.slow_ratio.clamp(0.0, 1.0);
+        }
+        let eff_vel = vel.0 * drive;
 
         if log_this_tick && speed_boost.multiplier > 1.001 {
-            info!(
+            log.info(format_args!(
                 "[SpeedBoost] vel_speed={:.2}  eff_speed={:.2}  multiplier={:.2}",
                 vel.0.length(),
                 eff_vel.length(),
                 speed_boost.multiplier
-            );
+            ));
         }
 
         transform.translation.x += eff_vel.x * dt;
@@ -53,18 +136,79 @@ pub fn integrate_projectiles(
     }
 }
 
+/// Append each projectile's current position to its trail.
+pub fn record_projectile_trails(
+    mut query: Query<(&Transform, &mut ProjectileTrail), With<ProjectileMarker>>,
+) {
+    for (transform, mut trail) in &mut query {
+        trail.push(transform.translation.truncate());
+    }
+}
+
+/// Append each top's end-of-tick position to its motion trail.
+pub fn record_top_trails(mut query: Query<(&Transform, &mut TopTrail), With<Top>>) {
+    for (transform, mut trail) in &mut query {
+        trail.push(transform.translation.truncate());
+    }
+}
+
 /// Apply natural spin drain (idle).
 pub fn spin_drain(
     tuning: Res<Tuning>,
+    timer: Option<Res<MatchTimer>>,
     mut query: Query<(&mut SpinHpCurrent, &TopEffectiveStats), With<Top>>,
 ) {
     let dt = tuning.dt;
+    // Sudden death: drain grows the longer the tie lasts
+    let sudden_death = timer
+        .filter(|t| t.sudden_death)
+        .map(|t| t.overtime * tuning.sudden_death_drain_ramp)
+        .unwrap_or(0.0);
     for (mut spin, stats) in &mut query {
-        let drain = stats.0.spin_drain_idle_per_sec * dt;
+        let drain = (stats.0.spin_drain_idle_per_sec + sudden_death) * dt;
         spin.0 = spin.0.sub_clamped(drain);
     }
 }
 
+/// Spin HP gap under which a timed-out battle counts as level.
+pub const SUDDEN_DEATH_HP_EPSILON: f32 = 1.0;
+
+/// OnEnter(Battle): start the clock for this round.
+pub fn reset_match_timer(mut timer: ResMut<MatchTimer>, tuning: Res<Tuning>) {
+    *timer = MatchTimer { remaining: tuning.match_time_limit, ..default() };
+}
+
+/// CleanupSet: count the battle down. At zero, the two highest spin HPs
+/// decide it, or start sudden death when they're within `SUDDEN_DEATH_HP_EPSILON`.
+pub fn tick_match_timer(
+    tuning: Res<Tuning>,
+    mut timer: ResMut<MatchTimer>,
+    tops: Query<&SpinHpCurrent, With<Top>>,
+) {
+    if tuning.match_time_limit <= 0.0 || timer.decided {
+        return;
+    }
+    if timer.sudden_death {
+        timer.overtime += tuning.dt;
+        return;
+    }
+    timer.remaining = (timer.remaining - tuning.dt).max(0.0);
+    if timer.remaining > 0.0 {
+        return;
+    }
+    let mut hp: Vec<f32> = tops.iter().map(|spin| spin.0 .0).collect();
+    hp.sort_by(|a, b| b.total_cmp(a));
+    if let [first, second, ..] = hp[..] {
+        if first - second <= SUDDEN_DEATH_HP_EPSILON {
+            info!("Time up at {:.1} - {:.1} spin HP: sudden death", first, second);
+            timer.sudden_death = true;
+            return;
+        }
+    }
+    info!("Time up: decided on spin HP");
+    timer.decided = true;
+}
+
 /// Tick control state timers.
 pub fn tick_control_state(tuning: Res<Tuning>, mut query: Query<&mut ControlState, With<Top>>) {
     let dt = tuning.dt;
@@ -82,35 +226,38 @@ pub fn tick_melee_trackers(tuning: Res<Tuning>, mut query: Query<&mut MeleeHitTr
 }
 
 /// PhysicsSet: for SeekNearestTarget ranged weapons, rotate the weapon visual to face
-/// the nearest enemy and store the aim angle in `WeaponAimAngle`.
+/// the nearest enemy and store the aim angle in `WeaponAimAngle`. FixedForward
+/// weapons point along the top's velocity instead.
 /// Must run AFTER `integrate_physics` so `RotationAngle` (spin) is up-to-date.
 pub fn update_seek_weapon_visual(
-    tops: Query<(Entity, &Transform, &TopBuild, &RotationAngle, &TopEffectiveStats, &Children), With<Top>>,
+    tops: Query<(Entity, &Transform, &Velocity, &TopBuild, &RotationAngle, &TopEffectiveStats, &Children), With<Top>>,
     mut aim_angles: Query<&mut WeaponAimAngle>,
     mut weapon_visuals: Query<&mut Transform, (With<WeaponVisual>, Without<Top>)>,
 ) {
     // Snapshot all top positions (avoids borrow conflict with mutable queries below).
     let positions: Vec<(Entity, Vec2)> = tops.iter()
-        .map(|(e, tf, _, _, _, _)| (e, tf.translation.truncate()))
+        .map(|(e, tf, ..)| (e, tf.translation.truncate()))
         .collect();
 
-    for (self_entity, self_tf, build, spin_angle, self_stats, children) in &tops {
-        let ranged = match &build.0.weapon.ranged {
-            Some(r) if r.aim_mode == AimMode::SeekNearestTarget => r,
-            _ => continue,
-        };
+    for (self_entity, self_tf, vel, build, spin_angle, self_stats, children) in &tops {
+        let Some(ranged) = &build.0.weapon.ranged else { continue };
 
         let self_pos = self_tf.translation.truncate();
-        let nearest = positions.iter()
-            .filter(|(e, _)| *e != self_entity)
-            .min_by(|(_, a), (_, b)| {
-                a.distance(self_pos)
-                    .partial_cmp(&b.distance(self_pos))
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-        let Some((_, target_pos)) = nearest else { continue };
-        let dir = *target_pos - self_pos;
+        let dir = match ranged.aim_mode {
+            AimMode::SeekNearestTarget => {
+                let nearest = positions.iter()
+                    .filter(|(e, _)| *e != self_entity)
+                    .min_by(|(_, a), (_, b)| {
+                        a.distance(self_pos)
+                            .partial_cmp(&b.distance(self_pos))
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    });
+                let Some((_, target_pos)) = nearest else { continue };
+                *target_pos - self_pos
+            }
+            AimMode::FixedForward => vel.0,
+            AimMode::FollowSpin => continue,
+        };
         if dir.length_squared() < 0.001 {
             continue;
         }
@@ -137,3 +284,74 @@ pub fn update_seek_weapon_visual(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::{apply_control_events, detect_melee_hits};
+    use crate::game::events::GameEvent;
+    use crate::game::parts::registry::PartRegistry;
+    use crate::game::stats::types::ControlEffect;
+    use crate::plugins::game_plugin::spawn_top;
+
+    #[test]
+    fn stunned_top_coasts_without_its_drive() {
+        let tuning = Tuning::default();
+        let dt = tuning.dt;
+        let build = PartRegistry::with_defaults().resolve_build_id("default_blade").expect("default build resolves");
+        let stats = build.combined_modifiers().compute_effective(&build.wheel, &tuning);
+
+        let mut app = App::new();
+        app.insert_resource(DebugLogging::default())
+            .insert_resource(tuning)
+            .add_systems(Update, integrate_physics);
+        let top = app.world_mut().spawn((
+            Top,
+            Transform::default(),
+            Velocity(Vec2::new(5.0, 0.0)),
+            RotationAngle(crate::game::stats::types::AngleRad::new(0.0)),
+            TopBuild(build),
+            SpeedBoostEffect { expires_at: f64::MAX, multiplier: 2.0 },
+            ControlState { stun_remaining: crate::game::stats::types::Seconds(1.0), ..default() },
+            TopEffectiveStats(stats),
+        )).id();
+
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Velocity>(top).expect("top has velocity").0, Vec2::new(5.0, 0.0));
+        let moved = world.get::<Transform>(top).expect("top has transform").translation.x;
+        assert!((moved - 5.0 * dt).abs() < 1e-5, "stunned top should ignore its speed boost, moved {moved}");
+    }
+
+    #[test]
+    fn knockback_melee_pushes_target_away() {
+        let tuning = Tuning::default();
+        let registry = PartRegistry::with_defaults();
+        let target_build = registry.resolve_build_id("default_blade").expect("default build resolves");
+        let mut attacker_build = target_build.clone();
+        attacker_build.weapon.melee.as_mut().expect("blade has melee").hit_control =
+            Some(ControlEffect::Knockback { distance: 3.0 });
+
+        let mut app = App::new();
+        app.add_message::<GameEvent>()
+            .insert_resource(DebugLogging::default())
+            .add_systems(Update, (detect_melee_hits, apply_control_events, integrate_physics).chain());
+
+        // Attacker's blade faces +x, toward the target
+        let world = app.world_mut();
+        let attacker_stats = attacker_build.combined_modifiers().compute_effective(&attacker_build.wheel, &tuning);
+        let target_stats = target_build.combined_modifiers().compute_effective(&target_build.wheel, &tuning);
+        let mut commands = world.commands();
+        spawn_top(&mut commands, &attacker_build, attacker_stats, Vec2::ZERO, 0.0);
+        let target = spawn_top(&mut commands, &target_build, target_stats, Vec2::new(2.0, 0.0), 0.0).id();
+        world.insert_resource(tuning);
+        world.flush();
+
+        app.update();
+
+        let vel = app.world().get::<Velocity>(target).expect("target has velocity").0;
+        assert!(vel.x > 0.0, "target should move away from the attacker, got {vel:?}");
+        assert!(vel.y.abs() < 1e-4, "knockback should be along the hit line, got {vel:?}");
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+/// Battle random number generator (SplitMix64).
+///
+/// Every random decision made during a match (AI aim, random effects) must draw
+/// from this resource so that a fixed `Tuning::rng_seed` reproduces the battle.
+#[derive(Resource, Debug, Clone)]
+pub struct GameRng {
+    seed: u64,
+    state: u64,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// Seed this generator started from. Put it in `Tuning::rng_seed` to
+    /// replay the match.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Seed from the system clock (non-reproducible).
+    pub fn from_entropy() -> Self {
+        use std::time::SystemTime;
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        Self::from_seed(nanos)
+    }
+
+    /// Fixed seed when given, clock otherwise.
+    pub fn for_battle(seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self::from_seed(seed),
+            None => Self::from_entropy(),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform in `[lo, hi)`.
+    pub fn range_f32(&mut self, lo: f32, hi: f32) -> f32 {
+        lo + (hi - lo) * self.next_f32()
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
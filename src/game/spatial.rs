@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::components::*;
+
+/// Cell size (world units) of the broad-phase grid. About two obstacle
+/// cells, so a top usually queries a 3×3 block.
+pub const SPATIAL_CELL_SIZE: f32 = 1.0;
+
+/// Uniform spatial hash of circles.
+///
+/// Each circle is stored once, in the cell holding its center; queries widen
+/// their search by the largest stored radius so no overlap is missed.
+/// Candidates come back in insertion (query iteration) order, which keeps
+/// order-dependent responses such as push-outs identical to a full scan.
+#[derive(Default)]
+pub struct SpatialGrid {
+    cells: HashMap<IVec2, Vec<(usize, Entity)>>,
+    max_radius: f32,
+    len: usize,
+}
+
+impl SpatialGrid {
+    /// Empty the grid, keeping cell allocations for the next rebuild.
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.max_radius = 0.0;
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Vec2, radius: f32) {
+        self.cells.entry(cell_of(pos)).or_default().push((self.len, entity));
+        self.max_radius = self.max_radius.max(radius);
+        self.len += 1;
+    }
+
+    /// Collect into `hits` every stored circle that may overlap a circle at
+    /// `pos` with `radius`. `hits` is cleared first and keeps its allocation.
+    pub fn query(&self, pos: Vec2, radius: f32, hits: &mut GridHits) {
+        hits.0.clear();
+        let reach = Vec2::splat(radius + self.max_radius);
+        let (min, max) = (cell_of(pos - reach), cell_of(pos + reach));
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                    hits.0.extend_from_slice(cell);
+                }
+            }
+        }
+        hits.0.sort_unstable_by_key(|(index, _)| *index);
+    }
+}
+
+/// Reusable result buffer for `SpatialGrid::query`; keep one in a `Local`.
+#[derive(Default)]
+pub struct GridHits(Vec<(usize, Entity)>);
+
+impl GridHits {
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().map(|(_, entity)| *entity)
+    }
+
+    /// Insertion index of each hit, i.e. its position in the rebuild order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().map(|(index, _)| *index)
+    }
+}
+
+/// Broad-phase grid of map obstacles, rebuilt every fixed step.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct ObstacleGrid(pub SpatialGrid);
+
+fn cell_of(pos: Vec2) -> IVec2 {
+    (pos / SPATIAL_CELL_SIZE).floor().as_ivec2()
+}
+
+/// PhysicsSet (after obstacles have moved): re-bucket every obstacle.
+/// `static_obstacle_bounce`, `projectile_obstacle_contact` and
+/// `detect_collisions` query this instead of scanning all obstacles.
+pub fn rebuild_obstacle_grid(
+    mut grid: ResMut<ObstacleGrid>,
+    obstacles: Query<(Entity, &Transform, &CollisionRadius), Or<(With<ObstacleMarker>, With<StaticObstacle>)>>,
+) {
+    grid.clear();
+    for (entity, tf, radius) in &obstacles {
+        grid.insert(entity, tf.translation.truncate(), radius.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_returns_nearby_hits_in_insertion_order() {
+        let mut grid = SpatialGrid::default();
+        let mut world = World::new();
+        let [a, b, far] = [(); 3].map(|_| world.spawn_empty().id());
+        grid.insert(b, Vec2::new(0.6, 0.0), 0.2);
+        grid.insert(far, Vec2::new(20.0, 20.0), 0.2);
+        grid.insert(a, Vec2::new(-0.6, 0.0), 0.2);
+
+        let mut hits = GridHits::default();
+        grid.query(Vec2::ZERO, 0.5, &mut hits);
+        assert_eq!(hits.entities().collect::<Vec<_>>(), vec![b, a]);
+        assert_eq!(hits.indices().collect::<Vec<_>>(), vec![0, 2]);
+
+        // Reusing the buffer drops the previous results
+        grid.query(Vec2::new(20.0, 20.0), 0.1, &mut hits);
+        assert_eq!(hits.entities().collect::<Vec<_>>(), vec![far]);
+    }
+}
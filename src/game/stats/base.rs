@@ -7,6 +7,9 @@ use super::types::{MetersPerSec, Radius, SpinHp};
 pub struct BaseStats {
     pub id: String,
     pub name: String,
+    /// Flavor text on the wheel's picker card; empty shows nothing.
+    #[serde(default)]
+    pub description: String,
     pub spin_hp_max: SpinHp,
     pub radius: Radius,
     pub move_speed: MetersPerSec,
@@ -24,6 +27,7 @@ impl Default for BaseStats {
         Self {
             id: "default_top".into(),
             name: "Standard Top".into(),
+            description: String::new(),
             spin_hp_max: SpinHp(100.0),
             radius: Radius(1.3),
             move_speed: MetersPerSec(10.0),
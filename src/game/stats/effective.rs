@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::types::{MetersPerSec, Multiplier, Radius, SpinHp};
+use super::types::{DamageIntake, MetersPerSec, Multiplier, Radius, SpinHp};
 
 /// Pre-computed stats read during combat ticks. Read-only in FixedUpdate.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,12 +12,16 @@ pub struct EffectiveStats {
     pub accel: f32,
     /// Control duration multiplier: m = max(0, 1 - R). Lower = more reduction.
     pub control_multiplier: f32,
+    /// Longest stun (seconds) that can land after reduction; `None` = uncapped.
+    pub stun_cap: Option<f32>,
     pub spin_drain_idle_per_sec: f32,
     pub spin_drain_on_wall_hit: f32,
     pub spin_drain_on_top_hit: f32,
     pub stability: f32,
     pub damage_out_mult: Multiplier,
     pub damage_in_mult: Multiplier,
+    /// Per-kind intake multipliers, applied on top of `damage_in_mult`.
+    pub damage_in_by_kind: DamageIntake,
     pub fire_rate_mult: Multiplier,
 }
 
@@ -29,12 +33,14 @@ impl Default for EffectiveStats {
             move_speed: MetersPerSec(5.0),
             accel: 25.0,
             control_multiplier: 1.0,
+            stun_cap: None,
             spin_drain_idle_per_sec: 0.2,
             spin_drain_on_wall_hit: 0.5,
             spin_drain_on_top_hit: 1.0,
             stability: 0.0,
             damage_out_mult: Multiplier::one(),
             damage_in_mult: Multiplier::one(),
+            damage_in_by_kind: DamageIntake::default(),
             fire_rate_mult: Multiplier::one(),
         }
     }
@@ -2,9 +2,12 @@ use serde::{Deserialize, Serialize};
 
 use super::base::BaseStats;
 use super::effective::EffectiveStats;
-use super::types::Multiplier;
+use super::types::{DamageIntake, Multiplier};
 use crate::config::tuning::Tuning;
 
+/// Lowest spin efficiency used for idle drain (at most 10× the base drain).
+const MIN_DRAIN_EFFICIENCY: f32 = 0.1;
+
 /// A single stat modifier with add / mul / clamp.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StatModifier {
@@ -48,10 +51,13 @@ pub struct ModifierSet {
     pub accel: StatModifier,
     /// Each control_reduction source contributes a ratio r_i.
     pub control_reduction_sources: Vec<f32>,
+    /// Stun duration cap in seconds; merging keeps the tightest.
+    pub stun_cap: Option<f32>,
     pub stability: StatModifier,
     pub spin_efficiency: StatModifier,
     pub damage_out_mult: Multiplier,
     pub damage_in_mult: Multiplier,
+    pub damage_in_by_kind: DamageIntake,
     pub fire_rate_mult: Multiplier,
 }
 
@@ -63,10 +69,12 @@ impl ModifierSet {
             move_speed: StatModifier::identity(),
             accel: StatModifier::identity(),
             control_reduction_sources: Vec::new(),
+            stun_cap: None,
             stability: StatModifier::identity(),
             spin_efficiency: StatModifier::identity(),
             damage_out_mult: Multiplier::one(),
             damage_in_mult: Multiplier::one(),
+            damage_in_by_kind: DamageIntake::default(),
             fire_rate_mult: Multiplier::one(),
         }
     }
@@ -83,12 +91,17 @@ impl ModifierSet {
         self.accel.mul *= other.accel.mul;
         self.control_reduction_sources
             .extend(&other.control_reduction_sources);
+        self.stun_cap = match (self.stun_cap, other.stun_cap) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
         self.stability.add += other.stability.add;
         self.stability.mul *= other.stability.mul;
         self.spin_efficiency.add += other.spin_efficiency.add;
         self.spin_efficiency.mul *= other.spin_efficiency.mul;
         self.damage_out_mult = self.damage_out_mult * other.damage_out_mult;
         self.damage_in_mult = self.damage_in_mult * other.damage_in_mult;
+        self.damage_in_by_kind = self.damage_in_by_kind * other.damage_in_by_kind;
         self.fire_rate_mult = self.fire_rate_mult * other.fire_rate_mult;
     }
 
@@ -115,6 +128,8 @@ impl ModifierSet {
 
         let stability = self.stability.apply(0.0).max(0.0);
         let spin_efficiency = self.spin_efficiency.apply(1.0).clamp(0.0, 10.0);
+        // Idle drain divides by efficiency; a floor keeps 0 from meaning infinite drain
+        let drain_efficiency = spin_efficiency.max(MIN_DRAIN_EFFICIENCY);
 
         EffectiveStats {
             spin_hp_max: super::types::SpinHp(spin_hp_max),
@@ -122,13 +137,140 @@ impl ModifierSet {
             move_speed: super::types::MetersPerSec(move_speed),
             accel,
             control_multiplier,
-            spin_drain_idle_per_sec: tuning.spin_drain_idle_per_sec / spin_efficiency,
+            stun_cap: self.stun_cap.map(|cap| cap.max(0.0)),
+            spin_drain_idle_per_sec: tuning.spin_drain_idle_per_sec / drain_efficiency,
             spin_drain_on_wall_hit: tuning.spin_drain_on_wall_hit,
             spin_drain_on_top_hit: tuning.spin_drain_on_top_hit,
             stability,
             damage_out_mult: self.damage_out_mult,
             damage_in_mult: self.damage_in_mult,
+            damage_in_by_kind: self.damage_in_by_kind,
             fire_rate_mult: self.fire_rate_mult,
         }
     }
 }
+
+/// Weighted "power budget" score of a build's effective stats, used by fair
+/// mode to reject absurd builds. Each stat is normalized against
+/// `EffectiveStats::default()`, so a stock top scores about 100.
+pub fn power_score(eff: &EffectiveStats) -> f32 {
+    let base = EffectiveStats::default();
+    let hp = eff.spin_hp_max.0 / base.spin_hp_max.0;
+    let speed = eff.move_speed.0 / base.move_speed.0;
+    let accel = eff.accel / base.accel;
+    let damage_out = eff.damage_out_mult.0;
+    let fire_rate = eff.fire_rate_mult.0;
+    // Taking less damage is worth as much as dealing more; per-kind
+    // resistances count by their average
+    let by_kind = eff.damage_in_by_kind;
+    let kind_avg = (by_kind.collision.0 + by_kind.melee.0 + by_kind.projectile.0 + by_kind.wall.0 + by_kind.obstacle.0) / 5.0;
+    let toughness = 1.0 / (eff.damage_in_mult.0 * kind_avg).max(0.1);
+    let control_resist = 1.0 - eff.control_multiplier;
+
+    25.0 * hp
+        + 15.0 * speed
+        + 10.0 * accel
+        + 20.0 * damage_out
+        + 10.0 * fire_rate
+        + 20.0 * toughness
+        + 20.0 * control_resist
+        + 10.0 * eff.stability
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_control_reduction(r: f32) -> ModifierSet {
+        let mut mods = ModifierSet::new();
+        mods.control_reduction_sources.push(r);
+        mods
+    }
+
+    #[test]
+    fn control_reduction_stacks_multiplicatively() {
+        let tuning = Tuning::default();
+        let base = BaseStats::default();
+
+        // (1 + 0.2)(1 + 0.2) - 1 = 0.44 → m = 0.56
+        let mut mods = with_control_reduction(0.2);
+        mods.merge(&with_control_reduction(0.2));
+        let eff = mods.compute_effective(&base, &tuning);
+        assert!((eff.control_multiplier - 0.56).abs() < 1e-5);
+
+        // (1.5)(1.5) - 1 = 1.25 → m = max(0, -0.25) = 0, not 1 - (0.5 + 0.5)
+        let mut mods = with_control_reduction(0.5);
+        mods.merge(&with_control_reduction(0.5));
+        assert_eq!(mods.control_reduction_sources, vec![0.5, 0.5]);
+        let eff = mods.compute_effective(&base, &tuning);
+        assert_eq!(eff.control_multiplier, 0.0);
+
+        // The wheel's own reduction is one more factor
+        let base = BaseStats { control_reduction: 0.5, ..BaseStats::default() };
+        let eff = with_control_reduction(0.2).compute_effective(&base, &tuning);
+        assert!((eff.control_multiplier - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn damage_multipliers_multiply() {
+        let tuning = Tuning::default();
+        let mut mods = ModifierSet::new();
+        let mut other = ModifierSet::new();
+        mods.damage_out_mult = Multiplier::new(1.5);
+        other.damage_out_mult = Multiplier::new(2.0);
+        mods.damage_in_mult = Multiplier::new(0.5);
+        other.damage_in_mult = Multiplier::new(0.8);
+        mods.merge(&other);
+
+        let eff = mods.compute_effective(&BaseStats::default(), &tuning);
+        assert!((eff.damage_out_mult.0 - 3.0).abs() < 1e-5);
+        assert!((eff.damage_in_mult.0 - 0.4).abs() < 1e-5);
+
+        // Products stay within Multiplier's range
+        mods.merge(&other);
+        mods.merge(&other);
+        assert_eq!(mods.damage_out_mult.0, Multiplier::MAX);
+    }
+
+    #[test]
+    fn spin_efficiency_clamps_to_range() {
+        let tuning = Tuning::default();
+        let base = BaseStats::default();
+
+        let mut mods = ModifierSet::new();
+        mods.spin_efficiency.mul = 50.0;
+        let eff = mods.compute_effective(&base, &tuning);
+        assert!((eff.spin_drain_idle_per_sec - tuning.spin_drain_idle_per_sec / 10.0).abs() < 1e-5);
+
+        // Efficiency clamps to 0, but drain stays finite
+        mods.spin_efficiency.mul = 1.0;
+        mods.spin_efficiency.add = -5.0;
+        let eff = mods.compute_effective(&base, &tuning);
+        assert!(eff.spin_drain_idle_per_sec.is_finite());
+        assert!((eff.spin_drain_idle_per_sec - tuning.spin_drain_idle_per_sec / MIN_DRAIN_EFFICIENCY).abs() < 1e-3);
+    }
+
+    #[test]
+    fn efficient_shaft_halves_idle_drain() {
+        let tuning = Tuning::default();
+        let shaft = crate::game::parts::shaft::ShaftSpec { spin_efficiency: 2.0, ..Default::default() };
+        let eff = shaft.to_modifiers().compute_effective(&BaseStats::default(), &tuning);
+        assert!((eff.spin_drain_idle_per_sec - tuning.spin_drain_idle_per_sec / 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn move_speed_clamps_to_max_speed() {
+        let tuning = Tuning::default();
+        let base = BaseStats::default();
+
+        let mut mods = ModifierSet::new();
+        mods.move_speed.mul = 100.0;
+        let eff = mods.compute_effective(&base, &tuning);
+        assert_eq!(eff.move_speed.0, tuning.max_speed);
+
+        mods.move_speed.mul = 1.0;
+        mods.move_speed.add = -1000.0;
+        let eff = mods.compute_effective(&base, &tuning);
+        assert_eq!(eff.move_speed.0, 0.0);
+    }
+}
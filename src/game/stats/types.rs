@@ -16,6 +16,9 @@ impl SpinHp {
         Self(v)
     }
 
+    pub fn add_clamped(self, delta: f32, max: SpinHp) -> Self {
+        Self((self.0 + delta).min(max.0))
+    }
 }
 
 /// Radius in world units.
@@ -103,6 +106,8 @@ pub enum PartSlot {
 pub enum AimMode {
     FollowSpin,
     SeekNearestTarget,
+    /// Along the top's direction of travel, whatever its spin.
+    FixedForward,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -112,11 +117,19 @@ pub enum WeaponKind {
     Bow,    // ranged — arrow projectile
     #[serde(alias = "Ranged")]
     Gun,    // ranged — bullet projectile
+    Hybrid, // melee blade + bullet projectile
+    Unarmed, // no weapon — collision damage only
 }
 
 impl WeaponKind {
-    pub fn is_ranged(self) -> bool {
-        matches!(self, WeaponKind::Bow | WeaponKind::Gun)
+    /// Uses a `MeleeSpec` (Sword, Hybrid).
+    pub fn has_melee(self) -> bool {
+        matches!(self, WeaponKind::Sword | WeaponKind::Hybrid)
+    }
+
+    /// Uses a `RangedSpec` (Bow, Gun, Hybrid).
+    pub fn has_ranged(self) -> bool {
+        matches!(self, WeaponKind::Bow | WeaponKind::Gun | WeaponKind::Hybrid)
     }
 
     pub fn display_name(self) -> &'static str {
@@ -124,19 +137,21 @@ impl WeaponKind {
             WeaponKind::Sword => "Sword",
             WeaponKind::Bow => "Bow",
             WeaponKind::Gun => "Gun",
+            WeaponKind::Hybrid => "Hybrid",
+            WeaponKind::Unarmed => "Unarmed",
         }
     }
 
     pub fn all_variants() -> &'static [WeaponKind] {
-        &[WeaponKind::Sword, WeaponKind::Bow, WeaponKind::Gun]
+        &[WeaponKind::Sword, WeaponKind::Bow, WeaponKind::Gun, WeaponKind::Hybrid, WeaponKind::Unarmed]
     }
 
     /// Returns (visual_len, visual_thick) for the projectile sprite.
     pub fn projectile_dims(self) -> (f32, f32) {
         match self {
             WeaponKind::Bow => (1.4, 0.25),
-            WeaponKind::Gun => (0.6, 0.5),
-            WeaponKind::Sword => (1.0, 1.0),
+            WeaponKind::Gun | WeaponKind::Hybrid => (0.6, 0.5),
+            WeaponKind::Sword | WeaponKind::Unarmed => (1.0, 1.0),
         }
     }
 }
@@ -176,9 +191,48 @@ pub enum DamageKind {
     Obstacle,
 }
 
+/// Incoming damage multiplier per `DamageKind`, applied on top of
+/// `damage_in_mult` (e.g. armored against projectiles but weak to melee).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DamageIntake {
+    pub collision: Multiplier,
+    pub melee: Multiplier,
+    pub projectile: Multiplier,
+    pub wall: Multiplier,
+    pub obstacle: Multiplier,
+}
+
+impl DamageIntake {
+    pub fn get(&self, kind: DamageKind) -> Multiplier {
+        match kind {
+            DamageKind::Collision => self.collision,
+            DamageKind::Melee => self.melee,
+            DamageKind::Projectile => self.projectile,
+            DamageKind::Wall => self.wall,
+            DamageKind::Obstacle => self.obstacle,
+        }
+    }
+}
+
+impl Mul for DamageIntake {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            collision: self.collision * rhs.collision,
+            melee: self.melee * rhs.melee,
+            projectile: self.projectile * rhs.projectile,
+            wall: self.wall * rhs.wall,
+            obstacle: self.obstacle * rhs.obstacle,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CollisionBehavior {
     Solid,
     DamageOnHit,
     ApplyControlOnHit,
+    /// Reflects tops with extra speed (`Tuning::bumper_gain`) instead of damaging them.
+    Bumper,
 }
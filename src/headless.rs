@@ -0,0 +1,199 @@
+//! Headless battles: the simulation without window, renderer, audio or assets.
+//!
+//! Used by `cargo run -- --headless` and by tests that need whole battles.
+//! Fixed ticks are driven manually, one `FixedMain` run per `step`, so results
+//! don't depend on wall-clock time.
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use bevy::app::FixedMain;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::state::state::StateTransition;
+
+use crate::config::logging::DebugLogging;
+use crate::config::tuning::Tuning;
+use crate::game::components::*;
+use crate::game::ai::AiDifficulty;
+use crate::game::events::{CollisionMessage, DamageTakenMessage, GameEvent};
+use crate::game::parts::{registry::PartRegistry, Build};
+use crate::game::rng::GameRng;
+use crate::plugins::game_plugin::{spawn_top, SimulationPlugin};
+
+/// How a headless battle ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BattleOutcome {
+    /// Build id of the surviving top.
+    Winner(String),
+    /// Both tops reached 0 spin HP on the same tick.
+    Draw,
+    /// No KO or time decision within the `max_seconds` given to `run`.
+    Timeout,
+}
+
+/// A two-top battle on a default arena (no map placements), already launched.
+pub struct HeadlessBattle {
+    pub app: App,
+    /// Fixed ticks simulated so far.
+    pub ticks: u64,
+}
+
+impl HeadlessBattle {
+    /// Spawn both builds at the usual start slots, facing each other, and
+    /// enter `GamePhase::Battle` (which launches them).
+    pub fn new(tuning: Tuning, registry: PartRegistry, p1_build_id: &str, p2_build_id: &str) -> Result<Self, String> {
+        let p1_build = resolve(&registry, p1_build_id)?;
+        let p2_build = resolve(&registry, p2_build_id)?;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_resource(DebugLogging::default())
+            .insert_resource(ArenaRadius(tuning.arena_radius))
+            .insert_resource(ArenaHazards::default())
+            .insert_resource(GameRng::for_battle(tuning.rng_seed))
+            .insert_resource(registry)
+            .add_plugins(SimulationPlugin);
+        app.finish();
+        app.cleanup();
+
+        let world = app.world_mut();
+        let p1_stats = p1_build.combined_modifiers().compute_effective(&p1_build.wheel, &tuning);
+        let p2_stats = p2_build.combined_modifiers().compute_effective(&p2_build.wheel, &tuning);
+        let mut commands = world.commands();
+        spawn_top(&mut commands, &p1_build, p1_stats, Vec2::new(-3.0, 0.0), 0.0).insert(PlayerControlled);
+        spawn_top(&mut commands, &p2_build, p2_stats, Vec2::new(3.0, 0.0), PI).insert((AiControlled, AiSteer::new(AiDifficulty::default(), &tuning)));
+        world.insert_resource(tuning);
+        world.flush();
+
+        world.resource_mut::<NextState<GamePhase>>().set(GamePhase::Battle);
+        world.run_schedule(StateTransition);
+
+        Ok(Self { app, ticks: 0 })
+    }
+
+    /// Run one fixed tick of `tuning.dt` seconds.
+    pub fn step(&mut self) {
+        let world = self.app.world_mut();
+        let dt = Duration::from_secs_f32(world.resource::<Tuning>().dt);
+        world.resource_mut::<Time<Fixed>>().advance_by(dt);
+        *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+        world.run_schedule(FixedMain);
+        // Normally done in `First`; keeps message buffers from growing
+        world.resource_mut::<Messages<GameEvent>>().update();
+        world.resource_mut::<Messages<CollisionMessage>>().update();
+        world.resource_mut::<Messages<DamageTakenMessage>>().update();
+        self.ticks += 1;
+    }
+
+    /// Battle time simulated so far, in seconds.
+    pub fn elapsed(&self) -> f32 {
+        self.ticks as f32 * self.app.world().resource::<Tuning>().dt
+    }
+
+    /// `Some` once a top has reached 0 spin HP or the match timer decided the
+    /// battle on spin HP (the game's GameOver conditions).
+    pub fn outcome(&mut self) -> Option<BattleOutcome> {
+        let world = self.app.world_mut();
+        let decided = world.resource::<MatchTimer>().decided;
+        let mut tops = world.query_filtered::<(&SpinHpCurrent, &TopBuild), With<Top>>();
+        let mut defeated = false;
+        let mut survivors = Vec::new();
+        for (spin, build) in tops.iter(world) {
+            if spin.0 .0 <= 0.0 {
+                defeated = true;
+            } else {
+                survivors.push((spin.0 .0, build.0.id.clone()));
+            }
+        }
+        if !defeated {
+            if !decided {
+                return None;
+            }
+            let leader = survivors.into_iter().max_by(|a, b| a.0.total_cmp(&b.0));
+            return leader.map(|(_, id)| BattleOutcome::Winner(id));
+        }
+        let survivors: Vec<String> = survivors.into_iter().map(|(_, id)| id).collect();
+        Some(match survivors.as_slice() {
+            [winner] => BattleOutcome::Winner(winner.clone()),
+            _ => BattleOutcome::Draw,
+        })
+    }
+
+    /// Step until the battle ends or `max_seconds` of battle time have passed.
+    pub fn run(&mut self, max_seconds: f32) -> BattleOutcome {
+        loop {
+            if let Some(outcome) = self.outcome() {
+                return outcome;
+            }
+            if self.elapsed() >= max_seconds {
+                return BattleOutcome::Timeout;
+            }
+            self.step();
+        }
+    }
+}
+
+fn resolve(registry: &PartRegistry, build_id: &str) -> Result<Build, String> {
+    registry
+        .resolve_build_id(build_id)
+        .ok_or_else(|| format!("build '{}' is missing or references missing parts", build_id))
+}
+
+/// `--headless [p1_build] [p2_build] [max_seconds]`: run one battle between
+/// default-registry builds and print the result. Returns the process exit code.
+pub fn run_cli(args: &[String], tuning: Tuning) -> i32 {
+    let p1 = args.first().map(String::as_str).unwrap_or("default_blade");
+    let p2 = args.get(1).map(String::as_str).unwrap_or("default_blaster");
+    let max_seconds = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(120.0);
+
+    let mut battle = match HeadlessBattle::new(tuning, PartRegistry::with_defaults(), p1, p2) {
+        Ok(battle) => battle,
+        Err(e) => {
+            eprintln!("headless: {}", e);
+            return 2;
+        }
+    };
+    let outcome = battle.run(max_seconds);
+    let result = match &outcome {
+        BattleOutcome::Winner(id) => format!("winner {}", id),
+        BattleOutcome::Draw => "draw".to_string(),
+        BattleOutcome::Timeout => "timeout".to_string(),
+    };
+    println!("{} vs {}: {} after {:.2}s ({} ticks)", p1, p2, result, battle.elapsed(), battle.ticks);
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Per-top spin HP and position as raw bits, in query order.
+    fn snapshot(battle: &mut HeadlessBattle) -> Vec<(String, u32, [u32; 2])> {
+        let world = battle.app.world_mut();
+        let mut tops = world.query_filtered::<(&TopBuild, &SpinHpCurrent, &Transform), With<Top>>();
+        tops.iter(world)
+            .map(|(build, spin, tf)| {
+                (build.0.id.clone(), spin.0 .0.to_bits(), [tf.translation.x.to_bits(), tf.translation.y.to_bits()])
+            })
+            .collect()
+    }
+
+    fn run_battle(seed: u64) -> (BattleOutcome, u64, Vec<(String, u32, [u32; 2])>) {
+        let tuning = Tuning { rng_seed: Some(seed), ..Tuning::default() };
+        let mut battle = HeadlessBattle::new(tuning, PartRegistry::with_defaults(), "default_blade", "default_blaster")
+            .expect("default builds resolve");
+        let outcome = battle.run(60.0);
+        let snapshot = snapshot(&mut battle);
+        (outcome, battle.ticks, snapshot)
+    }
+
+    #[test]
+    fn same_seed_replays_identically() {
+        let first = run_battle(42);
+        let second = run_battle(42);
+        assert_eq!(first.0, second.0, "outcome differs");
+        assert_eq!(first.1, second.1, "battle length differs");
+        assert_eq!(first.2, second.2, "final spin HP or positions differ");
+    }
+}
@@ -1,17 +1,25 @@
 mod assets_map;
 mod config;
 mod game;
+mod headless;
 mod plugins;
 mod storage;
 
 use bevy::prelude::*;
 
+use config::logging::DebugLogging;
 use config::tuning::Tuning;
-use plugins::{design_plugin::DesignPlugin, game_plugin::GamePlugin, map_design_plugin::MapDesignPlugin, menu_plugin::MenuPlugin, storage_plugin::StoragePlugin, ui_plugin::UiPlugin};
+use plugins::{debug_plugin::DebugPlugin, design_plugin::DesignPlugin, game_plugin::GamePlugin, map_design_plugin::MapDesignPlugin, menu_plugin::MenuPlugin, settings_plugin::SettingsPlugin, storage_plugin::StoragePlugin, tutorial_plugin::TutorialPlugin, ui_plugin::UiPlugin};
 
 fn main() {
     let tuning = Tuning::load_or_default();
 
+    // Battle simulation only, no window (CI / balance tooling)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|a| a == "--headless") {
+        std::process::exit(headless::run_cli(&args[1..], tuning));
+    }
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -22,6 +30,7 @@ fn main() {
             ..default()
         }))
         .insert_resource(Time::<Fixed>::from_seconds(tuning.dt as f64))
+        .insert_resource(DebugLogging { enabled: tuning.debug_logging })
         .insert_resource(tuning)
         .add_plugins(GamePlugin)
         .add_plugins(MenuPlugin)
@@ -29,5 +38,8 @@ fn main() {
         .add_plugins(StoragePlugin)
         .add_plugins(DesignPlugin)
         .add_plugins(MapDesignPlugin)
+        .add_plugins(SettingsPlugin)
+        .add_plugins(TutorialPlugin)
+        .add_plugins(DebugPlugin)
         .run();
 }
@@ -0,0 +1,205 @@
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::config::logging::DebugLogging;
+use crate::config::tuning::Tuning;
+use crate::game::components::*;
+use crate::plugins::ui_plugin::MINIMAP_SIZE;
+
+// ── Data types ───────────────────────────────────────────────────────
+
+/// Fixed ticks run this battle, shown on the debug overlay. Frame stepping
+/// itself is the player pause (P, then N per tick; see `battle_pause_input`).
+#[derive(Resource, Default)]
+struct BattleTickCount(u64);
+
+/// F6 per-tick battle readout (debug builds, or `tuning.debug_tools`):
+/// the tick counter plus each top's position, velocity and spin HP, meant
+/// to be watched while stepping a paused battle with N.
+#[derive(Resource, Default)]
+pub struct TickReadout {
+    pub visible: bool,
+}
+
+/// F3 performance overlay (FPS, frame time, entity counts).
+/// Available in release builds too, so players can report slowdowns.
+#[derive(Resource, Default)]
+pub struct PerfOverlay {
+    pub visible: bool,
+}
+
+/// Debug tools are always on in debug builds; release builds need `tuning.debug_tools`.
+pub fn debug_tools_enabled(tuning: &Tuning) -> bool {
+    cfg!(debug_assertions) || tuning.debug_tools
+}
+
+// ── Marker components ────────────────────────────────────────────────
+
+#[derive(Component)]
+struct DebugOverlayText;
+
+#[derive(Component)]
+struct PerfOverlayText;
+
+// ── Plugin ───────────────────────────────────────────────────────────
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BattleTickCount>();
+        app.init_resource::<TickReadout>();
+        app.init_resource::<PerfOverlay>();
+        app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        app.add_systems(Startup, (spawn_debug_overlay, spawn_perf_overlay));
+        app.add_systems(FixedPreUpdate, count_battle_ticks.run_if(in_state(GamePhase::Battle)));
+        app.add_systems(OnEnter(GamePhase::Aiming), reset_tick_count);
+        app.add_systems(
+            Update,
+            (debug_logging_input, tick_readout_input, update_debug_overlay, perf_overlay_input, update_perf_overlay),
+        );
+    }
+}
+
+// ── Tick counter ─────────────────────────────────────────────────────
+
+/// FixedPreUpdate runs once per fixed tick, so paused battles don't count.
+fn count_battle_ticks(mut ticks: ResMut<BattleTickCount>) {
+    ticks.0 += 1;
+}
+
+fn reset_tick_count(mut ticks: ResMut<BattleTickCount>) {
+    ticks.0 = 0;
+}
+
+/// F8 toggles gameplay diagnostics logging.
+fn debug_logging_input(keyboard: Res<ButtonInput<KeyCode>>, mut log: ResMut<DebugLogging>) {
+    if keyboard.just_pressed(KeyCode::F8) {
+        log.enabled = !log.enabled;
+        info!("Debug logging {}", if log.enabled { "ON" } else { "OFF" });
+    }
+}
+
+// ── Overlay ──────────────────────────────────────────────────────────
+
+/// F6 toggles the per-tick readout; ignored unless debug tools are enabled.
+fn tick_readout_input(keyboard: Res<ButtonInput<KeyCode>>, tuning: Res<Tuning>, mut readout: ResMut<TickReadout>) {
+    if debug_tools_enabled(&tuning) && keyboard.just_pressed(KeyCode::F6) {
+        readout.visible = !readout.visible;
+    }
+}
+
+fn spawn_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        DebugOverlayText,
+        Text::new(""),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(0.6, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(30),
+        Visibility::Hidden,
+    ));
+}
+
+/// During a battle with the F6 readout on (debug tools only), list each top's
+/// position, velocity and spin HP as of the last fixed tick.
+fn update_debug_overlay(
+    tuning: Res<Tuning>,
+    readout: Res<TickReadout>,
+    state: Res<State<GamePhase>>,
+    virtual_time: Res<Time<Virtual>>,
+    ticks: Res<BattleTickCount>,
+    tops: Query<(&Transform, &Velocity, &SpinHpCurrent, &TopBuild, Has<PlayerControlled>), With<Top>>,
+    mut overlay: Query<(&mut Text, &mut Visibility), With<DebugOverlayText>>,
+) {
+    let Ok((mut text, mut visibility)) = overlay.single_mut() else { return };
+    let shown = readout.visible && debug_tools_enabled(&tuning) && *state.get() == GamePhase::Battle;
+    if !shown {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    *visibility = Visibility::Visible;
+
+    let controls = if virtual_time.is_paused() { "N step, P resume" } else { "P pause" };
+    let mut lines = vec![format!("tick {}  ({}, F6 hide)", ticks.0, controls)];
+    for (tf, vel, hp, build, is_p1) in &tops {
+        let pos = tf.translation.truncate();
+        lines.push(format!(
+            "{} {}  pos ({:.3}, {:.3})  vel ({:.3}, {:.3}) |{:.3}|  hp {:.2}",
+            if is_p1 { "P1" } else { "P2" },
+            build.0.name,
+            pos.x, pos.y,
+            vel.0.x, vel.0.y, vel.0.length(),
+            hp.0 .0,
+        ));
+    }
+    **text = lines.join("\n");
+}
+
+// ── Performance overlay ──────────────────────────────────────────────
+
+/// F3 toggles the performance overlay.
+fn perf_overlay_input(keyboard: Res<ButtonInput<KeyCode>>, mut perf: ResMut<PerfOverlay>) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        perf.visible = !perf.visible;
+    }
+}
+
+/// Sits under the battle minimap, which owns the top-right corner.
+fn spawn_perf_overlay(mut commands: Commands) {
+    commands.spawn((
+        PerfOverlayText,
+        Text::new(""),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.9, 0.5)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(10.0 + MINIMAP_SIZE + 8.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(30),
+        Visibility::Hidden,
+    ));
+}
+
+/// FPS / frame time from Bevy diagnostics plus live entity counts.
+fn update_perf_overlay(
+    perf: Res<PerfOverlay>,
+    diagnostics: Res<DiagnosticsStore>,
+    tops: Query<(), With<Top>>,
+    projectiles: Query<(), With<ProjectileMarker>>,
+    pool: Res<ProjectilePool>,
+    obstacles: Query<(), With<ObstacleMarker>>,
+    particles: Query<(), With<BurstParticle>>,
+    all: Query<()>,
+    mut overlay: Query<(&mut Text, &mut Visibility), With<PerfOverlayText>>,
+) {
+    let Ok((mut text, mut visibility)) = overlay.single_mut() else { return };
+    if !perf.visible {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    *visibility = Visibility::Visible;
+
+    let smoothed = |path| diagnostics.get(path).and_then(|d| d.smoothed()).unwrap_or(0.0);
+    **text = format!(
+        "FPS {:.0}  ({:.2} ms)\ntops {}  projectiles {} (+{} pooled)\nobstacles {}  particles {}\nentities {}  (F3 hide)",
+        smoothed(&FrameTimeDiagnosticsPlugin::FPS),
+        smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+        tops.iter().len(),
+        projectiles.iter().len(),
+        pool.len(),
+        obstacles.iter().len(),
+        particles.iter().len(),
+        all.iter().len(),
+    );
+}
@@ -3,17 +3,26 @@ use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::SystemTime;
 
+use crate::assets_map::{
+    asset_file, copy_into_assets, import_image, part_image_path, projectile_image_path, remove_asset_file, weapon_sfx_path, GameAssets,
+    IMAGE_EXTENSIONS,
+};
 use crate::config::tuning::Tuning;
 use crate::game::components::GamePhase;
-use crate::game::parts::registry::PartRegistry;
+use crate::game::parts::registry::{PartRegistry, SlotLocks};
+use crate::game::parts::Build;
 use crate::game::parts::weapon_wheel::{MeleeSpec, RangedSpec, WeaponWheelSpec};
 use crate::game::parts::shaft::ShaftSpec;
 use crate::game::parts::chassis::ChassisSpec;
-use crate::game::parts::trait_screw::TraitScrewSpec;
+use crate::game::parts::trait_screw::{TraitHookKind, TraitScrewSpec};
 use crate::game::stats::base::BaseStats;
-use crate::game::stats::types::{AimMode, MetersPerSec, PartSlot, Radius, SpinHp, WeaponKind};
+use crate::game::stats::effective::EffectiveStats;
+use crate::game::stats::modifier::{power_score, ModifierSet, StatModifier};
+use crate::game::stats::types::{AimMode, ControlEffect, DamageIntake, MetersPerSec, Multiplier, PartSlot, Radius, SpinHp, WeaponKind};
+use crate::plugins::menu_plugin::GameSelection;
 use crate::plugins::storage_plugin::TokioRuntime;
 use crate::storage::sqlite_repo::SqliteRepo;
 
@@ -29,6 +38,9 @@ const COLOR_CARD: Color = Color::srgba(0.12, 0.14, 0.20, 1.0);
 const COLOR_CARD_SELECTED: Color = Color::srgba(0.15, 0.35, 0.60, 1.0);
 const COLOR_INPUT_BG: Color = Color::srgba(0.10, 0.10, 0.16, 1.0);
 const COLOR_INPUT_FOCUS: Color = Color::srgba(0.15, 0.15, 0.25, 1.0);
+const COLOR_WARN: Color = Color::srgba(1.0, 0.45, 0.35, 1.0);
+const COLOR_DANGER: Color = Color::srgba(0.8, 0.2, 0.2, 1.0);
+const COLOR_IMAGE_PLACEHOLDER: Color = Color::srgba(0.15, 0.15, 0.22, 1.0);
 
 // ── Plugin ──────────────────────────────────────────────────────────
 
@@ -45,43 +57,48 @@ impl Plugin for DesignPlugin {
 
         // ManageParts
         app.add_systems(OnEnter(GamePhase::ManageParts), spawn_manage_parts);
-        app.add_systems(OnExit(GamePhase::ManageParts), despawn::<ScreenRoot>);
-        app.add_systems(Update, manage_parts_system.run_if(in_state(GamePhase::ManageParts)));
+        app.add_systems(OnExit(GamePhase::ManageParts), (remember_manage_filter, clear_pending_delete, despawn::<ScreenRoot>));
+        app.add_systems(Update, (text_input_system, filter_cards_system, manage_parts_system, confirm_delete_system).chain().run_if(in_state(GamePhase::ManageParts)));
 
         // EditWheel
         app.add_systems(OnEnter(GamePhase::EditWheel), spawn_wheel_editor);
-        app.add_systems(OnExit(GamePhase::EditWheel), despawn::<ScreenRoot>);
-        app.add_systems(Update, (text_input_system, wheel_editor_system).chain().run_if(in_state(GamePhase::EditWheel)));
+        app.add_systems(OnExit(GamePhase::EditWheel), (despawn::<ScreenRoot>, clear_save_error));
+        app.add_systems(Update, (text_input_system, copy_from_system, wheel_editor_system, clear_image_system, wheel_live_stats_system, save_error_system).chain().run_if(in_state(GamePhase::EditWheel)));
 
         // EditShaft
         app.add_systems(OnEnter(GamePhase::EditShaft), spawn_shaft_editor);
-        app.add_systems(OnExit(GamePhase::EditShaft), despawn::<ScreenRoot>);
-        app.add_systems(Update, (text_input_system, shaft_editor_system).chain().run_if(in_state(GamePhase::EditShaft)));
+        app.add_systems(OnExit(GamePhase::EditShaft), (despawn::<ScreenRoot>, clear_save_error));
+        app.add_systems(Update, (text_input_system, copy_from_system, shaft_editor_system, clear_image_system, shaft_live_stats_system, save_error_system).chain().run_if(in_state(GamePhase::EditShaft)));
 
         // EditChassis
         app.add_systems(OnEnter(GamePhase::EditChassis), spawn_chassis_editor);
-        app.add_systems(OnExit(GamePhase::EditChassis), despawn::<ScreenRoot>);
-        app.add_systems(Update, (text_input_system, chassis_editor_system).chain().run_if(in_state(GamePhase::EditChassis)));
+        app.add_systems(OnExit(GamePhase::EditChassis), (despawn::<ScreenRoot>, clear_save_error));
+        app.add_systems(Update, (text_input_system, copy_from_system, chassis_editor_system, clear_image_system, save_error_system).chain().run_if(in_state(GamePhase::EditChassis)));
 
         // EditScrew
         app.add_systems(OnEnter(GamePhase::EditScrew), spawn_screw_editor);
-        app.add_systems(OnExit(GamePhase::EditScrew), despawn::<ScreenRoot>);
-        app.add_systems(Update, (text_input_system, screw_editor_system).chain().run_if(in_state(GamePhase::EditScrew)));
+        app.add_systems(OnExit(GamePhase::EditScrew), (despawn::<ScreenRoot>, clear_save_error));
+        app.add_systems(Update, (text_input_system, copy_from_system, screw_editor_system, clear_image_system, screw_live_stats_system, save_error_system).chain().run_if(in_state(GamePhase::EditScrew)));
 
         // EditWeapon
         app.add_systems(OnEnter(GamePhase::EditWeapon), spawn_weapon_editor);
-        app.add_systems(OnExit(GamePhase::EditWeapon), despawn::<ScreenRoot>);
-        app.add_systems(Update, (text_input_system, weapon_editor_system).chain().run_if(in_state(GamePhase::EditWeapon)));
+        app.add_systems(OnExit(GamePhase::EditWeapon), (despawn::<ScreenRoot>, clear_save_error));
+        app.add_systems(Update, (text_input_system, copy_from_system, weapon_editor_system, clear_image_system, hit_control_selector_system, bounces_toggle_system, weapon_diagram_system, save_error_system).chain().run_if(in_state(GamePhase::EditWeapon)));
 
         // AssembleBuild
         app.add_systems(OnEnter(GamePhase::AssembleBuild), spawn_assemble_build);
         app.add_systems(OnExit(GamePhase::AssembleBuild), despawn::<ScreenRoot>);
         app.add_systems(Update, (text_input_system, assemble_build_system).chain().run_if(in_state(GamePhase::AssembleBuild)));
 
+        // CompareBuilds
+        app.add_systems(OnEnter(GamePhase::CompareBuilds), spawn_compare_builds);
+        app.add_systems(OnExit(GamePhase::CompareBuilds), despawn::<ScreenRoot>);
+        app.add_systems(Update, compare_builds_system.run_if(in_state(GamePhase::CompareBuilds)));
+
         // PickDesignPart
         app.add_systems(OnEnter(GamePhase::PickDesignPart), spawn_pick_design_part);
         app.add_systems(OnExit(GamePhase::PickDesignPart), despawn::<ScreenRoot>);
-        app.add_systems(Update, pick_design_part_system.run_if(in_state(GamePhase::PickDesignPart)));
+        app.add_systems(Update, (text_input_system, filter_cards_system, pick_design_part_system).chain().run_if(in_state(GamePhase::PickDesignPart)));
 
         // Global UI scroll (works for all scroll containers across all screens)
         app.add_systems(Update, ui_scroll_system);
@@ -120,12 +137,20 @@ struct ScreenRoot;
 
 // ── Design State ────────────────────────────────────────────────────
 
+/// What the PickDesignPart screen is choosing for the build being assembled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PickTarget {
+    #[default]
+    TopBody,
+    Part(PartSlot),
+}
+
 #[derive(Resource, Default)]
 pub struct DesignState {
     /// Part ID being edited (None = creating new part)
     pub editing_part_id: Option<String>,
     /// Which slot we're picking for in PickDesignPart
-    pub picking_slot: Option<PartSlot>,
+    pub picking: PickTarget,
     /// Build being assembled
     pub current_build_id: Option<String>,
     pub current_build_wheel_id: String,
@@ -134,10 +159,54 @@ pub struct DesignState {
     pub current_build_chassis_id: String,
     pub current_build_screw_id: String,
     pub current_build_note: String,
+    pub current_build_locks: SlotLocks,
+    /// Locks the edited build was saved with. Those slots stay locked here:
+    /// only locks added since opening the build can be undone.
+    pub current_build_saved_locks: SlotLocks,
     /// Where to return after editor save (DesignHub for create, ManageParts for edit)
     pub return_to_manage: bool,
     /// Error message shown when a delete is blocked (e.g. part used by builds)
     pub delete_error: Option<String>,
+    /// Whether Assemble Build shows the per-part stat breakdown
+    pub show_stat_breakdown: bool,
+    /// Shown under an editor when Save is refused (e.g. a field isn't a number)
+    pub save_error: Option<String>,
+    /// ManageParts: cards show a select checkbox instead of edit/delete icons
+    pub select_mode: bool,
+    /// ManageParts: items checked for "Delete Selected"
+    pub delete_selection: Vec<ManagedItem>,
+    /// CompareBuilds: build ids shown in the left and right columns
+    pub compare_build_ids: [String; 2],
+    /// ManageParts: card filter text, kept while the screen respawns
+    pub manage_filter: String,
+    /// Delete waiting for Confirm in the dialog (ManageParts and the map hub)
+    pub pending_delete: Option<PendingDelete>,
+}
+
+/// What the "Delete X?" dialog removes on Confirm.
+#[derive(Clone, PartialEq)]
+pub enum PendingDelete {
+    Item(ManagedItem),
+    /// Items checked for "Delete Selected" on ManageParts.
+    Many(Vec<ManagedItem>),
+    /// Map id on the map hub.
+    Map(String),
+}
+
+/// A deletable entry on the ManageParts screen.
+#[derive(Clone, PartialEq)]
+pub enum ManagedItem {
+    Wheel(String),
+    Part { slot: PartSlot, id: String },
+    Build(String),
+}
+
+impl ManagedItem {
+    fn id(&self) -> &str {
+        match self {
+            ManagedItem::Wheel(id) | ManagedItem::Part { id, .. } | ManagedItem::Build(id) => id,
+        }
+    }
 }
 
 // ── Text Input Widget ───────────────────────────────────────────────
@@ -147,15 +216,117 @@ struct TextInput {
     value: String,
     focused: bool,
     field_key: String,
+    /// Caret position as a byte index into `value` (always on a char boundary).
+    cursor: usize,
+    /// Stat fields are tinted red while the value doesn't parse.
+    kind: FieldKind,
 }
 
 #[derive(Component)]
 struct TextInputDisplay;
 
+/// Field key of the card filter box on ManageParts and PickDesignPart.
+const FILTER_FIELD_KEY: &str = "card_filter";
+
+/// A card the filter box can hide; holds its lowercased name and id.
+#[derive(Component)]
+struct FilterCard(String);
+
+impl FilterCard {
+    fn new(id: &str, name: &str) -> Self {
+        Self(format!("{}\n{}", name.to_lowercase(), id.to_lowercase()))
+    }
+}
+
+/// What an editor field holds, which decides how its value is validated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    /// Free text; always valid.
+    Text,
+    /// Finite number, read with `read_f32`.
+    Number,
+    /// Finite number, or empty for unset.
+    OptionalNumber,
+    /// Whole number, read with `read_u32`.
+    Integer,
+}
+
+/// Whether a numeric field's value would be read as typed rather than
+/// silently replaced by the default. Text fields are always valid.
+fn numeric_input_valid(input: &TextInput) -> bool {
+    let value = input.value.trim();
+    match input.kind {
+        FieldKind::Text => true,
+        FieldKind::OptionalNumber if value.is_empty() => true,
+        FieldKind::Integer => value.parse::<u32>().is_ok(),
+        FieldKind::Number | FieldKind::OptionalNumber => value.parse::<f32>().is_ok_and(f32::is_finite),
+    }
+}
+
+/// Save-refusal message listing invalid numeric fields among those `relevant`
+/// accepts, or `None` if every one parses.
+fn invalid_numeric_message(inputs: &Query<&TextInput>, relevant: impl Fn(&str) -> bool) -> Option<String> {
+    let bad: Vec<&str> = inputs.iter()
+        .filter(|input| relevant(&input.field_key) && !numeric_input_valid(input))
+        .map(|input| input.field_key.as_str())
+        .collect();
+    (!bad.is_empty()).then(|| format!("Not saved: fix the numbers in {}", bad.join(", ")))
+}
+
+/// Byte index of the char boundary before `cursor` (0 at the start).
+pub fn prev_char_boundary(value: &str, cursor: usize) -> usize {
+    value[..cursor].char_indices().next_back().map_or(0, |(i, _)| i)
+}
+
+/// Byte index of the char boundary after `cursor` (`len` at the end).
+pub fn next_char_boundary(value: &str, cursor: usize) -> usize {
+    value[cursor..].chars().next().map_or(cursor, |c| cursor + c.len_utf8())
+}
+
+/// Clipboard text for pasting into a one-line field: control characters are
+/// dropped and the text stops at the first line break. The flag is true when a
+/// line break was found, so the paste ends input like Enter.
+pub fn clipboard_line() -> Option<(String, bool)> {
+    let text = arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| warn!("Clipboard paste failed: {e}"))
+        .ok()?;
+    let (line, ended) = match text.find(['\n', '\r']) {
+        Some(i) => (&text[..i], true),
+        None => (text.as_str(), false),
+    };
+    Some((line.chars().filter(|c| !c.is_control()).collect(), ended))
+}
+
+/// Ctrl+V (Cmd+V on macOS) or the dedicated Paste key.
+pub fn is_paste(key: &Key, keyboard: &ButtonInput<KeyCode>) -> bool {
+    match key {
+        Key::Paste => true,
+        Key::Character(c) => {
+            c.eq_ignore_ascii_case("v")
+                && keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight])
+        }
+        _ => false,
+    }
+}
+
+/// Field text as shown: a `|` caret while focused, "..." when empty and idle.
+fn text_input_display(input: &TextInput) -> String {
+    if input.focused {
+        let cursor = input.cursor.min(input.value.len());
+        format!("{}|{}", &input.value[..cursor], &input.value[cursor..])
+    } else if input.value.is_empty() {
+        "...".into()
+    } else {
+        input.value.clone()
+    }
+}
+
 fn text_input_system(
     mut inputs: Query<(&Interaction, &mut TextInput, &mut BackgroundColor, &Children)>,
     mut displays: Query<&mut Text, With<TextInputDisplay>>,
     mut keyboard_events: MessageReader<KeyboardInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     // Focus on click
     for (interaction, mut input, mut bg, _) in &mut inputs {
@@ -165,52 +336,307 @@ fn text_input_system(
         }
     }
 
+    // Unfocus all others when one is clicked
+    let any_clicked = inputs.iter().any(|(i, _, _, _)| *i == Interaction::Pressed);
+    if any_clicked {
+        for (interaction, mut input, _, _) in &mut inputs {
+            if *interaction != Interaction::Pressed {
+                input.focused = false;
+            }
+        }
+    }
+
     // Collect keyboard events
     let events: Vec<_> = keyboard_events.read().cloned().collect();
 
     for (_interaction, mut input, mut bg, children) in &mut inputs {
-        if !input.focused {
-            *bg = BackgroundColor(COLOR_INPUT_BG);
-            continue;
-        }
-        *bg = BackgroundColor(COLOR_INPUT_FOCUS);
-
-        for event in &events {
-            if !event.state.is_pressed() {
-                continue;
-            }
-            match &event.logical_key {
-                Key::Backspace => {
-                    input.value.pop();
+        if input.focused {
+            // The value may have been replaced since the last frame (Copy From)
+            let input = &mut *input;
+            input.cursor = input.cursor.min(input.value.len());
+            for event in &events {
+                if !event.state.is_pressed() {
+                    continue;
                 }
-                Key::Escape | Key::Enter => {
-                    input.focused = false;
+                if is_paste(&event.logical_key, &keyboard) {
+                    if let Some((line, ended)) = clipboard_line() {
+                        input.value.insert_str(input.cursor, &line);
+                        input.cursor += line.len();
+                        if ended {
+                            input.focused = false;
+                        }
+                    }
+                    continue;
                 }
-                Key::Character(c) => {
-                    input.value.push_str(c.as_str());
+                match &event.logical_key {
+                    Key::Backspace => {
+                        if input.cursor > 0 {
+                            let prev = prev_char_boundary(&input.value, input.cursor);
+                            input.value.replace_range(prev..input.cursor, "");
+                            input.cursor = prev;
+                        }
+                    }
+                    Key::ArrowLeft => input.cursor = prev_char_boundary(&input.value, input.cursor),
+                    Key::ArrowRight => input.cursor = next_char_boundary(&input.value, input.cursor),
+                    Key::Home => input.cursor = 0,
+                    Key::End => input.cursor = input.value.len(),
+                    Key::Escape | Key::Enter => {
+                        input.focused = false;
+                    }
+                    Key::Character(c) => {
+                        input.value.insert_str(input.cursor, c.as_str());
+                        input.cursor += c.len();
+                    }
+                    // Numbers never contain spaces
+                    Key::Space if input.kind == FieldKind::Text => {
+                        input.value.insert(input.cursor, ' ');
+                        input.cursor += 1;
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
         }
+        *bg = BackgroundColor(if !numeric_input_valid(&input) {
+            COLOR_DANGER
+        } else if input.focused {
+            COLOR_INPUT_FOCUS
+        } else {
+            COLOR_INPUT_BG
+        });
 
-        // Update display text
+        // Update display text (only when it differs, to avoid relayout every frame)
+        let shown = text_input_display(&input);
         for child in children.iter() {
             if let Ok(mut text) = displays.get_mut(child) {
-                **text = if input.value.is_empty() {
-                    "...".into()
-                } else {
-                    input.value.clone()
-                };
+                if **text != shown {
+                    **text = shown.clone();
+                }
             }
         }
     }
+}
 
-    // Unfocus all others when one is clicked
-    let any_clicked = inputs.iter().any(|(i, _, _, _)| *i == Interaction::Pressed);
-    if any_clicked {
-        for (interaction, mut input, _, _) in &mut inputs {
-            if *interaction != Interaction::Pressed {
-                input.focused = false;
+// ── Save Error ──────────────────────────────────────────────────────
+
+/// Part editor line showing `DesignState.save_error`.
+#[derive(Component)]
+struct SaveErrorText;
+
+fn spawn_save_error_text(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        SaveErrorText,
+        Text::new(""),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(COLOR_WARN),
+    ));
+}
+
+fn save_error_system(state: Res<DesignState>, mut q: Query<&mut Text, With<SaveErrorText>>) {
+    if !state.is_changed() {
+        return;
+    }
+    for mut text in &mut q {
+        let msg = state.save_error.clone().unwrap_or_default();
+        if **text != msg {
+            **text = msg;
+        }
+    }
+}
+
+fn clear_save_error(mut state: ResMut<DesignState>) {
+    state.save_error = None;
+}
+
+// ── Copy From ───────────────────────────────────────────────────────
+
+/// Editor button that fills the stat fields from another part of the same
+/// slot. Each press moves on to the next part; id, name and description stay.
+#[derive(Component)]
+struct CopyFromButton {
+    target: PickTarget,
+    next: usize,
+}
+
+#[derive(Component)]
+struct CopyFromLabel;
+
+fn spawn_copy_from_row(parent: &mut ChildSpawnerCommands, target: PickTarget) {
+    parent.spawn((
+        CopyFromButton { target, next: 0 },
+        Button,
+        Node {
+            height: Val::Px(32.0),
+            padding: UiRect::horizontal(Val::Px(12.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BTN),
+    )).with_children(|btn| {
+        btn.spawn((
+            CopyFromLabel,
+            Text::new("Copy values from..."),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+    });
+}
+
+/// Sorted ids of every part the editor for `target` could copy from.
+fn copy_source_ids(registry: &PartRegistry, target: PickTarget, editing: Option<&String>) -> Vec<String> {
+    let mut ids: Vec<String> = match target {
+        PickTarget::TopBody => registry.wheels.keys().cloned().collect(),
+        PickTarget::Part(PartSlot::WeaponWheel) => registry.weapons.keys().cloned().collect(),
+        PickTarget::Part(PartSlot::Shaft) => registry.shafts.keys().cloned().collect(),
+        PickTarget::Part(PartSlot::Chassis) => registry.chassis.keys().cloned().collect(),
+        PickTarget::Part(PartSlot::TraitScrew) => registry.screws.keys().cloned().collect(),
+    };
+    ids.retain(|id| Some(id) != editing);
+    ids.sort();
+    ids
+}
+
+/// A part's name and its stat values keyed by editor `field_key`, formatted
+/// the way the editors fill their fields.
+fn part_field_values(registry: &PartRegistry, target: PickTarget, id: &str) -> Option<(String, Vec<(&'static str, String)>)> {
+    match target {
+        PickTarget::TopBody => registry.wheels.get(id).map(|t| (t.name.clone(), vec![
+            ("spin_hp_max", format!("{}", t.spin_hp_max.0)),
+            ("radius", format!("{}", t.radius.0)),
+            ("move_speed", format!("{}", t.move_speed.0)),
+            ("accel", format!("{}", t.accel)),
+            ("control_reduction", format!("{}", t.control_reduction)),
+        ])),
+        PickTarget::Part(PartSlot::Shaft) => registry.shafts.get(id).map(|s| (s.name.clone(), vec![
+            ("stability", format!("{}", s.stability)),
+            ("spin_efficiency", format!("{}", s.spin_efficiency)),
+        ])),
+        PickTarget::Part(PartSlot::Chassis) => registry.chassis.get(id).map(|c| (c.name.clone(), vec![
+            ("move_speed_add", format!("{}", c.move_speed_add)),
+            ("move_speed_mul", format!("{}", c.move_speed_mul)),
+            ("accel_add", format!("{}", c.accel_add)),
+            ("accel_mul", format!("{}", c.accel_mul)),
+            ("radius_add", format!("{}", c.radius_add)),
+            ("radius_mul", format!("{}", c.radius_mul)),
+        ])),
+        PickTarget::Part(PartSlot::TraitScrew) => registry.screws.get(id).map(|s| (s.name.clone(), vec![
+            ("spin_hp_max_add", format!("{}", s.passive.spin_hp_max_add)),
+            ("control_reduction", format!("{}", s.passive.control_reduction)),
+            ("damage_out_mult", format!("{}", s.passive.damage_out_mult)),
+            ("damage_in_mult", format!("{}", s.passive.damage_in_mult)),
+            ("in_collision", format!("{}", s.passive.damage_in_by_kind.collision.0)),
+            ("in_melee", format!("{}", s.passive.damage_in_by_kind.melee.0)),
+            ("in_projectile", format!("{}", s.passive.damage_in_by_kind.projectile.0)),
+            ("in_wall", format!("{}", s.passive.damage_in_by_kind.wall.0)),
+            ("in_obstacle", format!("{}", s.passive.damage_in_by_kind.obstacle.0)),
+            ("stun_cap", s.passive.stun_cap.map(|c| format!("{}", c)).unwrap_or_default()),
+            ("hook_drop_obstacle", if s.hooks.contains(&TraitHookKind::OnTakeHit) { "1" } else { "0" }.into()),
+            ("hook_thorns", format!("{}", s.thorns())),
+            ("hook_hit_bonus", format!("{}", s.hit_bonus())),
+        ])),
+        PickTarget::Part(PartSlot::WeaponWheel) => registry.weapons.get(id).map(|w| {
+            // Fill both sections so switching kind afterwards still shows copied values
+            let m = w.melee.clone().unwrap_or_default();
+            let r = w.ranged.clone().unwrap_or_default();
+            let (duration, ratio, distance) = hit_control_values(m.hit_control);
+            (w.name.clone(), vec![
+                ("m_base_damage", format!("{}", m.base_damage)),
+                ("m_hit_cooldown", format!("{}", m.hit_cooldown)),
+                ("m_hitbox_radius", format!("{}", m.hitbox_radius)),
+                ("m_hitbox_angle", format!("{}", m.hitbox_angle)),
+                ("m_blade_len", format!("{}", m.blade_len)),
+                ("m_blade_thick", format!("{}", m.blade_thick)),
+                ("m_spin_rate", format!("{}", m.spin_rate_multiplier)),
+                ("m_ctrl_duration", format!("{}", duration)),
+                ("m_ctrl_slow_ratio", format!("{}", ratio)),
+                ("m_ctrl_knockback", format!("{}", distance)),
+                ("r_proj_damage", format!("{}", r.projectile_damage)),
+                ("r_fire_rate", format!("{}", r.fire_rate)),
+                ("r_burst_count", format!("{}", r.burst_count)),
+                ("r_spread_angle", format!("{}", r.spread_angle)),
+                ("r_proj_radius", format!("{}", r.projectile_radius)),
+                ("r_lifetime", format!("{}", r.lifetime.0)),
+                ("r_proj_speed", format!("{}", r.projectile_speed)),
+                ("r_barrel_len", format!("{}", r.barrel_len)),
+                ("r_barrel_thick", format!("{}", r.barrel_thick)),
+                ("r_spin_rate", format!("{}", r.spin_rate_multiplier)),
+            ])
+        }),
+    }
+}
+
+fn copy_from_system(
+    mut buttons: Query<(&Interaction, &mut CopyFromButton, &mut BackgroundColor, &Children), Changed<Interaction>>,
+    mut labels: Query<&mut Text, (With<CopyFromLabel>, Without<TextInputDisplay>, Without<AimModeSelectorLabel>)>,
+    mut inputs: Query<(&mut TextInput, &Children)>,
+    mut displays: Query<&mut Text, (With<TextInputDisplay>, Without<CopyFromLabel>, Without<AimModeSelectorLabel>)>,
+    mut kind_selectors: Query<&mut KindSelector>,
+    mut melee_sections: Query<&mut Node, (With<MeleeSection>, Without<RangedSection>)>,
+    mut ranged_sections: Query<&mut Node, (With<RangedSection>, Without<MeleeSection>)>,
+    mut aim_selectors: Query<(&mut AimModeSelector, &Children)>,
+    mut aim_labels: Query<&mut Text, (With<AimModeSelectorLabel>, Without<CopyFromLabel>, Without<TextInputDisplay>)>,
+    mut hit_controls: Query<&mut HitControlSelector>,
+    mut bounce_toggles: Query<&mut BouncesToggle>,
+    state: Res<DesignState>,
+    registry: Res<PartRegistry>,
+) {
+    for (interaction, mut button, mut bg, children) in &mut buttons {
+        hover_system(interaction, &mut bg);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let ids = copy_source_ids(&registry, button.target, state.editing_part_id.as_ref());
+        let label = if ids.is_empty() {
+            "No other parts to copy".to_string()
+        } else {
+            let id = &ids[button.next % ids.len()];
+            button.next = button.next.wrapping_add(1);
+            let Some((name, values)) = part_field_values(&registry, button.target, id) else { continue };
+            for (mut input, input_children) in &mut inputs {
+                let Some((_, value)) = values.iter().find(|(key, _)| *key == input.field_key) else { continue };
+                input.value = value.clone();
+                input.cursor = input.value.len();
+                for child in input_children.iter() {
+                    if let Ok(mut text) = displays.get_mut(child) {
+                        **text = text_input_display(&input);
+                    }
+                }
+            }
+            // Weapons also take the kind, aim mode and on-hit control
+            if let Some(w) = registry.weapons.get(id).filter(|_| button.target == PickTarget::Part(PartSlot::WeaponWheel)) {
+                for mut selector in &mut kind_selectors {
+                    selector.current = w.kind;
+                }
+                for mut node in &mut melee_sections {
+                    node.display = if w.kind.has_melee() { Display::Flex } else { Display::None };
+                }
+                for mut node in &mut ranged_sections {
+                    node.display = if w.kind.has_ranged() { Display::Flex } else { Display::None };
+                }
+                let aim_mode = w.ranged.as_ref().map_or(AimMode::FollowSpin, |r| r.aim_mode);
+                for (mut selector, aim_children) in &mut aim_selectors {
+                    selector.current = aim_mode;
+                    for child in aim_children.iter() {
+                        if let Ok(mut text) = aim_labels.get_mut(child) {
+                            **text = aim_mode_label(aim_mode).into();
+                        }
+                    }
+                }
+                let control = hit_control_kind(w.melee.as_ref().and_then(|m| m.hit_control));
+                for mut selector in &mut hit_controls {
+                    selector.current = control;
+                }
+                let bounces = w.ranged.as_ref().is_some_and(|r| r.bounces_off_obstacles);
+                for mut toggle in &mut bounce_toggles {
+                    toggle.on = bounces;
+                }
+            }
+            format!("Copied from: {}", name)
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = labels.get_mut(child) {
+                **text = label.clone();
             }
         }
     }
@@ -224,12 +650,20 @@ fn despawn<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>)
     }
 }
 
-fn gen_custom_id() -> String {
-    let nanos = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos();
-    format!("custom_{:08x}", nanos)
+/// Fresh id for a new custom part or build that nothing in `registry` uses.
+/// The counter keeps ids generated within one clock tick apart.
+fn gen_custom_id(registry: &PartRegistry) -> String {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    loop {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos();
+        let id = format!("custom_{:08x}", nanos ^ COUNTER.fetch_add(1, Ordering::Relaxed));
+        if !registry.id_in_use(&id) {
+            return id;
+        }
+    }
 }
 
 fn slot_dir(slot: &PartSlot) -> &'static str {
@@ -244,7 +678,7 @@ fn slot_dir(slot: &PartSlot) -> &'static str {
 fn is_builtin(id: &str) -> bool {
     matches!(
         id,
-        "default_top" | "basic_blade" | "basic_blaster"
+        "default_top" | "basic_blade" | "basic_blaster" | "no_weapon"
             | "standard_shaft" | "standard_chassis" | "standard_screw"
             | "default_shaft" | "default_chassis" | "default_screw"
             | "default_blade" | "default_blaster"
@@ -301,6 +735,7 @@ fn spawn_field_row(
     label: &str,
     description: &str,
     field_key: &str,
+    kind: FieldKind,
     default_value: &str,
 ) {
     parent.spawn(Node {
@@ -327,32 +762,37 @@ fn spawn_field_row(
             ));
         });
 
-        // Text input
-        row.spawn((
-            TextInput {
-                value: default_value.into(),
-                focused: false,
-                field_key: field_key.into(),
-            },
-            Button,
-            Node {
-                width: Val::Px(180.0),
-                height: Val::Px(32.0),
-                justify_content: JustifyContent::FlexStart,
-                align_items: AlignItems::Center,
-                padding: UiRect::horizontal(Val::Px(8.0)),
-                border_radius: BorderRadius::all(Val::Px(4.0)),
-                ..default()
-            },
-            BackgroundColor(COLOR_INPUT_BG),
-        )).with_children(|input| {
-            input.spawn((
-                TextInputDisplay,
-                Text::new(if default_value.is_empty() { "..." } else { default_value }),
-                TextFont { font_size: 15.0, ..default() },
-                TextColor(COLOR_TEXT),
-            ));
-        });
+        spawn_text_input(row, field_key, default_value, 180.0, kind);
+    });
+}
+
+fn spawn_text_input(parent: &mut ChildSpawnerCommands, field_key: &str, default_value: &str, width: f32, kind: FieldKind) {
+    parent.spawn((
+        TextInput {
+            value: default_value.into(),
+            focused: false,
+            field_key: field_key.into(),
+            cursor: default_value.len(),
+            kind,
+        },
+        Button,
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(32.0),
+            justify_content: JustifyContent::FlexStart,
+            align_items: AlignItems::Center,
+            padding: UiRect::horizontal(Val::Px(8.0)),
+            border_radius: BorderRadius::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_INPUT_BG),
+    )).with_children(|input| {
+        input.spawn((
+            TextInputDisplay,
+            Text::new(if default_value.is_empty() { "..." } else { default_value }),
+            TextFont { font_size: 15.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
     });
 }
 
@@ -382,8 +822,13 @@ fn hover_system(interaction: &Interaction, bg: &mut BackgroundColor) {
 }
 
 fn spawn_image_preview(parent: &mut ChildSpawnerCommands, image: Option<Handle<Image>>, size: f32) {
+    spawn_image_preview_with(parent, image, size, ());
+}
+
+fn spawn_image_preview_with(parent: &mut ChildSpawnerCommands, image: Option<Handle<Image>>, size: f32, extra: impl Bundle) {
     if let Some(handle) = image {
         parent.spawn((
+            extra,
             ImageNode { image: handle, ..default() },
             Node {
                 width: Val::Px(size),
@@ -393,27 +838,31 @@ fn spawn_image_preview(parent: &mut ChildSpawnerCommands, image: Option<Handle<I
         ));
     } else {
         parent.spawn((
+            extra,
             Node {
                 width: Val::Px(size),
                 height: Val::Px(size),
                 border_radius: BorderRadius::all(Val::Px(4.0)),
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.15, 0.15, 0.22, 1.0)),
+            BackgroundColor(COLOR_IMAGE_PLACEHOLDER),
         ));
     }
 }
 
 fn spawn_card_frame(
     parent: &mut ChildSpawnerCommands,
+    id: &str,
     name: &str,
     stats_line: &str,
+    description: &str,
     image: Option<Handle<Image>>,
     bg_color: Color,
     width: f32,
     spawn_extras: impl FnOnce(&mut ChildSpawnerCommands),
 ) {
     parent.spawn((
+        FilterCard::new(id, name),
         Node {
             width: Val::Px(width),
             flex_direction: FlexDirection::Column,
@@ -436,10 +885,135 @@ fn spawn_card_frame(
             TextFont { font_size: 11.0, ..default() },
             TextColor(COLOR_TEXT_DIM),
         ));
+        spawn_card_description(card, description);
         spawn_extras(card);
     });
 }
 
+/// Flavor text line under a card's stats; nothing when the part has none.
+fn spawn_card_description(card: &mut ChildSpawnerCommands, description: &str) {
+    if description.is_empty() {
+        return;
+    }
+    card.spawn((
+        Text::new(description),
+        TextFont { font_size: 10.0, ..default() },
+        TextColor(COLOR_TEXT_DIM),
+        TextLayout::new_with_justify(Justify::Center),
+    ));
+}
+
+/// "Filter: [...]" row above a card grid; see `filter_cards_system`.
+fn spawn_filter_row(parent: &mut ChildSpawnerCommands, value: &str) {
+    parent.spawn(Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(12.0),
+        ..default()
+    }).with_children(|row| {
+        row.spawn((
+            Text::new("Filter:"),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+        spawn_text_input(row, FILTER_FIELD_KEY, value, 260.0, FieldKind::Text);
+    });
+}
+
+/// Hide cards whose name and id don't contain the filter text
+/// (case-insensitive). Runs when the filter box changes, including on spawn.
+fn filter_cards_system(
+    inputs: Query<&TextInput, Changed<TextInput>>,
+    mut cards: Query<(&FilterCard, &mut Node)>,
+) {
+    let Some(filter) = inputs.iter().find(|i| i.field_key == FILTER_FIELD_KEY) else {
+        return;
+    };
+    let needle = filter.value.trim().to_lowercase();
+    for (card, mut node) in &mut cards {
+        let display = if card.0.contains(&needle) { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+fn remember_manage_filter(inputs: Query<&TextInput>, mut state: ResMut<DesignState>) {
+    state.manage_filter = read_field(&inputs, FILTER_FIELD_KEY);
+}
+
+// ── Confirm dialog ──────────────────────────────────────────────────
+
+/// A dialog left open when its screen exits is despawned with the screen,
+/// so its pending delete must not survive either.
+pub fn clear_pending_delete(mut state: ResMut<DesignState>) {
+    state.pending_delete = None;
+}
+
+/// Modal overlay asking to confirm a `PendingDelete`.
+#[derive(Component)]
+pub struct ConfirmDialog;
+
+#[derive(Component)]
+pub enum ConfirmDialogButton {
+    Confirm,
+    Cancel,
+}
+
+/// Spawn the confirm dialog as its own UI root tagged with the screen's
+/// `root` marker, so leaving the screen despawns it too. It blocks clicks
+/// to the cards underneath until Confirm or Cancel.
+pub fn spawn_confirm_dialog<R: Component>(commands: &mut Commands, root: R, message: &str) {
+    commands.spawn((
+        root,
+        ConfirmDialog,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        bevy::ui::FocusPolicy::Block,
+        GlobalZIndex(15),
+    )).with_children(|overlay| {
+        overlay.spawn((
+            Node {
+                max_width: Val::Px(520.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(24.0)),
+                border_radius: BorderRadius::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(COLOR_CARD),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new(message),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(COLOR_TEXT),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(16.0),
+                ..default()
+            }).with_children(|row| {
+                spawn_button(row, "Delete", ConfirmDialogButton::Confirm);
+                spawn_button(row, "Cancel", ConfirmDialogButton::Cancel);
+            });
+        });
+    });
+}
+
+/// Dialog message: "Delete 'Name'? This cannot be undone."
+pub fn confirm_delete_message(name: &str) -> String {
+    format!("Delete '{}'? This cannot be undone.", name)
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // DESIGN HUB (Create entry point)
 // ═══════════════════════════════════════════════════════════════════════
@@ -523,29 +1097,30 @@ fn design_hub_system(
     mut q: Query<(&Interaction, &HubButton, &mut BackgroundColor), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GamePhase>>,
     mut state: ResMut<DesignState>,
+    registry: Res<PartRegistry>,
 ) {
     for (interaction, button, mut bg) in &mut q {
         if *interaction == Interaction::Pressed {
             state.return_to_manage = false;
             match button {
                 HubButton::NewWheel => {
-                    state.editing_part_id = Some(gen_custom_id());
+                    state.editing_part_id = Some(gen_custom_id(&registry));
                     next_state.set(GamePhase::EditWheel);
                 }
                 HubButton::NewWeapon => {
-                    state.editing_part_id = Some(gen_custom_id());
+                    state.editing_part_id = Some(gen_custom_id(&registry));
                     next_state.set(GamePhase::EditWeapon);
                 }
                 HubButton::NewShaft => {
-                    state.editing_part_id = Some(gen_custom_id());
+                    state.editing_part_id = Some(gen_custom_id(&registry));
                     next_state.set(GamePhase::EditShaft);
                 }
                 HubButton::NewChassis => {
-                    state.editing_part_id = Some(gen_custom_id());
+                    state.editing_part_id = Some(gen_custom_id(&registry));
                     next_state.set(GamePhase::EditChassis);
                 }
                 HubButton::NewScrew => {
-                    state.editing_part_id = Some(gen_custom_id());
+                    state.editing_part_id = Some(gen_custom_id(&registry));
                     next_state.set(GamePhase::EditScrew);
                 }
                 HubButton::ManageParts => {
@@ -577,7 +1152,22 @@ enum ManageButton {
     DeletePart { slot: PartSlot, id: String },
     EditBuild(String),
     DeleteBuild(String),
+    /// Duplicate under a fresh id; built-ins included.
+    CloneWheel(String),
+    ClonePart { slot: PartSlot, id: String },
+    CloneBuild(String),
+    /// Apply the inline name field (`rename:<id>`) without re-assembling.
+    RenameBuild(String),
+    /// Replace a part's image in place (`dir` = asset folder, e.g. "tops").
+    SetImage { dir: &'static str, id: String },
     NewBuild,
+    /// Load a shared build file (see `import_build`).
+    ImportBuild,
+    CompareBuilds,
+    ToggleSelectMode,
+    /// Check / uncheck a card in select mode.
+    ToggleSelect(ManagedItem),
+    DeleteSelected,
     Back,
 }
 
@@ -588,8 +1178,11 @@ fn spawn_manage_parts(
     mut state: ResMut<DesignState>,
 ) {
     let error_msg = state.delete_error.take();
+    let selection = state.select_mode.then_some(state.delete_selection.as_slice());
     let edit_icon: Handle<Image> = asset_server.load("ui/edit.png");
     let delete_icon: Handle<Image> = asset_server.load("ui/delete.png");
+    let image_icon: Handle<Image> = asset_server.load("ui/image.png");
+    let copy_icon: Handle<Image> = asset_server.load("ui/copy.png");
 
     // Outer container: fixed full-screen, clips vertically
     commands.spawn((
@@ -602,14 +1195,16 @@ fn spawn_manage_parts(
         },
         BackgroundColor(COLOR_BG),
     )).with_children(|outer| {
-        // Fixed top bar: title
+        // Fixed top bar: title + filter
         outer.spawn(Node {
+            flex_direction: FlexDirection::Column,
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
             padding: UiRect::new(Val::Px(20.0), Val::Px(20.0), Val::Px(16.0), Val::Px(8.0)),
             ..default()
         }).with_children(|bar| {
             spawn_title(bar, "My Parts & Builds");
+            spawn_filter_row(bar, &state.manage_filter);
         });
 
         // Scrollable middle area
@@ -649,22 +1244,22 @@ fn spawn_manage_parts(
             }
 
             // ── Tops ──
-            spawn_section_with_wheels(root, &registry.wheels, &asset_server, &edit_icon, &delete_icon);
+            spawn_section_with_wheels(root, &registry.wheels, &asset_server, &edit_icon, &delete_icon, &image_icon, &copy_icon, selection);
 
             // ── Weapons ──
-            spawn_section_with_parts(root, "Weapons", &registry.weapons, PartSlot::WeaponWheel, &asset_server, &edit_icon, &delete_icon);
+            spawn_section_with_parts(root, "Weapons", &registry.weapons, PartSlot::WeaponWheel, &asset_server, &edit_icon, &delete_icon, &image_icon, &copy_icon, selection);
 
             // ── Shafts ──
-            spawn_section_with_shafts(root, &registry.shafts, &asset_server, &edit_icon, &delete_icon);
+            spawn_section_with_shafts(root, &registry.shafts, &asset_server, &edit_icon, &delete_icon, &image_icon, &copy_icon, selection);
 
             // ── Chassis ──
-            spawn_section_with_chassis(root, &registry.chassis, &asset_server, &edit_icon, &delete_icon);
+            spawn_section_with_chassis(root, &registry.chassis, &asset_server, &edit_icon, &delete_icon, &image_icon, &copy_icon, selection);
 
             // ── Screws ──
-            spawn_section_with_screws(root, &registry.screws, &asset_server, &edit_icon, &delete_icon);
+            spawn_section_with_screws(root, &registry.screws, &asset_server, &edit_icon, &delete_icon, &image_icon, &copy_icon, selection);
 
             // ── Builds ──
-            spawn_section_with_builds(root, &registry.builds, &edit_icon, &delete_icon);
+            spawn_section_with_builds(root, &registry.builds, &edit_icon, &delete_icon, &copy_icon, selection);
 
             // Bottom padding so content doesn't sit against the button bar
             root.spawn(Node { height: Val::Px(8.0), ..default() });
@@ -679,6 +1274,14 @@ fn spawn_manage_parts(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "New Build", ManageButton::NewBuild);
+            spawn_button(row, "Import Build", ManageButton::ImportBuild);
+            spawn_button(row, "Compare Builds", ManageButton::CompareBuilds);
+            if state.select_mode {
+                spawn_button(row, "Delete Selected", ManageButton::DeleteSelected);
+                spawn_button(row, "Cancel Select", ManageButton::ToggleSelectMode);
+            } else {
+                spawn_button(row, "Select...", ManageButton::ToggleSelectMode);
+            }
             spawn_button(row, "Back", ManageButton::Back);
         });
     });
@@ -692,6 +1295,9 @@ fn spawn_section_with_parts(
     asset_server: &AssetServer,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    image_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new(title),
@@ -714,8 +1320,8 @@ fn spawn_section_with_parts(
         for id in ids {
             let w = &weapons[id];
             let builtin = is_builtin(id);
-            let img: Handle<Image> = asset_server.load(format!("weapons/{}.png", id));
-            spawn_part_card(grid, id, &w.name, &format!("{:?}", w.kind), PartSlot::WeaponWheel, builtin, Some(img), edit_icon.clone(), delete_icon.clone());
+            let img: Handle<Image> = asset_server.load(part_image_path("weapons", id));
+            spawn_part_card(grid, id, &w.name, &w.stats_line(), &w.description, PartSlot::WeaponWheel, builtin, Some(img), edit_icon.clone(), delete_icon.clone(), image_icon.clone(), copy_icon.clone(), selection);
         }
     });
 }
@@ -726,6 +1332,9 @@ fn spawn_section_with_shafts(
     asset_server: &AssetServer,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    image_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new("Shafts"),
@@ -748,8 +1357,8 @@ fn spawn_section_with_shafts(
         for id in ids {
             let s = &shafts[id];
             let builtin = is_builtin(id);
-            let img: Handle<Image> = asset_server.load(format!("shafts/{}.png", id));
-            spawn_part_card(grid, id, &s.name, &format!("Stab:{:.1} Eff:{:.1}", s.stability, s.spin_efficiency), PartSlot::Shaft, builtin, Some(img), edit_icon.clone(), delete_icon.clone());
+            let img: Handle<Image> = asset_server.load(part_image_path("shafts", id));
+            spawn_part_card(grid, id, &s.name, &format!("Stab:{:.1} Eff:{:.1}", s.stability, s.spin_efficiency), &s.description, PartSlot::Shaft, builtin, Some(img), edit_icon.clone(), delete_icon.clone(), image_icon.clone(), copy_icon.clone(), selection);
         }
     });
 }
@@ -760,6 +1369,9 @@ fn spawn_section_with_chassis(
     asset_server: &AssetServer,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    image_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new("Chassis"),
@@ -782,8 +1394,8 @@ fn spawn_section_with_chassis(
         for id in ids {
             let c = &chassis[id];
             let builtin = is_builtin(id);
-            let img: Handle<Image> = asset_server.load(format!("chassis/{}.png", id));
-            spawn_part_card(grid, id, &c.name, &format!("Spd+{:.0}x{:.1}", c.move_speed_add, c.move_speed_mul), PartSlot::Chassis, builtin, Some(img), edit_icon.clone(), delete_icon.clone());
+            let img: Handle<Image> = asset_server.load(part_image_path("chassis", id));
+            spawn_part_card(grid, id, &c.name, &format!("Spd+{:.0}x{:.1}", c.move_speed_add, c.move_speed_mul), &c.description, PartSlot::Chassis, builtin, Some(img), edit_icon.clone(), delete_icon.clone(), image_icon.clone(), copy_icon.clone(), selection);
         }
     });
 }
@@ -794,6 +1406,9 @@ fn spawn_section_with_screws(
     asset_server: &AssetServer,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    image_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new("Screws"),
@@ -816,8 +1431,8 @@ fn spawn_section_with_screws(
         for id in ids {
             let s = &screws[id];
             let builtin = is_builtin(id);
-            let img: Handle<Image> = asset_server.load(format!("screws/{}.png", id));
-            spawn_part_card(grid, id, &s.name, &format!("HP+{:.0} CR:{:.1}", s.passive.spin_hp_max_add, s.passive.control_reduction), PartSlot::TraitScrew, builtin, Some(img), edit_icon.clone(), delete_icon.clone());
+            let img: Handle<Image> = asset_server.load(part_image_path("screws", id));
+            spawn_part_card(grid, id, &s.name, &format!("HP+{:.0} CR:{:.1}", s.passive.spin_hp_max_add, s.passive.control_reduction), &s.description, PartSlot::TraitScrew, builtin, Some(img), edit_icon.clone(), delete_icon.clone(), image_icon.clone(), copy_icon.clone(), selection);
         }
     });
 }
@@ -827,6 +1442,8 @@ fn spawn_section_with_builds(
     builds: &std::collections::HashMap<String, crate::game::parts::registry::BuildRef>,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new("Builds"),
@@ -861,8 +1478,41 @@ fn spawn_section_with_builds(
             let stats = format!("{} + {}", b.wheel_id, b.weapon_id);
             let id_str: String = id.clone();
             let id_str2: String = id.clone();
-            spawn_card_frame(grid, &b.name, &stats, None, COLOR_CARD, 220.0, move |card| {
-                if !builtin {
+            let name = b.name.clone();
+            let item = ManagedItem::Build(id.clone());
+            let checked = selection.map(|sel| sel.contains(&item));
+            spawn_card_frame(grid, id, &b.name, &stats, "", None, COLOR_CARD, 220.0, move |card| {
+                if let (false, Some(checked)) = (builtin, checked) {
+                    spawn_select_checkbox(card, item, checked);
+                } else if !builtin {
+                    // Inline rename
+                    card.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    }).with_children(|row| {
+                        spawn_text_input(row, &format!("rename:{}", id), &name, 130.0, FieldKind::Text);
+                        row.spawn((
+                            ManageButton::RenameBuild(id.clone()),
+                            Button,
+                            Node {
+                                height: Val::Px(32.0),
+                                padding: UiRect::horizontal(Val::Px(8.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border_radius: BorderRadius::all(Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(COLOR_BTN),
+                        )).with_children(|btn| {
+                            btn.spawn((
+                                Text::new("Rename"),
+                                TextFont { font_size: 12.0, ..default() },
+                                TextColor(COLOR_TEXT),
+                            ));
+                        });
+                    });
                     card.spawn(Node {
                         flex_direction: FlexDirection::Row,
                         column_gap: Val::Px(8.0),
@@ -870,14 +1520,11 @@ fn spawn_section_with_builds(
                         ..default()
                     }).with_children(|row| {
                         spawn_icon_button(row, edit_icon.clone(), ManageButton::EditBuild(id_str));
+                        spawn_icon_button(row, copy_icon.clone(), ManageButton::CloneBuild(id.clone()));
                         spawn_icon_button(row, delete_icon.clone(), ManageButton::DeleteBuild(id_str2));
                     });
                 } else {
-                    card.spawn((
-                        Text::new("(built-in)"),
-                        TextFont { font_size: 10.0, ..default() },
-                        TextColor(COLOR_TEXT_DIM),
-                    ));
+                    spawn_builtin_footer(card, copy_icon.clone(), ManageButton::CloneBuild(id.clone()));
                 }
             });
         }
@@ -890,6 +1537,9 @@ fn spawn_section_with_wheels(
     asset_server: &AssetServer,
     edit_icon: &Handle<Image>,
     delete_icon: &Handle<Image>,
+    image_icon: &Handle<Image>,
+    copy_icon: &Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     root.spawn((
         Text::new("Tops"),
@@ -912,8 +1562,8 @@ fn spawn_section_with_wheels(
         for id in ids {
             let t = &tops[id];
             let builtin = is_builtin(id);
-            let img: Handle<Image> = asset_server.load(format!("tops/{}.png", id));
-            spawn_wheel_card(grid, id, &t.name, &format!("HP:{:.0} R:{:.2}", t.spin_hp_max.0, t.radius.0), builtin, Some(img), edit_icon.clone(), delete_icon.clone());
+            let img: Handle<Image> = asset_server.load(part_image_path("tops", id));
+            spawn_wheel_card(grid, id, &t.name, &format!("HP:{:.0} R:{:.2}", t.spin_hp_max.0, t.radius.0), &t.description, builtin, Some(img), edit_icon.clone(), delete_icon.clone(), image_icon.clone(), copy_icon.clone(), selection);
         }
     });
 }
@@ -923,30 +1573,36 @@ fn spawn_wheel_card(
     id: &str,
     name: &str,
     stats_line: &str,
+    description: &str,
     builtin: bool,
     image: Option<Handle<Image>>,
     edit_icon: Handle<Image>,
     delete_icon: Handle<Image>,
+    image_icon: Handle<Image>,
+    copy_icon: Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     let id_str: String = id.into();
     let id_str2: String = id.into();
-    spawn_card_frame(parent, name, stats_line, image, COLOR_CARD, 200.0, move |card| {
-        if !builtin {
+    let item = ManagedItem::Wheel(id.into());
+    let checked = selection.map(|sel| sel.contains(&item));
+    spawn_card_frame(parent, id, name, stats_line, description, image, COLOR_CARD, 200.0, move |card| {
+        if let (false, Some(checked)) = (builtin, checked) {
+            spawn_select_checkbox(card, item, checked);
+        } else if !builtin {
             card.spawn(Node {
                 flex_direction: FlexDirection::Row,
                 column_gap: Val::Px(8.0),
                 margin: UiRect::top(Val::Px(4.0)),
                 ..default()
             }).with_children(|row| {
-                spawn_icon_button(row, edit_icon, ManageButton::EditWheel(id_str));
+                spawn_icon_button(row, image_icon, ManageButton::SetImage { dir: "tops", id: id_str.clone() });
+                spawn_icon_button(row, edit_icon, ManageButton::EditWheel(id_str.clone()));
+                spawn_icon_button(row, copy_icon, ManageButton::CloneWheel(id_str));
                 spawn_icon_button(row, delete_icon, ManageButton::DeleteWheel(id_str2));
             });
         } else {
-            card.spawn((
-                Text::new("(built-in)"),
-                TextFont { font_size: 10.0, ..default() },
-                TextColor(COLOR_TEXT_DIM),
-            ));
+            spawn_builtin_footer(card, copy_icon, ManageButton::CloneWheel(id_str));
         }
     });
 }
@@ -956,38 +1612,287 @@ fn spawn_part_card(
     id: &str,
     name: &str,
     stats_line: &str,
+    description: &str,
     slot: PartSlot,
     builtin: bool,
     image: Option<Handle<Image>>,
     edit_icon: Handle<Image>,
     delete_icon: Handle<Image>,
+    image_icon: Handle<Image>,
+    copy_icon: Handle<Image>,
+    selection: Option<&[ManagedItem]>,
 ) {
     let id_str: String = id.into();
     let id_str2: String = id.into();
-    spawn_card_frame(parent, name, stats_line, image, COLOR_CARD, 200.0, move |card| {
-        if !builtin {
+    let item = ManagedItem::Part { slot, id: id.into() };
+    let checked = selection.map(|sel| sel.contains(&item));
+    spawn_card_frame(parent, id, name, stats_line, description, image, COLOR_CARD, 200.0, move |card| {
+        if let (false, Some(checked)) = (builtin, checked) {
+            spawn_select_checkbox(card, item, checked);
+        } else if !builtin {
             card.spawn(Node {
                 flex_direction: FlexDirection::Row,
                 column_gap: Val::Px(8.0),
                 margin: UiRect::top(Val::Px(4.0)),
                 ..default()
             }).with_children(|row| {
-                spawn_icon_button(row, edit_icon, ManageButton::EditPart { slot, id: id_str });
+                spawn_icon_button(row, image_icon, ManageButton::SetImage { dir: slot_dir(&slot), id: id_str.clone() });
+                spawn_icon_button(row, edit_icon, ManageButton::EditPart { slot, id: id_str.clone() });
+                spawn_icon_button(row, copy_icon, ManageButton::ClonePart { slot, id: id_str });
                 spawn_icon_button(row, delete_icon, ManageButton::DeletePart { slot, id: id_str2 });
             });
         } else {
-            card.spawn((
-                Text::new("(built-in)"),
-                TextFont { font_size: 10.0, ..default() },
-                TextColor(COLOR_TEXT_DIM),
-            ));
+            spawn_builtin_footer(card, copy_icon, ManageButton::ClonePart { slot, id: id_str });
         }
     });
 }
 
-fn spawn_icon_button<C: Component>(
-    parent: &mut ChildSpawnerCommands,
-    icon_handle: Handle<Image>,
+/// Built-in cards can't be edited or deleted, only cloned.
+fn spawn_builtin_footer(card: &mut ChildSpawnerCommands, copy_icon: Handle<Image>, clone: ManageButton) {
+    card.spawn(Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        margin: UiRect::top(Val::Px(4.0)),
+        ..default()
+    }).with_children(|row| {
+        spawn_icon_button(row, copy_icon, clone);
+        row.spawn((
+            Text::new("(built-in)"),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(COLOR_TEXT_DIM),
+        ));
+    });
+}
+
+fn select_checkbox_label(checked: bool) -> &'static str {
+    if checked { "[x] Selected" } else { "[ ] Select" }
+}
+
+fn spawn_select_checkbox(card: &mut ChildSpawnerCommands, item: ManagedItem, checked: bool) {
+    card.spawn((
+        ManageButton::ToggleSelect(item),
+        Button,
+        Node {
+            height: Val::Px(28.0),
+            padding: UiRect::horizontal(Val::Px(10.0)),
+            margin: UiRect::top(Val::Px(4.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BTN),
+    )).with_children(|btn| {
+        btn.spawn((
+            Text::new(select_checkbox_label(checked)),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+    });
+}
+
+/// Remove a wheel, part or build from the registry (and a part's image files),
+/// unless builds still use it; returns their names then. DB rows are the
+/// caller's job so batches can go in one transaction.
+fn remove_managed_item(registry: &mut PartRegistry, item: &ManagedItem) -> Result<(), Vec<String>> {
+    if !matches!(item, ManagedItem::Build(_)) {
+        let used_by = builds_using_part(registry, item.id());
+        if !used_by.is_empty() {
+            return Err(used_by);
+        }
+    }
+    match item {
+        ManagedItem::Wheel(id) => {
+            remove_asset_file(&part_image_path("tops", id));
+            registry.wheels.remove(id.as_str());
+        }
+        ManagedItem::Part { slot, id } => {
+            remove_asset_file(&part_image_path(slot_dir(slot), id));
+            if *slot == PartSlot::WeaponWheel {
+                remove_asset_file(&projectile_image_path(id));
+            }
+            match slot {
+                PartSlot::WeaponWheel => { registry.weapons.remove(id.as_str()); }
+                PartSlot::Shaft => { registry.shafts.remove(id.as_str()); }
+                PartSlot::Chassis => { registry.chassis.remove(id.as_str()); }
+                PartSlot::TraitScrew => { registry.screws.remove(id.as_str()); }
+            }
+        }
+        ManagedItem::Build(id) => {
+            registry.builds.remove(id);
+        }
+    }
+    Ok(())
+}
+
+/// Copy a wheel, part or build (built-ins too) under a fresh id with " (copy)"
+/// appended to its name: registry entry, DB row and image files. Returns the
+/// new id, or `None` if the source is gone.
+fn clone_managed_item(
+    registry: &mut PartRegistry,
+    item: &ManagedItem,
+    db: Option<(&SqliteRepo, &TokioRuntime)>,
+) -> Option<String> {
+    let new_id = gen_custom_id(registry);
+    let save_part = |part_type: &str, category: &str, json: String| {
+        if let Some((repo, rt)) = db {
+            let _ = repo.save_part_sync(&rt.0, part_type, category, &new_id, &json);
+        }
+    };
+    let copy_image = |from: String, to: String| {
+        // Built-ins without their own image just fall back to the default
+        let _ = copy_into_assets(&asset_file(&from), &to);
+    };
+    match item {
+        ManagedItem::Wheel(id) => {
+            let mut spec = registry.wheels.get(id)?.clone();
+            spec.id = new_id.clone();
+            spec.name = format!("{} (copy)", spec.name);
+            save_part("top", "top", serde_json::to_string(&spec).unwrap_or_default());
+            copy_image(part_image_path("tops", id), part_image_path("tops", &new_id));
+            registry.wheels.insert(new_id.clone(), spec);
+        }
+        ManagedItem::Part { slot, id } => {
+            let dir = slot_dir(slot);
+            match slot {
+                PartSlot::WeaponWheel => {
+                    let mut spec = registry.weapons.get(id)?.clone();
+                    spec.id = new_id.clone();
+                    spec.name = format!("{} (copy)", spec.name);
+                    save_part("weapon", &format!("{:?}", spec.kind), serde_json::to_string(&spec).unwrap_or_default());
+                    copy_image(
+                        projectile_image_path(id),
+                        projectile_image_path(&new_id),
+                    );
+                    registry.weapons.insert(new_id.clone(), spec);
+                }
+                PartSlot::Shaft => {
+                    let mut spec = registry.shafts.get(id)?.clone();
+                    spec.id = new_id.clone();
+                    spec.name = format!("{} (copy)", spec.name);
+                    save_part("shaft", "shaft", serde_json::to_string(&spec).unwrap_or_default());
+                    registry.shafts.insert(new_id.clone(), spec);
+                }
+                PartSlot::Chassis => {
+                    let mut spec = registry.chassis.get(id)?.clone();
+                    spec.id = new_id.clone();
+                    spec.name = format!("{} (copy)", spec.name);
+                    save_part("chassis", "chassis", serde_json::to_string(&spec).unwrap_or_default());
+                    registry.chassis.insert(new_id.clone(), spec);
+                }
+                PartSlot::TraitScrew => {
+                    let mut spec = registry.screws.get(id)?.clone();
+                    spec.id = new_id.clone();
+                    spec.name = format!("{} (copy)", spec.name);
+                    save_part("screw", "screw", serde_json::to_string(&spec).unwrap_or_default());
+                    registry.screws.insert(new_id.clone(), spec);
+                }
+            }
+            copy_image(part_image_path(dir, id), part_image_path(dir, &new_id));
+        }
+        ManagedItem::Build(id) => {
+            let mut build_ref = registry.builds.get(id)?.clone();
+            build_ref.id = new_id.clone();
+            build_ref.name = format!("{} (copy)", build_ref.name);
+            if let Some((repo, rt)) = db {
+                if let Some(mut build) = registry.resolve_build(
+                    &new_id,
+                    &build_ref.name,
+                    &build_ref.wheel_id,
+                    &build_ref.weapon_id,
+                    &build_ref.shaft_id,
+                    &build_ref.chassis_id,
+                    &build_ref.screw_id,
+                ) {
+                    build.note = Some(build_ref.name.clone());
+                    let _ = repo.save_build_sync(&rt.0, &build, &build_ref.locks);
+                }
+            }
+            registry.builds.insert(new_id.clone(), build_ref);
+        }
+    }
+    Some(new_id)
+}
+
+/// Id an imported part is stored under: its own if free, a fresh one if an
+/// existing part with that id differs. `None` if an identical part is already
+/// registered, so there's nothing to insert.
+fn import_part_id<T: serde::Serialize>(
+    registry: &PartRegistry,
+    parts: &std::collections::HashMap<String, T>,
+    spec: &T,
+    id: &str,
+) -> Option<String> {
+    match parts.get(id) {
+        None => Some(id.to_string()),
+        Some(existing) if serde_json::to_value(existing).ok() == serde_json::to_value(spec).ok() => None,
+        Some(_) => Some(gen_custom_id(registry)),
+    }
+}
+
+/// Register a build read from a shared JSON file: each part is added (and
+/// saved) unless an identical one exists, renamed on id collisions, then the
+/// build itself is registered under a free id.
+fn import_build(
+    registry: &mut PartRegistry,
+    mut build: Build,
+    db: Option<(&SqliteRepo, &TokioRuntime)>,
+) {
+    let save_part = |part_type: &str, category: &str, id: &str, json: String| {
+        if let Some((repo, rt)) = db {
+            let _ = repo.save_part_sync(&rt.0, part_type, category, id, &json);
+        }
+    };
+    if let Some(id) = import_part_id(registry, &registry.wheels, &build.wheel, &build.wheel.id) {
+        build.wheel.id = id.clone();
+        save_part("top", "top", &id, serde_json::to_string(&build.wheel).unwrap_or_default());
+        registry.wheels.insert(id, build.wheel.clone());
+    }
+    if let Some(id) = import_part_id(registry, &registry.weapons, &build.weapon, &build.weapon.id) {
+        build.weapon.id = id.clone();
+        let category = format!("{:?}", build.weapon.kind);
+        save_part("weapon", &category, &id, serde_json::to_string(&build.weapon).unwrap_or_default());
+        registry.weapons.insert(id, build.weapon.clone());
+    }
+    if let Some(id) = import_part_id(registry, &registry.shafts, &build.shaft, &build.shaft.id) {
+        build.shaft.id = id.clone();
+        save_part("shaft", "shaft", &id, serde_json::to_string(&build.shaft).unwrap_or_default());
+        registry.shafts.insert(id, build.shaft.clone());
+    }
+    if let Some(id) = import_part_id(registry, &registry.chassis, &build.chassis, &build.chassis.id) {
+        build.chassis.id = id.clone();
+        save_part("chassis", "chassis", &id, serde_json::to_string(&build.chassis).unwrap_or_default());
+        registry.chassis.insert(id, build.chassis.clone());
+    }
+    if let Some(id) = import_part_id(registry, &registry.screws, &build.screw, &build.screw.id) {
+        build.screw.id = id.clone();
+        save_part("screw", "screw", &id, serde_json::to_string(&build.screw).unwrap_or_default());
+        registry.screws.insert(id, build.screw.clone());
+    }
+
+    if registry.builds.contains_key(&build.id) {
+        build.id = gen_custom_id(registry);
+    }
+    let name = build.note.clone().filter(|n| !n.is_empty()).unwrap_or_else(|| build.name.clone());
+    if let Some((repo, rt)) = db {
+        let _ = repo.save_build_sync(&rt.0, &build, &SlotLocks::default());
+    }
+    registry.builds.insert(build.id.clone(), crate::game::parts::registry::BuildRef {
+        id: build.id,
+        name,
+        wheel_id: build.wheel.id.clone(),
+        weapon_id: build.weapon.id.clone(),
+        shaft_id: build.shaft.id.clone(),
+        chassis_id: build.chassis.id.clone(),
+        screw_id: build.screw.id.clone(),
+        locks: SlotLocks::default(),
+    });
+}
+
+fn spawn_icon_button<C: Component>(
+    parent: &mut ChildSpawnerCommands,
+    icon_handle: Handle<Image>,
     marker: C,
 ) {
     parent.spawn((
@@ -1015,14 +1920,18 @@ fn spawn_icon_button<C: Component>(
 }
 
 fn manage_parts_system(
-    mut q: Query<(&Interaction, &ManageButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut commands: Commands,
+    mut q: Query<(&Interaction, &ManageButton, &mut BackgroundColor, Option<&Children>), Changed<Interaction>>,
+    mut labels: Query<&mut Text>,
     mut next_state: ResMut<NextState<GamePhase>>,
     mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
+    inputs: Query<&TextInput>,
+    asset_server: Res<AssetServer>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
 ) {
-    for (interaction, button, mut bg) in &mut q {
+    for (interaction, button, mut bg, children) in &mut q {
         if *interaction == Interaction::Pressed {
             match button {
                 ManageButton::EditWheel(id) => {
@@ -1031,19 +1940,9 @@ fn manage_parts_system(
                     next_state.set(GamePhase::EditWheel);
                 }
                 ManageButton::DeleteWheel(id) => {
-                    let used_by = builds_using_part(&registry, id);
-                    if !used_by.is_empty() {
-                        state.delete_error = Some(format!(
-                            "Cannot delete '{}': used by builds: {}", id, used_by.join(", ")
-                        ));
-                    } else {
-                        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
-                            let _ = repo.delete_part_sync(&rt.0, id);
-                        }
-                        let _ = std::fs::remove_file(format!("assets/tops/{}.png", id));
-                        registry.wheels.remove(id.as_str());
+                    if !request_delete(&mut commands, &mut state, &registry, ManagedItem::Wheel(id.clone())) {
+                        next_state.set(GamePhase::ManageParts);
                     }
-                    next_state.set(GamePhase::ManageParts);
                 }
                 ManageButton::EditPart { slot, id } => {
                     state.editing_part_id = Some(id.clone());
@@ -1056,38 +1955,50 @@ fn manage_parts_system(
                     }
                 }
                 ManageButton::DeletePart { slot, id } => {
-                    let used_by = builds_using_part(&registry, id);
-                    if !used_by.is_empty() {
-                        state.delete_error = Some(format!(
-                            "Cannot delete '{}': used by builds: {}", id, used_by.join(", ")
-                        ));
-                    } else {
-                        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
-                            let _ = repo.delete_part_sync(&rt.0, id);
-                        }
-                        let dir = slot_dir(slot);
-                        let _ = std::fs::remove_file(format!("assets/{}/{}.png", dir, id));
-                        if *slot == PartSlot::WeaponWheel {
-                            let _ = std::fs::remove_file(format!("assets/projectiles/{}_projectile.png", id));
-                        }
-                        match slot {
-                            PartSlot::WeaponWheel => { registry.weapons.remove(id.as_str()); }
-                            PartSlot::Shaft => { registry.shafts.remove(id.as_str()); }
-                            PartSlot::Chassis => { registry.chassis.remove(id.as_str()); }
-                            PartSlot::TraitScrew => { registry.screws.remove(id.as_str()); }
-                        }
+                    let item = ManagedItem::Part { slot: *slot, id: id.clone() };
+                    if !request_delete(&mut commands, &mut state, &registry, item) {
+                        next_state.set(GamePhase::ManageParts);
                     }
-                    next_state.set(GamePhase::ManageParts);
                 }
                 ManageButton::EditBuild(id) => {
                     state.current_build_id = Some(id.clone());
+                    state.current_build_locks = registry.builds.get(id).map(|b| b.locks).unwrap_or_default();
+                    state.current_build_saved_locks = state.current_build_locks;
                     next_state.set(GamePhase::AssembleBuild);
                 }
                 ManageButton::DeleteBuild(id) => {
-                    if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
-                        let _ = repo.delete_build_sync(&rt.0, id);
+                    request_delete(&mut commands, &mut state, &registry, ManagedItem::Build(id.clone()));
+                }
+                ManageButton::CloneWheel(id) => {
+                    clone_managed_item(&mut registry, &ManagedItem::Wheel(id.clone()), repo.as_deref().zip(rt.as_deref()));
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::ClonePart { slot, id } => {
+                    let item = ManagedItem::Part { slot: *slot, id: id.clone() };
+                    clone_managed_item(&mut registry, &item, repo.as_deref().zip(rt.as_deref()));
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::CloneBuild(id) => {
+                    clone_managed_item(&mut registry, &ManagedItem::Build(id.clone()), repo.as_deref().zip(rt.as_deref()));
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::RenameBuild(id) => {
+                    let name = read_field(&inputs, &format!("rename:{}", id)).trim().to_string();
+                    if name.is_empty() {
+                        continue;
+                    }
+                    if let Some(b) = registry.builds.get_mut(id) {
+                        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                            let _ = repo.rename_build_sync(&rt.0, id, &name);
+                        }
+                        b.name = name;
                     }
-                    registry.builds.remove(id);
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::SetImage { dir, id } => {
+                    pick_and_copy_image(dir, id);
+                    // Same path → same handle; reload so every preview picks up the new file
+                    asset_server.reload(part_image_path(dir, id));
                     next_state.set(GamePhase::ManageParts);
                 }
                 ManageButton::NewBuild => {
@@ -1098,9 +2009,65 @@ fn manage_parts_system(
                     state.current_build_chassis_id = "standard_chassis".into();
                     state.current_build_screw_id = "standard_screw".into();
                     state.current_build_note.clear();
+                    state.current_build_locks = SlotLocks::default();
+                    state.current_build_saved_locks = SlotLocks::default();
                     next_state.set(GamePhase::AssembleBuild);
                 }
+                ManageButton::ImportBuild => {
+                    let Some(path) = rfd::FileDialog::new().add_filter("Build JSON", &["json"]).pick_file() else {
+                        continue;
+                    };
+                    let parsed = std::fs::read_to_string(&path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|json| serde_json::from_str::<Build>(&json).map_err(|e| e.to_string()));
+                    match parsed {
+                        Ok(build) => import_build(&mut registry, build, repo.as_deref().zip(rt.as_deref())),
+                        Err(e) => {
+                            state.delete_error = Some(format!("Cannot import '{}': {}", path.display(), e));
+                        }
+                    }
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::ToggleSelectMode => {
+                    state.select_mode = !state.select_mode;
+                    state.delete_selection.clear();
+                    next_state.set(GamePhase::ManageParts);
+                }
+                ManageButton::ToggleSelect(item) => {
+                    let checked = match state.delete_selection.iter().position(|i| i == item) {
+                        Some(i) => {
+                            state.delete_selection.remove(i);
+                            false
+                        }
+                        None => {
+                            state.delete_selection.push(item.clone());
+                            true
+                        }
+                    };
+                    for child in children.into_iter().flatten() {
+                        if let Ok(mut text) = labels.get_mut(*child) {
+                            **text = select_checkbox_label(checked).into();
+                        }
+                    }
+                }
+                ManageButton::DeleteSelected => {
+                    if state.delete_selection.is_empty() {
+                        continue;
+                    }
+                    let items = state.delete_selection.clone();
+                    spawn_confirm_dialog(
+                        &mut commands,
+                        ScreenRoot,
+                        &format!("Delete {} items? This cannot be undone.", items.len()),
+                    );
+                    state.pending_delete = Some(PendingDelete::Many(items));
+                }
+                ManageButton::CompareBuilds => {
+                    next_state.set(GamePhase::CompareBuilds);
+                }
                 ManageButton::Back => {
+                    state.select_mode = false;
+                    state.delete_selection.clear();
                     next_state.set(GamePhase::DesignHub);
                 }
             }
@@ -1109,7 +2076,9 @@ fn manage_parts_system(
         match button {
             ManageButton::EditWheel(_) | ManageButton::DeleteWheel(_) |
             ManageButton::EditPart { .. } | ManageButton::DeletePart { .. } |
-            ManageButton::EditBuild(_) | ManageButton::DeleteBuild(_) => {
+            ManageButton::EditBuild(_) | ManageButton::DeleteBuild(_) |
+            ManageButton::CloneWheel(_) | ManageButton::ClonePart { .. } | ManageButton::CloneBuild(_) |
+            ManageButton::SetImage { .. } => {
                 match interaction {
                     Interaction::Hovered => *bg = BackgroundColor(Color::srgba(0.4, 0.4, 0.5, 0.3)),
                     Interaction::None => *bg = BackgroundColor(Color::NONE),
@@ -1121,6 +2090,123 @@ fn manage_parts_system(
     }
 }
 
+/// Open the confirm dialog for `item`. A part still used by builds is refused
+/// up front with the `delete_error` banner instead; returns false then, and
+/// the caller re-enters ManageParts to show it.
+fn request_delete(commands: &mut Commands, state: &mut DesignState, registry: &PartRegistry, item: ManagedItem) -> bool {
+    if !matches!(item, ManagedItem::Build(_)) {
+        let used_by = builds_using_part(registry, item.id());
+        if !used_by.is_empty() {
+            state.delete_error = Some(format!(
+                "Cannot delete '{}': used by builds: {}", item.id(), used_by.join(", ")
+            ));
+            return false;
+        }
+    }
+    let name = managed_item_name(registry, &item).unwrap_or_else(|| item.id().to_string());
+    spawn_confirm_dialog(commands, ScreenRoot, &confirm_delete_message(&name));
+    state.pending_delete = Some(PendingDelete::Item(item));
+    true
+}
+
+fn managed_item_name(registry: &PartRegistry, item: &ManagedItem) -> Option<String> {
+    match item {
+        ManagedItem::Wheel(id) => registry.wheels.get(id).map(|t| t.name.clone()),
+        ManagedItem::Part { slot: PartSlot::WeaponWheel, id } => registry.weapons.get(id).map(|w| w.name.clone()),
+        ManagedItem::Part { slot: PartSlot::Shaft, id } => registry.shafts.get(id).map(|s| s.name.clone()),
+        ManagedItem::Part { slot: PartSlot::Chassis, id } => registry.chassis.get(id).map(|c| c.name.clone()),
+        ManagedItem::Part { slot: PartSlot::TraitScrew, id } => registry.screws.get(id).map(|s| s.name.clone()),
+        ManagedItem::Build(id) => registry.builds.get(id).map(|b| b.name.clone()),
+    }
+}
+
+/// "Delete Selected": remove builds first, so parts used only by selected
+/// builds become free, then the parts; deletes every removed item's DB rows
+/// in one transaction and reports skipped parts in the `delete_error` banner.
+fn delete_managed_items(
+    state: &mut DesignState,
+    registry: &mut PartRegistry,
+    mut items: Vec<ManagedItem>,
+    db: Option<(&SqliteRepo, &TokioRuntime)>,
+) {
+    items.sort_by_key(|item| !matches!(item, ManagedItem::Build(_)));
+    let mut part_ids = Vec::new();
+    let mut build_ids = Vec::new();
+    let mut skipped = Vec::new();
+    for item in &items {
+        match remove_managed_item(registry, item) {
+            Ok(()) if matches!(item, ManagedItem::Build(_)) => build_ids.push(item.id().to_string()),
+            Ok(()) => part_ids.push(item.id().to_string()),
+            Err(used_by) => skipped.push(format!("'{}' (used by {})", item.id(), used_by.join(", "))),
+        }
+    }
+    if let Some((repo, rt)) = db {
+        if let Err(e) = repo.delete_many_sync(&rt.0, &part_ids, &build_ids) {
+            error!("Batch delete failed: {}", e);
+        }
+    }
+    if !skipped.is_empty() {
+        state.delete_error = Some(format!(
+            "Deleted {}, skipped {}: {}",
+            part_ids.len() + build_ids.len(), skipped.len(), skipped.join("; ")
+        ));
+    }
+    state.delete_selection.clear();
+    state.select_mode = false;
+}
+
+/// ManageParts dialog: Confirm removes the pending item (or every selected
+/// item) from the registry, its image files and the DB, then re-enters the
+/// screen; Cancel just closes.
+fn confirm_delete_system(
+    mut commands: Commands,
+    mut q: Query<(&Interaction, &ConfirmDialogButton, &mut BackgroundColor), Changed<Interaction>>,
+    dialogs: Query<Entity, With<ConfirmDialog>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    mut state: ResMut<DesignState>,
+    mut registry: ResMut<PartRegistry>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        hover_system(interaction, &mut bg);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let pending = state.pending_delete.take();
+        let item = match pending.filter(|_| matches!(button, ConfirmDialogButton::Confirm)) {
+            Some(PendingDelete::Item(item)) => item,
+            Some(PendingDelete::Many(items)) => {
+                delete_managed_items(&mut state, &mut registry, items, repo.as_deref().zip(rt.as_deref()));
+                next_state.set(GamePhase::ManageParts);
+                continue;
+            }
+            _ => {
+                for entity in &dialogs {
+                    commands.entity(entity).despawn();
+                }
+                continue;
+            }
+        };
+        match remove_managed_item(&mut registry, &item) {
+            Ok(()) => {
+                if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                    let _ = match &item {
+                        ManagedItem::Build(id) => repo.delete_build_sync(&rt.0, id),
+                        _ => repo.delete_part_sync(&rt.0, item.id()),
+                    };
+                }
+            }
+            Err(used_by) => {
+                state.delete_error = Some(format!(
+                    "Cannot delete '{}': used by builds: {}", item.id(), used_by.join(", ")
+                ));
+            }
+        }
+        next_state.set(GamePhase::ManageParts);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // TOP EDITOR
 // ═══════════════════════════════════════════════════════════════════════
@@ -1128,6 +2214,50 @@ fn manage_parts_system(
 #[derive(Component)]
 enum EditorButton { Save, Cancel, SetImage }
 
+/// Read-only effective stats in the wheel / shaft / screw editors, refreshed every frame.
+#[derive(Component)]
+struct LiveStatsText;
+
+fn spawn_live_stats_panel(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            border_radius: BorderRadius::all(Val::Px(6.0)),
+            margin: UiRect::top(Val::Px(8.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(COLOR_CARD),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Effective stats (unsaved)"),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(COLOR_TEXT_DIM),
+        ));
+        panel.spawn((
+            LiveStatsText,
+            Text::new(""),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+    });
+}
+
+fn live_stats_text(eff: &EffectiveStats) -> String {
+    format!(
+        "HP: {:.0}  Radius: {:.2}  Speed: {:.1}  Accel: {:.1}\n\
+         Ctrl: x{:.2} ({:.0}% reduced)  Stun Cap: {}\n\
+         Spin Drain: {:.2}/s idle, {:.1} per wall hit, {:.1} per top hit\n\
+         Damage Out: x{:.2}  Damage In: x{:.2}  Power: {:.0}",
+        eff.spin_hp_max.0, eff.radius.0, eff.move_speed.0, eff.accel,
+        eff.control_multiplier, (1.0 - eff.control_multiplier) * 100.0,
+        eff.stun_cap.map(|c| format!("{:.1}s", c)).unwrap_or_else(|| "none".into()),
+        eff.spin_drain_idle_per_sec, eff.spin_drain_on_wall_hit, eff.spin_drain_on_top_hit,
+        eff.damage_out_mult.0, eff.damage_in_mult.0, power_score(eff),
+    )
+}
+
 fn spawn_wheel_editor(
     mut commands: Commands,
     state: Res<DesignState>,
@@ -1161,15 +2291,19 @@ fn spawn_wheel_editor(
         let title = if state.return_to_manage { "Edit Wheel" } else { "New Wheel" };
         spawn_title(root, title);
 
-        let img = state.editing_part_id.as_ref().map(|id| asset_server.load(format!("tops/{}.png", id)));
-        spawn_image_preview(root, img, 96.0);
+        spawn_editor_image_preview(root, &asset_server, "tops", state.editing_part_id.as_ref());
 
-        spawn_field_row(root, "Name", "Display name", "name", &t.name);
-        spawn_field_row(root, "Max HP", "Max spin HP", "spin_hp_max", &format!("{}", t.spin_hp_max.0));
-        spawn_field_row(root, "Radius", "Collision radius (world units)", "radius", &format!("{}", t.radius.0));
-        spawn_field_row(root, "Move Speed", "Movement speed", "move_speed", &format!("{}", t.move_speed.0));
-        spawn_field_row(root, "Accel", "Acceleration", "accel", &format!("{}", t.accel));
-        spawn_field_row(root, "Control Reduction", "Control effect reduction (0.0=none)", "control_reduction", &format!("{}", t.control_reduction));
+        spawn_field_row(root, "Name", "Display name", "name", FieldKind::Text, &t.name);
+        spawn_field_row(root, "Description", "Flavor text shown on cards", "description", FieldKind::Text, &t.description);
+        spawn_copy_from_row(root, PickTarget::TopBody);
+        spawn_field_row(root, "Max HP", "Max spin HP", "spin_hp_max", FieldKind::Number, &format!("{}", t.spin_hp_max.0));
+        spawn_field_row(root, "Radius", "Collision radius (world units)", "radius", FieldKind::Number, &format!("{}", t.radius.0));
+        spawn_field_row(root, "Move Speed", "Movement speed", "move_speed", FieldKind::Number, &format!("{}", t.move_speed.0));
+        spawn_field_row(root, "Accel", "Acceleration", "accel", FieldKind::Number, &format!("{}", t.accel));
+        spawn_field_row(root, "Control Reduction", "Control effect reduction (0.0=none)", "control_reduction", FieldKind::Number, &format!("{}", t.control_reduction));
+
+        spawn_live_stats_panel(root);
+        spawn_save_error_text(root);
 
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1178,6 +2312,7 @@ fn spawn_wheel_editor(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Set Image", EditorButton::SetImage);
+            spawn_button(row, "Clear Image", ClearImageButton("tops"));
             spawn_button(row, "Save", EditorButton::Save);
             spawn_button(row, "Cancel", EditorButton::Cancel);
         });
@@ -1188,7 +2323,7 @@ fn wheel_editor_system(
     mut q: Query<(&Interaction, &EditorButton, &mut BackgroundColor), Changed<Interaction>>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
-    state: ResMut<DesignState>,
+    mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
@@ -1197,18 +2332,12 @@ fn wheel_editor_system(
         if *interaction == Interaction::Pressed {
             match button {
                 EditorButton::Save => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
-                    let name = read_field(&inputs, "name");
-                    let spec = BaseStats {
-                        id: id.clone(),
-                        name: if name.is_empty() { "My Top".into() } else { name },
-                        spin_hp_max: SpinHp(read_f32(&inputs, "spin_hp_max", 100.0)),
-                        radius: Radius(read_f32(&inputs, "radius", 1.3)),
-                        move_speed: MetersPerSec(read_f32(&inputs, "move_speed", 10.0)),
-                        accel: read_f32(&inputs, "accel", 25.0),
-                        control_reduction: read_f32(&inputs, "control_reduction", 0.0),
-                        sprite_path: None,
-                    };
+                    if let Some(msg) = invalid_numeric_message(&inputs, |_| true) {
+                        state.save_error = Some(msg);
+                        continue;
+                    }
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
+                    let spec = read_wheel_spec(&inputs, &id);
                     if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
                         let json = serde_json::to_string(&spec).unwrap_or_default();
                         let _ = repo.save_part_sync(&rt.0, "top", "top", &id, &json);
@@ -1220,7 +2349,7 @@ fn wheel_editor_system(
                     next_state.set(if state.return_to_manage { GamePhase::ManageParts } else { GamePhase::DesignHub });
                 }
                 EditorButton::SetImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_image("tops", &id);
                 }
             }
@@ -1229,6 +2358,36 @@ fn wheel_editor_system(
     }
 }
 
+fn read_wheel_spec(inputs: &Query<&TextInput>, id: &str) -> BaseStats {
+    let name = read_field(inputs, "name");
+    BaseStats {
+        id: id.into(),
+        name: if name.is_empty() { "My Top".into() } else { name },
+        description: read_field(inputs, "description"),
+        spin_hp_max: SpinHp(read_f32(inputs, "spin_hp_max", 100.0)),
+        radius: Radius(read_f32(inputs, "radius", 1.3)),
+        move_speed: MetersPerSec(read_f32(inputs, "move_speed", 10.0)),
+        accel: read_f32(inputs, "accel", 25.0),
+        control_reduction: read_f32(inputs, "control_reduction", 0.0),
+        sprite_path: None,
+    }
+}
+
+/// Wheel alone (no shaft, chassis or screw modifiers), as typed so far.
+fn wheel_live_stats_system(
+    inputs: Query<&TextInput>,
+    tuning: Res<Tuning>,
+    mut q: Query<&mut Text, With<LiveStatsText>>,
+) {
+    let eff = ModifierSet::new().compute_effective(&read_wheel_spec(&inputs, ""), &tuning);
+    for mut text in &mut q {
+        let line = live_stats_text(&eff);
+        if text.0 != line {
+            text.0 = line;
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // SHAFT EDITOR
 // ═══════════════════════════════════════════════════════════════════════
@@ -1239,14 +2398,14 @@ fn spawn_shaft_editor(
     registry: Res<PartRegistry>,
     asset_server: Res<AssetServer>,
 ) {
-    let (name, stability, efficiency) = if let Some(id) = &state.editing_part_id {
+    let (name, description, stability, efficiency) = if let Some(id) = &state.editing_part_id {
         if let Some(s) = registry.shafts.get(id) {
-            (s.name.clone(), s.stability, s.spin_efficiency)
+            (s.name.clone(), s.description.clone(), s.stability, s.spin_efficiency)
         } else {
-            ("My Shaft".into(), 0.5, 1.0)
+            ("My Shaft".into(), String::new(), 0.5, 1.0)
         }
     } else {
-        ("My Shaft".into(), 0.5, 1.0)
+        ("My Shaft".into(), String::new(), 0.5, 1.0)
     };
 
     commands.spawn((
@@ -1268,12 +2427,16 @@ fn spawn_shaft_editor(
         spawn_title(root, title);
 
         // Image preview
-        let img = state.editing_part_id.as_ref().map(|id| asset_server.load(format!("shafts/{}.png", id)));
-        spawn_image_preview(root, img, 96.0);
+        spawn_editor_image_preview(root, &asset_server, "shafts", state.editing_part_id.as_ref());
+
+        spawn_field_row(root, "Name", "Display name", "name", FieldKind::Text, &name);
+        spawn_field_row(root, "Description", "Flavor text shown on cards", "description", FieldKind::Text, &description);
+        spawn_copy_from_row(root, PickTarget::Part(PartSlot::Shaft));
+        spawn_field_row(root, "Stability", "Reduces knockback from collisions", "stability", FieldKind::Number, &format!("{}", stability));
+        spawn_field_row(root, "Spin Efficiency", "Idle drain divisor (1.0=standard, 0.25-4.0)", "spin_efficiency", FieldKind::Number, &format!("{}", efficiency));
 
-        spawn_field_row(root, "Name", "Display name", "name", &name);
-        spawn_field_row(root, "Stability", "Reduces knockback from collisions", "stability", &format!("{}", stability));
-        spawn_field_row(root, "Spin Efficiency", "Spin consumption multiplier (1.0=standard)", "spin_efficiency", &format!("{}", efficiency));
+        spawn_live_stats_panel(root);
+        spawn_save_error_text(root);
 
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1282,6 +2445,7 @@ fn spawn_shaft_editor(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Set Image", EditorButton::SetImage);
+            spawn_button(row, "Clear Image", ClearImageButton("shafts"));
             spawn_button(row, "Save", EditorButton::Save);
             spawn_button(row, "Cancel", EditorButton::Cancel);
         });
@@ -1292,7 +2456,7 @@ fn shaft_editor_system(
     mut q: Query<(&Interaction, &EditorButton, &mut BackgroundColor), Changed<Interaction>>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
-    state: ResMut<DesignState>,
+    mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
@@ -1301,14 +2465,12 @@ fn shaft_editor_system(
         if *interaction == Interaction::Pressed {
             match button {
                 EditorButton::Save => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
-                    let name = read_field(&inputs, "name");
-                    let spec = ShaftSpec {
-                        id: id.clone(),
-                        name: if name.is_empty() { "My Shaft".into() } else { name },
-                        stability: read_f32(&inputs, "stability", 0.5),
-                        spin_efficiency: read_f32(&inputs, "spin_efficiency", 1.0),
-                    };
+                    if let Some(msg) = invalid_numeric_message(&inputs, |_| true) {
+                        state.save_error = Some(msg);
+                        continue;
+                    }
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
+                    let spec = read_shaft_spec(&inputs, &id);
                     if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
                         let json = serde_json::to_string(&spec).unwrap_or_default();
                         let _ = repo.save_part_sync(&rt.0, "shaft", "shaft", &id, &json);
@@ -1320,7 +2482,7 @@ fn shaft_editor_system(
                     next_state.set(if state.return_to_manage { GamePhase::ManageParts } else { GamePhase::DesignHub });
                 }
                 EditorButton::SetImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_image("shafts", &id);
                 }
             }
@@ -1329,6 +2491,36 @@ fn shaft_editor_system(
     }
 }
 
+/// Efficiency is clamped here so a near-zero value can't make drain explode
+/// and a huge one can't make the top effectively immortal.
+fn read_shaft_spec(inputs: &Query<&TextInput>, id: &str) -> ShaftSpec {
+    let name = read_field(inputs, "name");
+    ShaftSpec {
+        id: id.into(),
+        name: if name.is_empty() { "My Shaft".into() } else { name },
+        description: read_field(inputs, "description"),
+        stability: read_f32(inputs, "stability", 0.5),
+        spin_efficiency: read_f32(inputs, "spin_efficiency", 1.0)
+            .clamp(ShaftSpec::MIN_EFFICIENCY, ShaftSpec::MAX_EFFICIENCY),
+    }
+}
+
+/// The shaft's modifiers applied to the default wheel, as typed so far.
+fn shaft_live_stats_system(
+    inputs: Query<&TextInput>,
+    tuning: Res<Tuning>,
+    mut q: Query<&mut Text, With<LiveStatsText>>,
+) {
+    let mods = read_shaft_spec(&inputs, "").to_modifiers();
+    let eff = mods.compute_effective(&BaseStats::default(), &tuning);
+    for mut text in &mut q {
+        let line = live_stats_text(&eff);
+        if text.0 != line {
+            text.0 = line;
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // CHASSIS EDITOR
 // ═══════════════════════════════════════════════════════════════════════
@@ -1362,16 +2554,19 @@ fn spawn_chassis_editor(
         let title = if state.return_to_manage { "Edit Chassis" } else { "New Chassis" };
         spawn_title(root, title);
 
-        let img = state.editing_part_id.as_ref().map(|id| asset_server.load(format!("chassis/{}.png", id)));
-        spawn_image_preview(root, img, 96.0);
+        spawn_editor_image_preview(root, &asset_server, "chassis", state.editing_part_id.as_ref());
+
+        spawn_field_row(root, "Name", "Display name", "name", FieldKind::Text, &c.name);
+        spawn_field_row(root, "Description", "Flavor text shown on cards", "description", FieldKind::Text, &c.description);
+        spawn_copy_from_row(root, PickTarget::Part(PartSlot::Chassis));
+        spawn_field_row(root, "Move Speed Add", "Flat movement speed bonus", "move_speed_add", FieldKind::Number, &format!("{}", c.move_speed_add));
+        spawn_field_row(root, "Move Speed Mul", "Movement speed multiplier (1.0=unchanged)", "move_speed_mul", FieldKind::Number, &format!("{}", c.move_speed_mul));
+        spawn_field_row(root, "Accel Add", "Flat acceleration bonus", "accel_add", FieldKind::Number, &format!("{}", c.accel_add));
+        spawn_field_row(root, "Accel Mul", "Acceleration multiplier (1.0=unchanged)", "accel_mul", FieldKind::Number, &format!("{}", c.accel_mul));
+        spawn_field_row(root, "Radius Add", "Collision radius bonus", "radius_add", FieldKind::Number, &format!("{}", c.radius_add));
+        spawn_field_row(root, "Radius Mul", "Collision radius multiplier (1.0=unchanged)", "radius_mul", FieldKind::Number, &format!("{}", c.radius_mul));
 
-        spawn_field_row(root, "Name", "Display name", "name", &c.name);
-        spawn_field_row(root, "Move Speed Add", "Flat movement speed bonus", "move_speed_add", &format!("{}", c.move_speed_add));
-        spawn_field_row(root, "Move Speed Mul", "Movement speed multiplier (1.0=unchanged)", "move_speed_mul", &format!("{}", c.move_speed_mul));
-        spawn_field_row(root, "Accel Add", "Flat acceleration bonus", "accel_add", &format!("{}", c.accel_add));
-        spawn_field_row(root, "Accel Mul", "Acceleration multiplier (1.0=unchanged)", "accel_mul", &format!("{}", c.accel_mul));
-        spawn_field_row(root, "Radius Add", "Collision radius bonus", "radius_add", &format!("{}", c.radius_add));
-        spawn_field_row(root, "Radius Mul", "Collision radius multiplier (1.0=unchanged)", "radius_mul", &format!("{}", c.radius_mul));
+        spawn_save_error_text(root);
 
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1380,6 +2575,7 @@ fn spawn_chassis_editor(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Set Image", EditorButton::SetImage);
+            spawn_button(row, "Clear Image", ClearImageButton("chassis"));
             spawn_button(row, "Save", EditorButton::Save);
             spawn_button(row, "Cancel", EditorButton::Cancel);
         });
@@ -1390,7 +2586,7 @@ fn chassis_editor_system(
     mut q: Query<(&Interaction, &EditorButton, &mut BackgroundColor), Changed<Interaction>>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
-    state: ResMut<DesignState>,
+    mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
@@ -1399,11 +2595,16 @@ fn chassis_editor_system(
         if *interaction == Interaction::Pressed {
             match button {
                 EditorButton::Save => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    if let Some(msg) = invalid_numeric_message(&inputs, |_| true) {
+                        state.save_error = Some(msg);
+                        continue;
+                    }
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     let name = read_field(&inputs, "name");
                     let spec = ChassisSpec {
                         id: id.clone(),
                         name: if name.is_empty() { "My Chassis".into() } else { name },
+                        description: read_field(&inputs, "description"),
                         move_speed_add: read_f32(&inputs, "move_speed_add", 0.0),
                         move_speed_mul: read_f32(&inputs, "move_speed_mul", 1.0),
                         accel_add: read_f32(&inputs, "accel_add", 0.0),
@@ -1422,7 +2623,7 @@ fn chassis_editor_system(
                     next_state.set(if state.return_to_manage { GamePhase::ManageParts } else { GamePhase::DesignHub });
                 }
                 EditorButton::SetImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_image("chassis", &id);
                 }
             }
@@ -1464,14 +2665,29 @@ fn spawn_screw_editor(
         let title = if state.return_to_manage { "Edit Screw" } else { "New Screw" };
         spawn_title(root, title);
 
-        let img = state.editing_part_id.as_ref().map(|id| asset_server.load(format!("screws/{}.png", id)));
-        spawn_image_preview(root, img, 96.0);
-
-        spawn_field_row(root, "Name", "Display name", "name", &s.name);
-        spawn_field_row(root, "Max HP Add", "Max spin (HP) bonus", "spin_hp_max_add", &format!("{}", s.passive.spin_hp_max_add));
-        spawn_field_row(root, "Control Reduction", "Control effect reduction (stun/slow/knockback)", "control_reduction", &format!("{}", s.passive.control_reduction));
-        spawn_field_row(root, "Damage Out Mul", "Outgoing damage multiplier (1.0=normal)", "damage_out_mult", &format!("{}", s.passive.damage_out_mult));
-        spawn_field_row(root, "Damage In Mul", "Incoming damage multiplier (<1.0=tankier)", "damage_in_mult", &format!("{}", s.passive.damage_in_mult));
+        spawn_editor_image_preview(root, &asset_server, "screws", state.editing_part_id.as_ref());
+
+        spawn_field_row(root, "Name", "Display name", "name", FieldKind::Text, &s.name);
+        spawn_field_row(root, "Description", "Flavor text shown on cards", "description", FieldKind::Text, &s.description);
+        spawn_copy_from_row(root, PickTarget::Part(PartSlot::TraitScrew));
+        spawn_field_row(root, "Max HP Add", "Max spin (HP) bonus", "spin_hp_max_add", FieldKind::Number, &format!("{}", s.passive.spin_hp_max_add));
+        spawn_field_row(root, "Control Reduction", "Control effect reduction (stun/slow/knockback)", "control_reduction", FieldKind::Number, &format!("{}", s.passive.control_reduction));
+        spawn_field_row(root, "Damage Out Mul", "Outgoing damage multiplier (1.0=normal)", "damage_out_mult", FieldKind::Number, &format!("{}", s.passive.damage_out_mult));
+        spawn_field_row(root, "Damage In Mul", "Incoming damage multiplier (<1.0=tankier)", "damage_in_mult", FieldKind::Number, &format!("{}", s.passive.damage_in_mult));
+        let by_kind = &s.passive.damage_in_by_kind;
+        spawn_field_row(root, "Vs Collision", "Extra intake multiplier for top collisions", "in_collision", FieldKind::Number, &format!("{}", by_kind.collision.0));
+        spawn_field_row(root, "Vs Melee", "Extra intake multiplier for melee hits", "in_melee", FieldKind::Number, &format!("{}", by_kind.melee.0));
+        spawn_field_row(root, "Vs Projectile", "Extra intake multiplier for projectiles", "in_projectile", FieldKind::Number, &format!("{}", by_kind.projectile.0));
+        spawn_field_row(root, "Vs Wall", "Extra intake multiplier for wall impacts", "in_wall", FieldKind::Number, &format!("{}", by_kind.wall.0));
+        spawn_field_row(root, "Vs Obstacle", "Extra intake multiplier for obstacle contact", "in_obstacle", FieldKind::Number, &format!("{}", by_kind.obstacle.0));
+        spawn_field_row(root, "Stun Cap", "Longest stun in seconds after reduction (empty=no cap)", "stun_cap", FieldKind::OptionalNumber, &s.passive.stun_cap.map(|c| format!("{}", c)).unwrap_or_default());
+        let drops = s.hooks.contains(&TraitHookKind::OnTakeHit);
+        spawn_field_row(root, "Drop Obstacle", "When hit, drop a temporary obstacle behind (0 = no, 1 = yes)", "hook_drop_obstacle", FieldKind::Integer, if drops { "1" } else { "0" });
+        spawn_field_row(root, "Thorns", "Fraction of collision damage sent back to the attacker (0 = off)", "hook_thorns", FieldKind::Number, &format!("{}", s.thorns()));
+        spawn_field_row(root, "Hit Bonus", "Extra damage on every hit this top lands (0 = off)", "hook_hit_bonus", FieldKind::Number, &format!("{}", s.hit_bonus()));
+
+        spawn_live_stats_panel(root);
+        spawn_save_error_text(root);
 
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1480,6 +2696,7 @@ fn spawn_screw_editor(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Set Image", EditorButton::SetImage);
+            spawn_button(row, "Clear Image", ClearImageButton("screws"));
             spawn_button(row, "Save", EditorButton::Save);
             spawn_button(row, "Cancel", EditorButton::Cancel);
         });
@@ -1490,7 +2707,7 @@ fn screw_editor_system(
     mut q: Query<(&Interaction, &EditorButton, &mut BackgroundColor), Changed<Interaction>>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
-    state: ResMut<DesignState>,
+    mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
@@ -1499,19 +2716,12 @@ fn screw_editor_system(
         if *interaction == Interaction::Pressed {
             match button {
                 EditorButton::Save => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
-                    let name = read_field(&inputs, "name");
-                    let spec = TraitScrewSpec {
-                        id: id.clone(),
-                        name: if name.is_empty() { "My Screw".into() } else { name },
-                        passive: crate::game::parts::trait_screw::TraitPassive {
-                            spin_hp_max_add: read_f32(&inputs, "spin_hp_max_add", 0.0),
-                            control_reduction: read_f32(&inputs, "control_reduction", 0.0),
-                            damage_out_mult: read_f32(&inputs, "damage_out_mult", 1.0),
-                            damage_in_mult: read_f32(&inputs, "damage_in_mult", 1.0),
-                        },
-                        hooks: vec![],
-                    };
+                    if let Some(msg) = invalid_numeric_message(&inputs, |_| true) {
+                        state.save_error = Some(msg);
+                        continue;
+                    }
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
+                    let spec = read_screw_spec(&inputs, &id);
                     if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
                         let json = serde_json::to_string(&spec).unwrap_or_default();
                         let _ = repo.save_part_sync(&rt.0, "screw", "screw", &id, &json);
@@ -1523,7 +2733,7 @@ fn screw_editor_system(
                     next_state.set(if state.return_to_manage { GamePhase::ManageParts } else { GamePhase::DesignHub });
                 }
                 EditorButton::SetImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_image("screws", &id);
                 }
             }
@@ -1532,6 +2742,63 @@ fn screw_editor_system(
     }
 }
 
+fn read_screw_spec(inputs: &Query<&TextInput>, id: &str) -> TraitScrewSpec {
+    let name = read_field(inputs, "name");
+    TraitScrewSpec {
+        id: id.into(),
+        name: if name.is_empty() { "My Screw".into() } else { name },
+        description: read_field(inputs, "description"),
+        passive: crate::game::parts::trait_screw::TraitPassive {
+            spin_hp_max_add: read_f32(inputs, "spin_hp_max_add", 0.0),
+            control_reduction: read_f32(inputs, "control_reduction", 0.0),
+            damage_out_mult: read_f32(inputs, "damage_out_mult", 1.0),
+            damage_in_mult: read_f32(inputs, "damage_in_mult", 1.0),
+            damage_in_by_kind: DamageIntake {
+                collision: Multiplier::new(read_f32(inputs, "in_collision", 1.0)),
+                melee: Multiplier::new(read_f32(inputs, "in_melee", 1.0)),
+                projectile: Multiplier::new(read_f32(inputs, "in_projectile", 1.0)),
+                wall: Multiplier::new(read_f32(inputs, "in_wall", 1.0)),
+                obstacle: Multiplier::new(read_f32(inputs, "in_obstacle", 1.0)),
+            },
+            stun_cap: read_field(inputs, "stun_cap").parse::<f32>().ok().map(|c| c.max(0.0)),
+        },
+        hooks: read_screw_hooks(inputs),
+    }
+}
+
+/// Each hook field adds its hook when set; zero leaves it off.
+fn read_screw_hooks(inputs: &Query<&TextInput>) -> Vec<TraitHookKind> {
+    let mut hooks = Vec::new();
+    if read_u32(inputs, "hook_drop_obstacle", 0) != 0 {
+        hooks.push(TraitHookKind::OnTakeHit);
+    }
+    let fraction = read_f32(inputs, "hook_thorns", 0.0).clamp(0.0, 1.0);
+    if fraction > 0.0 {
+        hooks.push(TraitHookKind::Thorns { fraction });
+    }
+    let amount = read_f32(inputs, "hook_hit_bonus", 0.0).max(0.0);
+    if amount > 0.0 {
+        hooks.push(TraitHookKind::OnHitDealBonus { amount });
+    }
+    hooks
+}
+
+/// The screw's modifiers applied to the default wheel, as typed so far.
+fn screw_live_stats_system(
+    inputs: Query<&TextInput>,
+    tuning: Res<Tuning>,
+    mut q: Query<&mut Text, With<LiveStatsText>>,
+) {
+    let mods = read_screw_spec(&inputs, "").to_modifiers();
+    let eff = mods.compute_effective(&BaseStats::default(), &tuning);
+    for mut text in &mut q {
+        let line = live_stats_text(&eff);
+        if text.0 != line {
+            text.0 = line;
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // WEAPON EDITOR
 // ═══════════════════════════════════════════════════════════════════════
@@ -1565,17 +2832,142 @@ struct AimModeSelector {
 #[derive(Component)]
 struct AimModeSelectorLabel;
 
-fn aim_mode_label(mode: AimMode) -> &'static str {
-    match mode {
-        AimMode::FollowSpin => "FollowSpin",
-        AimMode::SeekNearestTarget => "SeekNearest",
-    }
+/// Which `ControlEffect` a melee hit applies; its numbers are `m_ctrl_*` fields.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HitControlKind { None, Stun, Slow, Knockback }
+
+#[derive(Component)]
+struct HitControlSelector {
+    current: HitControlKind,
+    just_pressed: bool,
 }
 
-fn next_aim_mode(mode: AimMode) -> AimMode {
+#[derive(Component)]
+struct HitControlSelectorLabel;
+
+/// Yes / No button for `RangedSpec::bounces_off_obstacles`.
+#[derive(Component)]
+struct BouncesToggle {
+    on: bool,
+}
+
+#[derive(Component)]
+struct BouncesToggleLabel;
+
+/// Wrapper around one `m_ctrl_*` field row, shown only for kinds that use it.
+#[derive(Component)]
+struct HitControlField(&'static str);
+
+/// Frame holding the live hitbox diagram; its children are redrawn by
+/// `weapon_diagram_system` whenever the geometry fields change.
+#[derive(Component)]
+struct WeaponDiagram;
+
+/// Show / hide button for the hitbox diagram.
+#[derive(Component)]
+struct WeaponDiagramToggle {
+    shown: bool,
+}
+
+#[derive(Component)]
+struct WeaponDiagramToggleLabel;
+
+/// Weapon geometry the diagram was last drawn for.
+#[derive(Clone, Copy, PartialEq)]
+struct DiagramShape {
+    kind: WeaponKind,
+    hitbox_radius: f32,
+    hitbox_angle: f32,
+    burst_count: u32,
+    spread_angle: f32,
+}
+
+const DIAGRAM_SIZE: f32 = 180.0;
+/// Body radius the diagram is drawn around (the default wheel's).
+const DIAGRAM_BODY_RADIUS: f32 = 1.3;
+/// How far spread rays extend past the rim, in world units.
+const DIAGRAM_RAY_LEN: f32 = 3.0;
+/// Spacing between the dots that trace lines and arcs, in pixels.
+const DIAGRAM_DOT_STEP: f32 = 5.0;
+const DIAGRAM_MAX_RAYS: u32 = 16;
+const COLOR_MELEE_ARC: Color = Color::srgba(1.0, 0.6, 0.25, 0.9);
+const COLOR_SPREAD_RAY: Color = Color::srgba(0.4, 0.85, 1.0, 0.9);
+
+fn aim_mode_label(mode: AimMode) -> &'static str {
+    match mode {
+        AimMode::FollowSpin => "FollowSpin",
+        AimMode::SeekNearestTarget => "SeekNearest",
+        AimMode::FixedForward => "FixedForward",
+    }
+}
+
+fn next_aim_mode(mode: AimMode) -> AimMode {
     match mode {
         AimMode::FollowSpin => AimMode::SeekNearestTarget,
-        AimMode::SeekNearestTarget => AimMode::FollowSpin,
+        AimMode::SeekNearestTarget => AimMode::FixedForward,
+        AimMode::FixedForward => AimMode::FollowSpin,
+    }
+}
+
+fn hit_control_kind(control: Option<ControlEffect>) -> HitControlKind {
+    match control {
+        None => HitControlKind::None,
+        Some(ControlEffect::Stun { .. }) => HitControlKind::Stun,
+        Some(ControlEffect::Slow { .. }) => HitControlKind::Slow,
+        Some(ControlEffect::Knockback { .. }) => HitControlKind::Knockback,
+    }
+}
+
+fn hit_control_label(kind: HitControlKind) -> &'static str {
+    match kind {
+        HitControlKind::None => "None",
+        HitControlKind::Stun => "Stun",
+        HitControlKind::Slow => "Slow",
+        HitControlKind::Knockback => "Knockback",
+    }
+}
+
+fn next_hit_control(kind: HitControlKind) -> HitControlKind {
+    match kind {
+        HitControlKind::None => HitControlKind::Stun,
+        HitControlKind::Stun => HitControlKind::Slow,
+        HitControlKind::Slow => HitControlKind::Knockback,
+        HitControlKind::Knockback => HitControlKind::None,
+    }
+}
+
+/// Whether field `key` applies under `kind` (non-`m_ctrl_*` keys always do).
+fn hit_control_uses_field(kind: HitControlKind, key: &str) -> bool {
+    match key {
+        "m_ctrl_duration" => matches!(kind, HitControlKind::Stun | HitControlKind::Slow),
+        "m_ctrl_slow_ratio" => kind == HitControlKind::Slow,
+        "m_ctrl_knockback" => kind == HitControlKind::Knockback,
+        _ => true,
+    }
+}
+
+/// `(duration, slow ratio, knockback distance)` field values for a control.
+fn hit_control_values(control: Option<ControlEffect>) -> (f32, f32, f32) {
+    match control {
+        Some(ControlEffect::Stun { duration }) => (duration.0, 0.5, 2.0),
+        Some(ControlEffect::Slow { duration, ratio }) => (duration.0, ratio, 2.0),
+        Some(ControlEffect::Knockback { distance }) => (0.5, 0.5, distance),
+        None => (0.5, 0.5, 2.0),
+    }
+}
+
+fn read_hit_control(inputs: &Query<&TextInput>, kind: HitControlKind) -> Option<ControlEffect> {
+    let duration = crate::game::stats::types::Seconds::new(read_f32(inputs, "m_ctrl_duration", 0.5));
+    match kind {
+        HitControlKind::None => None,
+        HitControlKind::Stun => Some(ControlEffect::Stun { duration }),
+        HitControlKind::Slow => Some(ControlEffect::Slow {
+            duration,
+            ratio: read_f32(inputs, "m_ctrl_slow_ratio", 0.5).clamp(0.0, 1.0),
+        }),
+        HitControlKind::Knockback => Some(ControlEffect::Knockback {
+            distance: read_f32(inputs, "m_ctrl_knockback", 2.0).max(0.0),
+        }),
     }
 }
 
@@ -1591,6 +2983,7 @@ fn spawn_weapon_editor(
         .unwrap_or(WeaponWheelSpec {
             id: String::new(),
             name: "My Weapon".into(),
+            description: String::new(),
             kind: WeaponKind::Sword,
             melee: Some(MeleeSpec::default()),
             ranged: None,
@@ -1620,10 +3013,11 @@ fn spawn_weapon_editor(
         let title = if state.return_to_manage { "Edit Weapon" } else { "New Weapon" };
         spawn_title(root, title);
 
-        let img = state.editing_part_id.as_ref().map(|id| asset_server.load(format!("weapons/{}.png", id)));
-        spawn_image_preview(root, img, 96.0);
+        spawn_editor_image_preview(root, &asset_server, "weapons", state.editing_part_id.as_ref());
 
-        spawn_field_row(root, "Name", "Display name", "name", &w.name);
+        spawn_field_row(root, "Name", "Display name", "name", FieldKind::Text, &w.name);
+        spawn_field_row(root, "Description", "Flavor text shown on cards", "description", FieldKind::Text, &w.description);
+        spawn_copy_from_row(root, PickTarget::Part(PartSlot::WeaponWheel));
 
         // Kind selector (radio buttons)
         root.spawn((
@@ -1662,9 +3056,46 @@ fn spawn_weapon_editor(
             }
         });
 
-        let show_melee = !kind.is_ranged();
+        // Hitbox diagram (top view, weapon facing right)
+        root.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|col| {
+            col.spawn((
+                WeaponDiagramToggle { shown: true },
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                    border_radius: BorderRadius::all(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(COLOR_BTN),
+            )).with_children(|btn| {
+                btn.spawn((
+                    WeaponDiagramToggleLabel,
+                    Text::new("Hide Hitboxes"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COLOR_TEXT),
+                ));
+            });
+            col.spawn((
+                WeaponDiagram,
+                Node {
+                    width: Val::Px(DIAGRAM_SIZE),
+                    height: Val::Px(DIAGRAM_SIZE),
+                    border_radius: BorderRadius::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(COLOR_CARD),
+            ));
+        });
+
+        let show_melee = kind.has_melee();
+        let show_ranged = kind.has_ranged();
 
-        // Melee section (shown when kind == Melee)
+        // Melee section (shown for Sword / Hybrid)
         root.spawn((
             MeleeSection,
             Node {
@@ -1680,20 +3111,69 @@ fn spawn_weapon_editor(
                 TextFont { font_size: 14.0, ..default() },
                 TextColor(COLOR_ACCENT),
             ));
-            spawn_field_row(section, "Base Damage", "Base damage per hit", "m_base_damage", &format!("{}", m.base_damage));
-            spawn_field_row(section, "Hit Cooldown", "Cooldown between hits on same target (sec)", "m_hit_cooldown", &format!("{}", m.hit_cooldown));
-            spawn_field_row(section, "Hitbox Radius", "Attack hitbox distance", "m_hitbox_radius", &format!("{}", m.hitbox_radius));
-            spawn_field_row(section, "Hitbox Angle", "Attack arc angle (radians)", "m_hitbox_angle", &format!("{}", m.hitbox_angle));
-            spawn_field_row(section, "Blade Len", "Blade length (world units)", "m_blade_len", &format!("{}", m.blade_len));
-            spawn_field_row(section, "Blade Thick", "Blade thickness", "m_blade_thick", &format!("{}", m.blade_thick));
-            spawn_field_row(section, "Spin Rate Mul", "Visual spin rate multiplier", "m_spin_rate", &format!("{}", m.spin_rate_multiplier));
+            spawn_field_row(section, "Base Damage", "Base damage per hit", "m_base_damage", FieldKind::Number, &format!("{}", m.base_damage));
+            spawn_field_row(section, "Hit Cooldown", "Cooldown between hits on same target (sec)", "m_hit_cooldown", FieldKind::Number, &format!("{}", m.hit_cooldown));
+            spawn_field_row(section, "Hitbox Radius", "Attack hitbox distance", "m_hitbox_radius", FieldKind::Number, &format!("{}", m.hitbox_radius));
+            spawn_field_row(section, "Hitbox Angle", "Attack arc angle (radians)", "m_hitbox_angle", FieldKind::Number, &format!("{}", m.hitbox_angle));
+            spawn_field_row(section, "Blade Len", "Blade length (world units)", "m_blade_len", FieldKind::Number, &format!("{}", m.blade_len));
+            spawn_field_row(section, "Blade Thick", "Blade thickness", "m_blade_thick", FieldKind::Number, &format!("{}", m.blade_thick));
+            spawn_field_row(section, "Spin Rate Mul", "Visual spin rate multiplier", "m_spin_rate", FieldKind::Number, &format!("{}", m.spin_rate_multiplier));
+
+            // Hit control cycle button + the fields the chosen control uses
+            let control = hit_control_kind(m.hit_control);
+            section.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new("On Hit:"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COLOR_TEXT_DIM),
+                ));
+                row.spawn((
+                    HitControlSelector { current: control, just_pressed: false },
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        border_radius: BorderRadius::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COLOR_BTN),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        HitControlSelectorLabel,
+                        Text::new(hit_control_label(control)),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(COLOR_TEXT),
+                    ));
+                });
+            });
+            let (duration, ratio, distance) = hit_control_values(m.hit_control);
+            for (key, label, hint, value) in [
+                ("m_ctrl_duration", "Control Duration", "Stun / slow duration (sec)", duration),
+                ("m_ctrl_slow_ratio", "Slow Ratio", "Speed multiplier while slowed (0-1)", ratio),
+                ("m_ctrl_knockback", "Knockback", "Knockback distance (world units)", distance),
+            ] {
+                section.spawn((
+                    HitControlField(key),
+                    Node {
+                        display: if hit_control_uses_field(control, key) { Display::Flex } else { Display::None },
+                        ..default()
+                    },
+                )).with_children(|wrap| {
+                    spawn_field_row(wrap, label, hint, key, FieldKind::Number, &format!("{}", value));
+                });
+            }
         });
 
-        // Ranged section (shown when kind == Ranged)
+        // Ranged section (shown for Bow / Gun / Hybrid)
         root.spawn((
             RangedSection,
             Node {
-                display: if !show_melee { Display::Flex } else { Display::None },
+                display: if show_ranged { Display::Flex } else { Display::None },
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
                 row_gap: Val::Px(8.0),
@@ -1705,16 +3185,48 @@ fn spawn_weapon_editor(
                 TextFont { font_size: 14.0, ..default() },
                 TextColor(COLOR_ACCENT),
             ));
-            spawn_field_row(section, "Proj Damage", "Damage per projectile", "r_proj_damage", &format!("{}", r.projectile_damage));
-            spawn_field_row(section, "Fire Rate", "Shots per second", "r_fire_rate", &format!("{}", r.fire_rate));
-            spawn_field_row(section, "Burst Count", "Projectiles per burst", "r_burst_count", &format!("{}", r.burst_count));
-            spawn_field_row(section, "Spread Angle", "Spread angle (radians)", "r_spread_angle", &format!("{}", r.spread_angle));
-            spawn_field_row(section, "Proj Radius", "Projectile radius", "r_proj_radius", &format!("{}", r.projectile_radius));
-            spawn_field_row(section, "Lifetime", "Projectile lifetime (sec)", "r_lifetime", &format!("{}", r.lifetime.0));
-            spawn_field_row(section, "Proj Speed", "Projectile speed", "r_proj_speed", &format!("{}", r.projectile_speed));
-            spawn_field_row(section, "Barrel Len", "Barrel length", "r_barrel_len", &format!("{}", r.barrel_len));
-            spawn_field_row(section, "Barrel Thick", "Barrel thickness", "r_barrel_thick", &format!("{}", r.barrel_thick));
-            spawn_field_row(section, "Spin Rate Mul", "Visual spin rate multiplier", "r_spin_rate", &format!("{}", r.spin_rate_multiplier));
+            spawn_field_row(section, "Proj Damage", "Damage per projectile", "r_proj_damage", FieldKind::Number, &format!("{}", r.projectile_damage));
+            spawn_field_row(section, "Fire Rate", "Shots per second", "r_fire_rate", FieldKind::Number, &format!("{}", r.fire_rate));
+            spawn_field_row(section, "Burst Count", "Projectiles per burst", "r_burst_count", FieldKind::Integer, &format!("{}", r.burst_count));
+            spawn_field_row(section, "Spread Angle", "Spread angle (radians)", "r_spread_angle", FieldKind::Number, &format!("{}", r.spread_angle));
+            spawn_field_row(section, "Proj Radius", "Projectile radius", "r_proj_radius", FieldKind::Number, &format!("{}", r.projectile_radius));
+            spawn_field_row(section, "Lifetime", "Projectile lifetime (sec)", "r_lifetime", FieldKind::Number, &format!("{}", r.lifetime.0));
+            spawn_field_row(section, "Proj Speed", "Projectile speed", "r_proj_speed", FieldKind::Number, &format!("{}", r.projectile_speed));
+            spawn_field_row(section, "Barrel Len", "Barrel length", "r_barrel_len", FieldKind::Number, &format!("{}", r.barrel_len));
+            spawn_field_row(section, "Barrel Thick", "Barrel thickness", "r_barrel_thick", FieldKind::Number, &format!("{}", r.barrel_thick));
+            spawn_field_row(section, "Spin Rate Mul", "Visual spin rate multiplier", "r_spin_rate", FieldKind::Number, &format!("{}", r.spin_rate_multiplier));
+
+            // Ricochet off obstacles toggle
+            section.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new("Bounces:"),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(COLOR_TEXT_DIM),
+                ));
+                row.spawn((
+                    BouncesToggle { on: r.bounces_off_obstacles },
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        border_radius: BorderRadius::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COLOR_BTN),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        BouncesToggleLabel,
+                        Text::new(bounces_label(r.bounces_off_obstacles)),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(COLOR_TEXT),
+                    ));
+                });
+            });
 
             // Aim mode toggle button
             section.spawn(Node {
@@ -1759,6 +3271,8 @@ fn spawn_weapon_editor(
             });
         });
 
+        spawn_save_error_text(root);
+
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
             column_gap: Val::Px(12.0),
@@ -1766,6 +3280,7 @@ fn spawn_weapon_editor(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Set Image", WeaponEditorButton::SetImage);
+            spawn_button(row, "Clear Image", ClearImageButton("weapons"));
             spawn_button(row, "Set Proj Image", WeaponEditorButton::SetProjectileImage);
             spawn_button(row, "Set Hit Sound", WeaponEditorButton::SetHitSound);
             spawn_button(row, "Save", WeaponEditorButton::Save);
@@ -1782,9 +3297,11 @@ fn weapon_editor_system(
     mut aim_labels: Query<&mut Text, With<AimModeSelectorLabel>>,
     mut melee_sections: Query<&mut Node, (With<MeleeSection>, Without<RangedSection>)>,
     mut ranged_sections: Query<&mut Node, (With<RangedSection>, Without<MeleeSection>)>,
+    hit_control_q: Query<&HitControlSelector>,
+    bounces_q: Query<&BouncesToggle>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
-    state: ResMut<DesignState>,
+    mut state: ResMut<DesignState>,
     mut registry: ResMut<PartRegistry>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
@@ -1800,12 +3317,11 @@ fn weapon_editor_system(
         if let Ok(mut ks) = kind_selector_q.single_mut() {
             ks.current = kind;
         }
-        let show_melee = !kind.is_ranged();
         for mut node in &mut melee_sections {
-            node.display = if show_melee { Display::Flex } else { Display::None };
+            node.display = if kind.has_melee() { Display::Flex } else { Display::None };
         }
         for mut node in &mut ranged_sections {
-            node.display = if !show_melee { Display::Flex } else { Display::None };
+            node.display = if kind.has_ranged() { Display::Flex } else { Display::None };
         }
     }
 
@@ -1847,29 +3363,38 @@ fn weapon_editor_system(
         if *interaction == Interaction::Pressed {
             match button {
                 WeaponEditorButton::Save => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     let name = read_field(&inputs, "name");
                     let kind = kind_selector_q.single()
                         .map(|ks| ks.current)
                         .unwrap_or(WeaponKind::Sword);
+                    let hit_control = hit_control_q.iter().next().map_or(HitControlKind::None, |s| s.current);
+                    // Fields of a hidden section aren't saved, so they can't block
+                    let relevant = |key: &str| {
+                        (kind.has_melee() || !key.starts_with("m_"))
+                            && (kind.has_ranged() || !key.starts_with("r_"))
+                            && hit_control_uses_field(hit_control, key)
+                    };
+                    if let Some(msg) = invalid_numeric_message(&inputs, relevant) {
+                        state.save_error = Some(msg);
+                        continue;
+                    }
 
-                    let is_ranged = kind.is_ranged();
-
-                    let melee = if !is_ranged {
+                    let melee = if kind.has_melee() {
                         Some(MeleeSpec {
                             base_damage: read_f32(&inputs, "m_base_damage", 5.5),
                             hit_cooldown: read_f32(&inputs, "m_hit_cooldown", 0.5),
                             max_hits_per_rotation: 0,
                             hitbox_radius: read_f32(&inputs, "m_hitbox_radius", 2.5),
                             hitbox_angle: read_f32(&inputs, "m_hitbox_angle", 1.047),
-                            hit_control: None,
+                            hit_control: read_hit_control(&inputs, hit_control),
                             spin_rate_multiplier: read_f32(&inputs, "m_spin_rate", 0.8),
                             blade_len: read_f32(&inputs, "m_blade_len", 2.3),
                             blade_thick: read_f32(&inputs, "m_blade_thick", 0.4),
                         })
                     } else { None };
 
-                    let ranged = if is_ranged {
+                    let ranged = if kind.has_ranged() {
                         Some(RangedSpec {
                             projectile_damage: read_f32(&inputs, "r_proj_damage", 7.0),
                             fire_rate: read_f32(&inputs, "r_fire_rate", 3.0),
@@ -1886,12 +3411,14 @@ fn weapon_editor_system(
                             barrel_thick: read_f32(&inputs, "r_barrel_thick", 0.3),
                             projectile_visual_len: kind.projectile_dims().0,
                             projectile_visual_thick: kind.projectile_dims().1,
+                            bounces_off_obstacles: bounces_q.iter().next().is_some_and(|t| t.on),
                         })
                     } else { None };
 
                     let spec = WeaponWheelSpec {
                         id: id.clone(),
                         name: if name.is_empty() { "My Weapon".into() } else { name },
+                        description: read_field(&inputs, "description"),
                         kind,
                         melee,
                         ranged,
@@ -1909,23 +3436,23 @@ fn weapon_editor_system(
                     next_state.set(if state.return_to_manage { GamePhase::ManageParts } else { GamePhase::DesignHub });
                 }
                 WeaponEditorButton::SetImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_image("weapons", &id);
                 }
                 WeaponEditorButton::SetProjectileImage => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
-                    let dest = format!("assets/projectiles/{}_projectile.png", id);
-                    if let Some(path) = rfd::FileDialog::new().add_filter("PNG", &["png"]).pick_file() {
-                        let _ = std::fs::create_dir_all("assets/projectiles");
-                        let _ = std::fs::copy(&path, &dest);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
+                    if let Some(path) = rfd::FileDialog::new().add_filter("Image", IMAGE_EXTENSIONS).pick_file() {
+                        if let Err(e) = import_image(&path, &projectile_image_path(&id)) {
+                            warn!("Failed to import projectile image: {e}");
+                        }
                     }
                 }
                 WeaponEditorButton::SetHitSound => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_audio("hit", &id);
                 }
                 WeaponEditorButton::SetFireSound => {
-                    let id = state.editing_part_id.clone().unwrap_or_else(gen_custom_id);
+                    let id = state.editing_part_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     pick_and_copy_audio("fire", &id);
                 }
             }
@@ -1934,6 +3461,378 @@ fn weapon_editor_system(
     }
 }
 
+/// Cycle the melee on-hit control and keep its label and field rows in sync
+/// (also after Copy From swaps `current`).
+fn hit_control_selector_system(
+    mut selectors: Query<(&Interaction, &mut HitControlSelector, &mut BackgroundColor, &Children)>,
+    mut labels: Query<&mut Text, With<HitControlSelectorLabel>>,
+    mut fields: Query<(&HitControlField, &mut Node)>,
+) {
+    for (interaction, mut selector, mut bg, children) in &mut selectors {
+        if *interaction == Interaction::Pressed && !selector.just_pressed {
+            selector.just_pressed = true;
+            selector.current = next_hit_control(selector.current);
+        }
+        if *interaction != Interaction::Pressed && selector.just_pressed {
+            selector.just_pressed = false;
+        }
+        match interaction {
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            _ => {}
+        }
+        if !selector.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = labels.get_mut(child) {
+                **text = hit_control_label(selector.current).into();
+            }
+        }
+        for (field, mut node) in &mut fields {
+            node.display = if hit_control_uses_field(selector.current, field.0) { Display::Flex } else { Display::None };
+        }
+    }
+}
+
+fn bounces_label(on: bool) -> &'static str {
+    if on { "Yes" } else { "No" }
+}
+
+/// Flip the ricochet toggle on click and keep its label in sync (also after
+/// Copy From sets `on`).
+fn bounces_toggle_system(
+    mut toggles: Query<(Ref<Interaction>, &mut BouncesToggle, &mut BackgroundColor, &Children)>,
+    mut labels: Query<&mut Text, With<BouncesToggleLabel>>,
+) {
+    for (interaction, mut toggle, mut bg, children) in &mut toggles {
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            toggle.on = !toggle.on;
+        }
+        hover_system(&interaction, &mut bg);
+        if !toggle.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = labels.get_mut(child) {
+                **text = bounces_label(toggle.on).into();
+            }
+        }
+    }
+}
+
+/// Redraw the hitbox diagram from the current field values: the melee arc
+/// wedge out to `radius + hitbox_radius`, and one ray per burst projectile
+/// across `spread_angle`, matching `detect_melee_hits` / `fire_ranged_weapons`.
+fn weapon_diagram_system(
+    mut commands: Commands,
+    inputs: Query<&TextInput>,
+    kind_selector_q: Query<&KindSelector>,
+    mut toggles: Query<(&Interaction, &mut WeaponDiagramToggle, &mut BackgroundColor, &Children), Changed<Interaction>>,
+    mut toggle_labels: Query<&mut Text, With<WeaponDiagramToggleLabel>>,
+    mut diagrams: Query<(Entity, &mut Node, Option<&Children>), With<WeaponDiagram>>,
+    mut drawn: Local<Option<DiagramShape>>,
+) {
+    for (interaction, mut toggle, mut bg, children) in &mut toggles {
+        if *interaction == Interaction::Pressed {
+            toggle.shown = !toggle.shown;
+            for child in children.iter() {
+                if let Ok(mut text) = toggle_labels.get_mut(child) {
+                    **text = if toggle.shown { "Hide Hitboxes" } else { "Show Hitboxes" }.into();
+                }
+            }
+            for (_, mut node, _) in &mut diagrams {
+                node.display = if toggle.shown { Display::Flex } else { Display::None };
+            }
+        }
+        hover_system(interaction, &mut bg);
+    }
+
+    let Ok(kind) = kind_selector_q.single().map(|ks| ks.current) else {
+        return;
+    };
+    let shape = DiagramShape {
+        kind,
+        hitbox_radius: read_f32(&inputs, "m_hitbox_radius", 0.0).max(0.0),
+        hitbox_angle: read_f32(&inputs, "m_hitbox_angle", 0.0).clamp(0.0, std::f32::consts::TAU),
+        burst_count: read_u32(&inputs, "r_burst_count", 1).clamp(1, DIAGRAM_MAX_RAYS),
+        spread_angle: read_f32(&inputs, "r_spread_angle", 0.0).clamp(0.0, std::f32::consts::TAU),
+    };
+    let Ok((diagram, _, children)) = diagrams.single() else {
+        return;
+    };
+    // A freshly spawned (empty) frame needs drawing even if the shape is unchanged.
+    if *drawn == Some(shape) && children.is_some_and(|c| !c.is_empty()) {
+        return;
+    }
+    *drawn = Some(shape);
+
+    let center = DIAGRAM_SIZE * 0.5;
+    let body = DIAGRAM_BODY_RADIUS;
+    let melee_reach = if kind.has_melee() { body + shape.hitbox_radius } else { 0.0 };
+    let ray_reach = if kind.has_ranged() { body + DIAGRAM_RAY_LEN } else { 0.0 };
+    // World units → diagram pixels, so the longest shape fits inside the frame.
+    let scale = (center - 8.0) / melee_reach.max(ray_reach).max(body);
+    let to_px = |angle: f32, dist: f32| Vec2::new(center + angle.cos() * dist * scale, center - angle.sin() * dist * scale);
+
+    let mut dots: Vec<(Vec2, Color)> = Vec::new();
+    let ray = |dots: &mut Vec<(Vec2, Color)>, angle: f32, from: f32, to: f32, color: Color| {
+        let steps = (((to - from) * scale) / DIAGRAM_DOT_STEP).ceil().max(1.0) as usize;
+        for i in 0..=steps {
+            dots.push((to_px(angle, from + (to - from) * i as f32 / steps as f32), color));
+        }
+    };
+    if kind.has_melee() {
+        let half = shape.hitbox_angle / 2.0;
+        ray(&mut dots, half, 0.0, melee_reach, COLOR_MELEE_ARC);
+        ray(&mut dots, -half, 0.0, melee_reach, COLOR_MELEE_ARC);
+        let steps = ((shape.hitbox_angle * melee_reach * scale) / DIAGRAM_DOT_STEP).ceil().max(1.0) as usize;
+        for i in 0..=steps {
+            let a = -half + shape.hitbox_angle * i as f32 / steps as f32;
+            dots.push((to_px(a, melee_reach), COLOR_MELEE_ARC));
+        }
+    }
+    if kind.has_ranged() {
+        let count = shape.burst_count;
+        let step = if count > 1 { shape.spread_angle / (count - 1) as f32 } else { 0.0 };
+        for i in 0..count {
+            let a = -shape.spread_angle / 2.0 + step * i as f32;
+            ray(&mut dots, a, body, ray_reach, COLOR_SPREAD_RAY);
+        }
+    }
+
+    let body_px = body * 2.0 * scale;
+    commands.entity(diagram).despawn_related::<Children>().with_children(|frame| {
+        frame.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(center - body_px * 0.5),
+                top: Val::Px(center - body_px * 0.5),
+                width: Val::Px(body_px),
+                height: Val::Px(body_px),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.2, 0.6, 1.0, 0.35)),
+        ));
+        for (pos, color) in dots {
+            frame.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(pos.x - 1.5),
+                    top: Val::Px(pos.y - 1.5),
+                    width: Val::Px(3.0),
+                    height: Val::Px(3.0),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        }
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// COMPARE BUILDS (read-only)
+// ═══════════════════════════════════════════════════════════════════════
+
+const COLOR_DELTA_UP: Color = Color::srgba(0.4, 0.9, 0.4, 1.0);
+const COLOR_DELTA_DOWN: Color = Color::srgba(1.0, 0.4, 0.4, 1.0);
+
+#[derive(Component)]
+enum CompareButton {
+    /// Step a column (0 = left, 1 = right) to the previous / next build.
+    Prev(usize),
+    Next(usize),
+    Back,
+}
+
+/// One comparison row: label and how to format the value.
+struct CompareStat {
+    label: &'static str,
+    decimals: usize,
+    value: fn(&Build, &EffectiveStats) -> f32,
+}
+
+const COMPARE_STATS: &[CompareStat] = &[
+    CompareStat { label: "Max HP", decimals: 0, value: |_, e| e.spin_hp_max.0 },
+    CompareStat { label: "Radius", decimals: 2, value: |_, e| e.radius.0 },
+    CompareStat { label: "Speed", decimals: 1, value: |_, e| e.move_speed.0 },
+    CompareStat { label: "Accel", decimals: 1, value: |_, e| e.accel },
+    CompareStat { label: "Stability", decimals: 1, value: |_, e| e.stability },
+    CompareStat { label: "Control Mult", decimals: 2, value: |_, e| e.control_multiplier },
+    CompareStat { label: "Idle Drain /s", decimals: 2, value: |_, e| e.spin_drain_idle_per_sec },
+    CompareStat { label: "Damage Out", decimals: 2, value: |_, e| e.damage_out_mult.0 },
+    CompareStat { label: "Damage In", decimals: 2, value: |_, e| e.damage_in_mult.0 },
+    CompareStat { label: "Fire Rate Mult", decimals: 2, value: |_, e| e.fire_rate_mult.0 },
+    CompareStat { label: "Weapon DPS", decimals: 1, value: |b, e| b.peak_dps(e) },
+    CompareStat { label: "Power", decimals: 0, value: |_, e| power_score(e) },
+];
+
+fn spawn_compare_builds(
+    mut commands: Commands,
+    mut state: ResMut<DesignState>,
+    registry: Res<PartRegistry>,
+    tuning: Res<Tuning>,
+) {
+    let mut ids: Vec<&String> = registry.builds.keys().collect();
+    ids.sort();
+    // Fill empty or deleted columns with the first builds in id order
+    for col in 0..2 {
+        if !registry.builds.contains_key(&state.compare_build_ids[col]) {
+            state.compare_build_ids[col] = ids.get(col).or(ids.first()).map(|id| (*id).clone()).unwrap_or_default();
+        }
+    }
+    let columns: Vec<Option<(Build, EffectiveStats)>> = state
+        .compare_build_ids
+        .iter()
+        .map(|id| {
+            registry.resolve_build_id(id).map(|build| {
+                let eff = build.combined_modifiers().compute_effective(&build.wheel, &tuning);
+                (build, eff)
+            })
+        })
+        .collect();
+
+    commands.spawn((
+        ScreenRoot,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(30.0)),
+            row_gap: Val::Px(8.0),
+            overflow: Overflow::scroll_y(),
+            ..default()
+        },
+        ScrollPosition::default(),
+        BackgroundColor(COLOR_BG),
+    )).with_children(|root| {
+        spawn_title(root, "Compare Builds");
+
+        // Column pickers
+        root.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(24.0),
+            margin: UiRect::vertical(Val::Px(8.0)),
+            ..default()
+        }).with_children(|row| {
+            for (col, entry) in columns.iter().enumerate() {
+                let name = entry.as_ref().map(|(b, _)| b.name.as_str()).unwrap_or("(no build)");
+                row.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                }).with_children(|picker| {
+                    spawn_button(picker, "<", CompareButton::Prev(col));
+                    picker.spawn((
+                        Text::new(name),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(COLOR_ACCENT),
+                        Node { width: Val::Px(200.0), justify_content: JustifyContent::Center, ..default() },
+                    ));
+                    spawn_button(picker, ">", CompareButton::Next(col));
+                });
+            }
+        });
+
+        // Stat table: label | left | right | right - left
+        root.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(COLOR_CARD),
+        )).with_children(|table| {
+            for stat in COMPARE_STATS {
+                let values: Vec<Option<f32>> = columns
+                    .iter()
+                    .map(|entry| entry.as_ref().map(|(b, e)| (stat.value)(b, e)))
+                    .collect();
+                let delta = match (values[0], values[1]) {
+                    (Some(a), Some(b)) => Some(b - a),
+                    _ => None,
+                };
+                let fmt = |v: Option<f32>| v.map(|v| format!("{:.*}", stat.decimals, v)).unwrap_or_else(|| "-".into());
+                // Deltas that round to zero at the shown precision count as equal
+                let epsilon = 0.5 * 10f32.powi(-(stat.decimals as i32));
+                let (delta_text, delta_color) = match delta {
+                    Some(d) if d > epsilon => (format!("+{:.*}", stat.decimals, d), COLOR_DELTA_UP),
+                    Some(d) if d < -epsilon => (format!("{:.*}", stat.decimals, d), COLOR_DELTA_DOWN),
+                    _ => ("=".to_string(), COLOR_TEXT_DIM),
+                };
+                table.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    ..default()
+                }).with_children(|row| {
+                    let cells = [
+                        (stat.label.to_string(), COLOR_TEXT_DIM, 140.0),
+                        (fmt(values[0]), COLOR_TEXT, 110.0),
+                        (fmt(values[1]), COLOR_TEXT, 110.0),
+                        (delta_text, delta_color, 90.0),
+                    ];
+                    for (text, color, width) in cells {
+                        row.spawn((
+                            Text::new(text),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(color),
+                            Node { width: Val::Px(width), ..default() },
+                        ));
+                    }
+                });
+            }
+        });
+
+        root.spawn((
+            Text::new("Weapon DPS assumes every hit lands. Deltas are right minus left."),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(COLOR_TEXT_DIM),
+        ));
+
+        root.spawn(Node {
+            margin: UiRect::top(Val::Px(16.0)),
+            ..default()
+        }).with_children(|row| {
+            spawn_button(row, "Back", CompareButton::Back);
+        });
+    });
+}
+
+fn compare_builds_system(
+    mut q: Query<(&Interaction, &CompareButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    mut state: ResMut<DesignState>,
+    registry: Res<PartRegistry>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        if *interaction == Interaction::Pressed {
+            match button {
+                CompareButton::Prev(col) | CompareButton::Next(col) => {
+                    let mut ids: Vec<&String> = registry.builds.keys().collect();
+                    ids.sort();
+                    if ids.is_empty() {
+                        continue;
+                    }
+                    let current = ids.iter().position(|id| **id == state.compare_build_ids[*col]).unwrap_or(0);
+                    let step = if matches!(button, CompareButton::Next(_)) { 1 } else { ids.len() - 1 };
+                    state.compare_build_ids[*col] = ids[(current + step) % ids.len()].clone();
+                    // Re-enter to rebuild the table
+                    next_state.set(GamePhase::CompareBuilds);
+                }
+                CompareButton::Back => {
+                    next_state.set(GamePhase::ManageParts);
+                }
+            }
+        }
+        hover_system(interaction, &mut bg);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // ASSEMBLE BUILD
 // ═══════════════════════════════════════════════════════════════════════
@@ -1945,6 +3844,8 @@ enum AssembleButton {
     ChangeShaft,
     ChangeChassis,
     ChangeScrew,
+    ToggleLock(PickTarget),
+    ToggleBreakdown,
     SaveBuild,
     Back,
 }
@@ -1952,11 +3853,15 @@ enum AssembleButton {
 #[derive(Component)]
 struct StatsPreviewText;
 
+#[derive(Component)]
+struct StatBreakdownPanel;
+
 fn spawn_assemble_build(
     mut commands: Commands,
     state: Res<DesignState>,
     registry: Res<PartRegistry>,
     tuning: Res<Tuning>,
+    selection: Res<GameSelection>,
     asset_server: Res<AssetServer>,
 ) {
     let top_name = registry.wheels.get(&state.current_build_wheel_id).map(|t| t.name.as_str()).unwrap_or("?");
@@ -1966,7 +3871,7 @@ fn spawn_assemble_build(
     let screw_name = registry.screws.get(&state.current_build_screw_id).map(|s| s.name.as_str()).unwrap_or("?");
 
     // Compute combined stats
-    let stats_text = if let Some(build) = registry.resolve_build(
+    let preview_build = registry.resolve_build(
         "preview",
         "",
         &state.current_build_wheel_id,
@@ -1974,13 +3879,21 @@ fn spawn_assemble_build(
         &state.current_build_shaft_id,
         &state.current_build_chassis_id,
         &state.current_build_screw_id,
-    ) {
-        let mods = build.combined_modifiers();
-        let eff = mods.compute_effective(&build.wheel, &tuning);
+    );
+    let preview_eff = preview_build
+        .as_ref()
+        .map(|build| build.combined_modifiers().compute_effective(&build.wheel, &tuning));
+    let mut over_budget = false;
+    let stats_text = if let Some(eff) = &preview_eff {
+        let power = power_score(eff);
+        over_budget = selection.fair_mode && power > tuning.power_budget;
         format!(
-            "HP: {:.0}  Radius: {:.2}  Speed: {:.1}\nAccel: {:.1}  Stab: {:.1}  Ctrl: {:.2}",
+            "HP: {:.0}  Radius: {:.2}  Speed: {:.1}\nAccel: {:.1}  Stab: {:.1}  Ctrl: {:.2}\nIdle Drain: {:.2}/s\nPower: {:.0} / {:.0}{}",
             eff.spin_hp_max.0, eff.radius.0, eff.move_speed.0,
-            eff.accel, eff.stability, eff.control_multiplier
+            eff.accel, eff.stability, eff.control_multiplier,
+            eff.spin_drain_idle_per_sec,
+            power, tuning.power_budget,
+            if over_budget { "  (over budget: not allowed in Fair Mode)" } else { "" },
         )
     } else {
         "Invalid build (missing parts)".into()
@@ -2003,20 +3916,30 @@ fn spawn_assemble_build(
     )).with_children(|root| {
         spawn_title(root, "Assemble Build");
 
-        spawn_field_row(root, "Build Name", "Optional note", "build_note", &state.current_build_note);
+        spawn_field_row(root, "Build Name", "Optional note", "build_note", FieldKind::Text, &state.current_build_note);
 
         // Slot cards
-        let top_img: Handle<Image> = asset_server.load(format!("tops/{}.png", state.current_build_wheel_id));
-        let wpn_img: Handle<Image> = asset_server.load(format!("weapons/{}.png", state.current_build_weapon_id));
-        let shaft_img: Handle<Image> = asset_server.load(format!("shafts/{}.png", state.current_build_shaft_id));
-        let chassis_img: Handle<Image> = asset_server.load(format!("chassis/{}.png", state.current_build_chassis_id));
-        let screw_img: Handle<Image> = asset_server.load(format!("screws/{}.png", state.current_build_screw_id));
-
-        spawn_slot_row(root, "Top Body", top_name, AssembleButton::ChangeTop, Some(top_img));
-        spawn_slot_row(root, "Weapon", weapon_name, AssembleButton::ChangeWeapon, Some(wpn_img));
-        spawn_slot_row(root, "Shaft", shaft_name, AssembleButton::ChangeShaft, Some(shaft_img));
-        spawn_slot_row(root, "Chassis", chassis_name, AssembleButton::ChangeChassis, Some(chassis_img));
-        spawn_slot_row(root, "Screw", screw_name, AssembleButton::ChangeScrew, Some(screw_img));
+        let top_img: Handle<Image> = asset_server.load(part_image_path("tops", &state.current_build_wheel_id));
+        let wpn_img: Handle<Image> = asset_server.load(part_image_path("weapons", &state.current_build_weapon_id));
+        let shaft_img: Handle<Image> = asset_server.load(part_image_path("shafts", &state.current_build_shaft_id));
+        let chassis_img: Handle<Image> = asset_server.load(part_image_path("chassis", &state.current_build_chassis_id));
+        let screw_img: Handle<Image> = asset_server.load(part_image_path("screws", &state.current_build_screw_id));
+
+        let lock = |target| slot_lock_state(&state, target);
+        spawn_slot_row(root, "Top Body", top_name, AssembleButton::ChangeTop, PickTarget::TopBody, lock(PickTarget::TopBody), Some(top_img));
+        let target = PickTarget::Part(PartSlot::WeaponWheel);
+        spawn_slot_row(root, "Weapon", weapon_name, AssembleButton::ChangeWeapon, target, lock(target), Some(wpn_img));
+        let target = PickTarget::Part(PartSlot::Shaft);
+        spawn_slot_row(root, "Shaft", shaft_name, AssembleButton::ChangeShaft, target, lock(target), Some(shaft_img));
+        let target = PickTarget::Part(PartSlot::Chassis);
+        spawn_slot_row(root, "Chassis", chassis_name, AssembleButton::ChangeChassis, target, lock(target), Some(chassis_img));
+        let target = PickTarget::Part(PartSlot::TraitScrew);
+        spawn_slot_row(root, "Screw", screw_name, AssembleButton::ChangeScrew, target, lock(target), Some(screw_img));
+
+        // Composite preview (body + weapon, laid out as in battle)
+        if let (Some(build), Some(eff)) = (&preview_build, &preview_eff) {
+            spawn_build_preview(root, build, eff.radius.0, &asset_server);
+        }
 
         // Stats preview
         root.spawn((
@@ -2032,10 +3955,30 @@ fn spawn_assemble_build(
                 StatsPreviewText,
                 Text::new(stats_text),
                 TextFont { font_size: 14.0, ..default() },
-                TextColor(COLOR_TEXT),
+                TextColor(if over_budget { COLOR_WARN } else { COLOR_TEXT }),
             ));
         });
 
+        // Per-part breakdown (expandable)
+        if let (Some(build), Some(eff)) = (&preview_build, &preview_eff) {
+            root.spawn((
+                StatBreakdownPanel,
+                Node {
+                    display: if state.show_stat_breakdown { Display::Flex } else { Display::None },
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border_radius: BorderRadius::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(COLOR_CARD),
+            )).with_children(|panel| {
+                panel.spawn((
+                    Text::new(stat_breakdown_text(build, eff)),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(COLOR_TEXT_DIM),
+                ));
+            });
+        }
+
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
             column_gap: Val::Px(16.0),
@@ -2043,12 +3986,119 @@ fn spawn_assemble_build(
             ..default()
         }).with_children(|row| {
             spawn_button(row, "Save Build", AssembleButton::SaveBuild);
+            spawn_button(row, "Breakdown", AssembleButton::ToggleBreakdown);
             spawn_button(row, "Back", AssembleButton::Back);
         });
     });
 }
 
-fn spawn_slot_row<C: Component>(parent: &mut ChildSpawnerCommands, slot_label: &str, current_name: &str, change_button: C, image: Option<Handle<Image>>) {
+/// One line per stat: base value, each part's add (applied first) and mul,
+/// then the effective value after clamping.
+fn stat_breakdown_text(build: &crate::game::parts::Build, eff: &EffectiveStats) -> String {
+    let parts = build.part_modifiers();
+    let mut lines = vec![
+        stat_breakdown_line("HP", build.wheel.spin_hp_max.0, &parts, |m| &m.spin_hp_max, eff.spin_hp_max.0),
+        stat_breakdown_line("Radius", build.wheel.radius.0, &parts, |m| &m.radius, eff.radius.0),
+        stat_breakdown_line("Speed", build.wheel.move_speed.0, &parts, |m| &m.move_speed, eff.move_speed.0),
+        stat_breakdown_line("Accel", build.wheel.accel, &parts, |m| &m.accel, eff.accel),
+        stat_breakdown_line("Stab", 0.0, &parts, |m| &m.stability, eff.stability),
+    ];
+
+    // Control reduction stacks multiplicatively: m = 1 - (Π(1 + r_i) - 1)
+    let mut ctrl = format!("Ctrl: base {:+.2}", build.wheel.control_reduction);
+    for (part, mods) in &parts {
+        for r in &mods.control_reduction_sources {
+            ctrl.push_str(&format!("  {} {:+.2}", part, r));
+        }
+    }
+    ctrl.push_str(&format!("  = x{:.2} duration", eff.control_multiplier));
+    if let Some(cap) = eff.stun_cap {
+        ctrl.push_str(&format!(", stun cap {:.2}s", cap));
+    }
+    lines.push(ctrl);
+
+    lines.push(multiplier_breakdown_line("Dmg Out", &parts, |m| m.damage_out_mult.0, eff.damage_out_mult.0));
+    lines.push(multiplier_breakdown_line("Dmg In", &parts, |m| m.damage_in_mult.0, eff.damage_in_mult.0));
+    let by_kind = eff.damage_in_by_kind;
+    if by_kind != DamageIntake::default() {
+        lines.push(format!(
+            "Dmg In by kind: Collision x{:.2}  Melee x{:.2}  Projectile x{:.2}  Wall x{:.2}  Obstacle x{:.2}",
+            by_kind.collision.0, by_kind.melee.0, by_kind.projectile.0, by_kind.wall.0, by_kind.obstacle.0,
+        ));
+    }
+    lines.join("\n")
+}
+
+fn stat_breakdown_line(
+    label: &str,
+    base: f32,
+    parts: &[(&'static str, ModifierSet)],
+    pick: fn(&ModifierSet) -> &StatModifier,
+    effective: f32,
+) -> String {
+    let mut line = format!("{}: base {:.2}", label, base);
+    for (part, mods) in parts {
+        let m = pick(mods);
+        if m.add != 0.0 {
+            line.push_str(&format!("  {} {:+.2}", part, m.add));
+        }
+        if m.mul != 1.0 {
+            line.push_str(&format!("  {} x{:.2}", part, m.mul));
+        }
+    }
+    line.push_str(&format!("  = {:.2}", effective));
+    line
+}
+
+fn multiplier_breakdown_line(
+    label: &str,
+    parts: &[(&'static str, ModifierSet)],
+    pick: fn(&ModifierSet) -> f32,
+    effective: f32,
+) -> String {
+    let mut line = format!("{}: base x1.00", label);
+    for (part, mods) in parts {
+        let mul = pick(mods);
+        if mul != 1.0 {
+            line.push_str(&format!("  {} x{:.2}", part, mul));
+        }
+    }
+    line.push_str(&format!("  = x{:.2}", effective));
+    line
+}
+
+/// Lock state of one Assemble Build slot.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SlotLockState {
+    Free,
+    /// Locked since the build was opened; can still be unlocked.
+    Locked,
+    /// Saved locked with the build; can't be unlocked.
+    Fixed,
+}
+
+fn slot_lock_state(state: &DesignState, target: PickTarget) -> SlotLockState {
+    if *slot_lock(&mut state.current_build_saved_locks.clone(), target) {
+        SlotLockState::Fixed
+    } else if *slot_lock(&mut state.current_build_locks.clone(), target) {
+        SlotLockState::Locked
+    } else {
+        SlotLockState::Free
+    }
+}
+
+/// One build slot: image, current part, "Change..." (disabled while the slot
+/// is locked) and a Lock/Unlock toggle (absent once the lock is saved).
+fn spawn_slot_row(
+    parent: &mut ChildSpawnerCommands,
+    slot_label: &str,
+    current_name: &str,
+    change_button: AssembleButton,
+    target: PickTarget,
+    lock: SlotLockState,
+    image: Option<Handle<Image>>,
+) {
+    let locked = lock != SlotLockState::Free;
     parent.spawn(Node {
         flex_direction: FlexDirection::Row,
         align_items: AlignItems::Center,
@@ -2059,15 +4109,118 @@ fn spawn_slot_row<C: Component>(parent: &mut ChildSpawnerCommands, slot_label: &
         row.spawn((
             Text::new(format!("{}: {}", slot_label, current_name)),
             TextFont { font_size: 16.0, ..default() },
-            TextColor(COLOR_TEXT),
+            TextColor(if locked { COLOR_TEXT_DIM } else { COLOR_TEXT }),
             Node { width: Val::Px(280.0), ..default() },
         ));
-        spawn_button(row, "Change...", change_button);
+        if locked {
+            // Same footprint as the button, but not interactive
+            row.spawn((
+                Node {
+                    min_width: Val::Px(160.0),
+                    height: Val::Px(44.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border_radius: BorderRadius::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(COLOR_CARD),
+            )).with_children(|slot| {
+                slot.spawn((
+                    Text::new("Locked"),
+                    TextFont { font_size: 18.0, ..default() },
+                    TextColor(COLOR_TEXT_DIM),
+                ));
+            });
+        } else {
+            spawn_button(row, "Change...", change_button);
+        }
+        match lock {
+            SlotLockState::Free => spawn_button(row, "Lock", AssembleButton::ToggleLock(target)),
+            SlotLockState::Locked => spawn_button(row, "Unlock", AssembleButton::ToggleLock(target)),
+            SlotLockState::Fixed => {}
+        }
+    });
+}
+
+fn slot_lock(locks: &mut SlotLocks, target: PickTarget) -> &mut bool {
+    match target {
+        PickTarget::TopBody => &mut locks.wheel,
+        PickTarget::Part(PartSlot::WeaponWheel) => &mut locks.weapon,
+        PickTarget::Part(PartSlot::Shaft) => &mut locks.shaft,
+        PickTarget::Part(PartSlot::Chassis) => &mut locks.chassis,
+        PickTarget::Part(PartSlot::TraitScrew) => &mut locks.screw,
+    }
+}
+
+/// Draw the top body with its weapon attached at the same offset `spawn_weapon_visual`
+/// uses in battle (`radius + len / 2` from the center, pointing right).
+fn spawn_build_preview(
+    parent: &mut ChildSpawnerCommands,
+    build: &crate::game::parts::Build,
+    radius: f32,
+    asset_server: &AssetServer,
+) {
+    const SIZE: f32 = 160.0;
+    let center = SIZE * 0.5;
+    let (len, thick) = build.weapon.visual_dims().unwrap_or((0.0, 0.0));
+    // World units → preview pixels, so body + weapon fit inside the frame.
+    let scale = (center - 8.0) / (radius + len).max(0.01);
+    let body_px = radius * 2.0 * scale;
+
+    let top_img: Handle<Image> = asset_server.load(
+        build.wheel.sprite_path.clone().unwrap_or_else(|| part_image_path("tops", &build.wheel.id)),
+    );
+    let wpn_img: Handle<Image> = asset_server.load(
+        build.weapon.sprite_path.clone().unwrap_or_else(|| part_image_path("weapons", &build.weapon.id)),
+    );
+
+    parent.spawn((
+        Node {
+            width: Val::Px(SIZE),
+            height: Val::Px(SIZE),
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_CARD),
+    )).with_children(|frame| {
+        frame.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(center - body_px * 0.5),
+                top: Val::Px(center - body_px * 0.5),
+                width: Val::Px(body_px),
+                height: Val::Px(body_px),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.2, 0.6, 1.0, 0.35)),
+        )).with_children(|body| {
+            body.spawn((
+                ImageNode { image: top_img, ..default() },
+                Node { width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() },
+            ));
+        });
+        if len <= 0.0 {
+            return;
+        }
+        frame.spawn((
+            ImageNode { image: wpn_img, ..default() },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(center + radius * scale),
+                top: Val::Px(center - thick * scale * 0.5),
+                width: Val::Px(len * scale),
+                height: Val::Px(thick * scale),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.9, 0.9, 1.0, 0.25)),
+        ));
     });
 }
 
 fn assemble_build_system(
     mut q: Query<(&Interaction, &AssembleButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut breakdown_panels: Query<&mut Node, With<StatBreakdownPanel>>,
     inputs: Query<&TextInput>,
     mut next_state: ResMut<NextState<GamePhase>>,
     mut state: ResMut<DesignState>,
@@ -2077,33 +4230,65 @@ fn assemble_build_system(
 ) {
     for (interaction, button, mut bg) in &mut q {
         if *interaction == Interaction::Pressed {
+            // Locked slots have no "Change..." button; ignore a stale press anyway
+            let change_target = match button {
+                AssembleButton::ChangeTop => Some(PickTarget::TopBody),
+                AssembleButton::ChangeWeapon => Some(PickTarget::Part(PartSlot::WeaponWheel)),
+                AssembleButton::ChangeShaft => Some(PickTarget::Part(PartSlot::Shaft)),
+                AssembleButton::ChangeChassis => Some(PickTarget::Part(PartSlot::Chassis)),
+                AssembleButton::ChangeScrew => Some(PickTarget::Part(PartSlot::TraitScrew)),
+                _ => None,
+            };
+            if change_target.is_some_and(|target| slot_lock_state(&state, target) != SlotLockState::Free) {
+                continue;
+            }
             match button {
                 AssembleButton::ChangeTop => {
-                    state.picking_slot = Some(PartSlot::Shaft); // reuse for top body selection
-                    // Actually use a special marker — we'll handle "top" in picker
-                    state.picking_slot = None; // Special: None = top body
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    state.picking = PickTarget::TopBody;
                     next_state.set(GamePhase::PickDesignPart);
                 }
                 AssembleButton::ChangeWeapon => {
-                    state.picking_slot = Some(PartSlot::WeaponWheel);
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    state.picking = PickTarget::Part(PartSlot::WeaponWheel);
                     next_state.set(GamePhase::PickDesignPart);
                 }
                 AssembleButton::ChangeShaft => {
-                    state.picking_slot = Some(PartSlot::Shaft);
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    state.picking = PickTarget::Part(PartSlot::Shaft);
                     next_state.set(GamePhase::PickDesignPart);
                 }
                 AssembleButton::ChangeChassis => {
-                    state.picking_slot = Some(PartSlot::Chassis);
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    state.picking = PickTarget::Part(PartSlot::Chassis);
                     next_state.set(GamePhase::PickDesignPart);
                 }
                 AssembleButton::ChangeScrew => {
-                    state.picking_slot = Some(PartSlot::TraitScrew);
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    state.picking = PickTarget::Part(PartSlot::TraitScrew);
                     next_state.set(GamePhase::PickDesignPart);
                 }
+                AssembleButton::ToggleLock(target) => {
+                    // Saved locks have no toggle; ignore a stale press anyway
+                    if slot_lock_state(&state, *target) == SlotLockState::Fixed {
+                        continue;
+                    }
+                    state.current_build_note = read_field(&inputs, "build_note");
+                    let locked = slot_lock(&mut state.current_build_locks, *target);
+                    *locked = !*locked;
+                    // Re-enter to rebuild the slot rows
+                    next_state.set(GamePhase::AssembleBuild);
+                }
+                AssembleButton::ToggleBreakdown => {
+                    state.show_stat_breakdown = !state.show_stat_breakdown;
+                    for mut node in &mut breakdown_panels {
+                        node.display = if state.show_stat_breakdown { Display::Flex } else { Display::None };
+                    }
+                }
                 AssembleButton::SaveBuild => {
                     let note = read_field(&inputs, "build_note");
                     state.current_build_note = note.clone();
-                    let build_id = state.current_build_id.clone().unwrap_or_else(gen_custom_id);
+                    let build_id = state.current_build_id.clone().unwrap_or_else(|| gen_custom_id(&registry));
                     let display_name = if note.is_empty() { build_id.clone() } else { note.clone() };
 
                     if let Some(build) = registry.resolve_build(
@@ -2118,7 +4303,7 @@ fn assemble_build_system(
                         let mut build = build;
                         build.note = if note.is_empty() { None } else { Some(note.clone()) };
                         if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
-                            let _ = repo.save_build_sync(&rt.0, &build);
+                            let _ = repo.save_build_sync(&rt.0, &build, &state.current_build_locks);
                         }
                         // Register build in memory so it's available in the game picker
                         registry.builds.insert(build_id.clone(), crate::game::parts::registry::BuildRef {
@@ -2129,6 +4314,7 @@ fn assemble_build_system(
                             shaft_id: state.current_build_shaft_id.clone(),
                             chassis_id: state.current_build_chassis_id.clone(),
                             screw_id: state.current_build_screw_id.clone(),
+                            locks: state.current_build_locks,
                         });
                     }
                     next_state.set(GamePhase::ManageParts);
@@ -2158,7 +4344,7 @@ fn spawn_pick_design_part(
     registry: Res<PartRegistry>,
     asset_server: Res<AssetServer>,
 ) {
-    let slot = &state.picking_slot;
+    let target = state.picking;
 
     commands.spawn((
         ScreenRoot,
@@ -2175,14 +4361,15 @@ fn spawn_pick_design_part(
         ScrollPosition::default(),
         BackgroundColor(COLOR_BG),
     )).with_children(|root| {
-        let title = match slot {
-            None => "Select Top Body",
-            Some(PartSlot::WeaponWheel) => "Select Weapon",
-            Some(PartSlot::Shaft) => "Select Shaft",
-            Some(PartSlot::Chassis) => "Select Chassis",
-            Some(PartSlot::TraitScrew) => "Select Screw",
+        let title = match target {
+            PickTarget::TopBody => "Select Top Body",
+            PickTarget::Part(PartSlot::WeaponWheel) => "Select Weapon",
+            PickTarget::Part(PartSlot::Shaft) => "Select Shaft",
+            PickTarget::Part(PartSlot::Chassis) => "Select Chassis",
+            PickTarget::Part(PartSlot::TraitScrew) => "Select Screw",
         };
         spawn_title(root, title);
+        spawn_filter_row(root, "");
 
         root.spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -2192,50 +4379,50 @@ fn spawn_pick_design_part(
             justify_content: JustifyContent::Center,
             ..default()
         }).with_children(|grid| {
-            match slot {
-                None => {
+            match target {
+                PickTarget::TopBody => {
                     let mut ids: Vec<_> = registry.wheels.keys().collect();
                     ids.sort();
                     for id in ids {
                         let t = &registry.wheels[id];
-                        let img: Handle<Image> = asset_server.load(format!("tops/{}.png", id));
-                        spawn_pick_card(grid, id, &t.name, &format!("HP:{:.0} R:{:.2}", t.spin_hp_max.0, t.radius.0), Some(img));
+                        let img: Handle<Image> = asset_server.load(part_image_path("tops", id));
+                        spawn_pick_card(grid, id, &t.name, &format!("HP:{:.0} R:{:.2}", t.spin_hp_max.0, t.radius.0), &t.description, Some(img));
                     }
                 }
-                Some(PartSlot::WeaponWheel) => {
+                PickTarget::Part(PartSlot::WeaponWheel) => {
                     let mut ids: Vec<_> = registry.weapons.keys().collect();
                     ids.sort();
                     for id in ids {
                         let w = &registry.weapons[id];
-                        let img: Handle<Image> = asset_server.load(format!("weapons/{}.png", id));
-                        spawn_pick_card(grid, id, &w.name, &format!("{:?}", w.kind), Some(img));
+                        let img: Handle<Image> = asset_server.load(part_image_path("weapons", id));
+                        spawn_pick_card(grid, id, &w.name, &w.stats_line(), &w.description, Some(img));
                     }
                 }
-                Some(PartSlot::Shaft) => {
+                PickTarget::Part(PartSlot::Shaft) => {
                     let mut ids: Vec<_> = registry.shafts.keys().collect();
                     ids.sort();
                     for id in ids {
                         let s = &registry.shafts[id];
-                        let img: Handle<Image> = asset_server.load(format!("shafts/{}.png", id));
-                        spawn_pick_card(grid, id, &s.name, &format!("Stab:{:.1}", s.stability), Some(img));
+                        let img: Handle<Image> = asset_server.load(part_image_path("shafts", id));
+                        spawn_pick_card(grid, id, &s.name, &format!("Stab:{:.1}", s.stability), &s.description, Some(img));
                     }
                 }
-                Some(PartSlot::Chassis) => {
+                PickTarget::Part(PartSlot::Chassis) => {
                     let mut ids: Vec<_> = registry.chassis.keys().collect();
                     ids.sort();
                     for id in ids {
                         let c = &registry.chassis[id];
-                        let img: Handle<Image> = asset_server.load(format!("chassis/{}.png", id));
-                        spawn_pick_card(grid, id, &c.name, &format!("Spd+{:.0}", c.move_speed_add), Some(img));
+                        let img: Handle<Image> = asset_server.load(part_image_path("chassis", id));
+                        spawn_pick_card(grid, id, &c.name, &format!("Spd+{:.0}", c.move_speed_add), &c.description, Some(img));
                     }
                 }
-                Some(PartSlot::TraitScrew) => {
+                PickTarget::Part(PartSlot::TraitScrew) => {
                     let mut ids: Vec<_> = registry.screws.keys().collect();
                     ids.sort();
                     for id in ids {
                         let s = &registry.screws[id];
-                        let img: Handle<Image> = asset_server.load(format!("screws/{}.png", id));
-                        spawn_pick_card(grid, id, &s.name, &format!("HP+{:.0}", s.passive.spin_hp_max_add), Some(img));
+                        let img: Handle<Image> = asset_server.load(part_image_path("screws", id));
+                        spawn_pick_card(grid, id, &s.name, &format!("HP+{:.0}", s.passive.spin_hp_max_add), &s.description, Some(img));
                     }
                 }
             }
@@ -2247,9 +4434,10 @@ fn spawn_pick_design_part(
     });
 }
 
-fn spawn_pick_card(parent: &mut ChildSpawnerCommands, id: &str, name: &str, stats: &str, image: Option<Handle<Image>>) {
+fn spawn_pick_card(parent: &mut ChildSpawnerCommands, id: &str, name: &str, stats: &str, description: &str, image: Option<Handle<Image>>) {
     parent.spawn((
         PickPartButton::Select(id.into()),
+        FilterCard::new(id, name),
         Button,
         Node {
             width: Val::Px(200.0),
@@ -2273,6 +4461,7 @@ fn spawn_pick_card(parent: &mut ChildSpawnerCommands, id: &str, name: &str, stat
             TextFont { font_size: 12.0, ..default() },
             TextColor(COLOR_TEXT_DIM),
         ));
+        spawn_card_description(card, description);
     });
 }
 
@@ -2285,16 +4474,17 @@ fn pick_design_part_system(
         if *interaction == Interaction::Pressed {
             match button {
                 PickPartButton::Select(id) => {
-                    match &state.picking_slot {
-                        None => state.current_build_wheel_id = id.clone(),
-                        Some(PartSlot::WeaponWheel) => state.current_build_weapon_id = id.clone(),
-                        Some(PartSlot::Shaft) => state.current_build_shaft_id = id.clone(),
-                        Some(PartSlot::Chassis) => state.current_build_chassis_id = id.clone(),
-                        Some(PartSlot::TraitScrew) => state.current_build_screw_id = id.clone(),
+                    match state.picking {
+                        PickTarget::TopBody => state.current_build_wheel_id = id.clone(),
+                        PickTarget::Part(PartSlot::WeaponWheel) => state.current_build_weapon_id = id.clone(),
+                        PickTarget::Part(PartSlot::Shaft) => state.current_build_shaft_id = id.clone(),
+                        PickTarget::Part(PartSlot::Chassis) => state.current_build_chassis_id = id.clone(),
+                        PickTarget::Part(PartSlot::TraitScrew) => state.current_build_screw_id = id.clone(),
                     }
                     next_state.set(GamePhase::AssembleBuild);
                 }
                 PickPartButton::Back => {
+                    // Leave every slot untouched; AssembleBuild respawns from DesignState.
                     next_state.set(GamePhase::AssembleBuild);
                 }
             }
@@ -2311,25 +4501,74 @@ fn pick_design_part_system(
 // IMAGE HANDLING (rfd file dialog)
 // ═══════════════════════════════════════════════════════════════════════
 
+/// Editor preview of the part being edited, swapped for the placeholder by "Clear Image".
+#[derive(Component)]
+struct EditorImagePreview;
+
+/// "Clear Image" in a part editor; holds the part's asset folder.
+#[derive(Component)]
+struct ClearImageButton(&'static str);
+
+/// Only a file that actually exists counts as a custom image, so a part
+/// without one shows the placeholder instead of a blank node.
+fn spawn_editor_image_preview(parent: &mut ChildSpawnerCommands, asset_server: &AssetServer, dir: &str, id: Option<&String>) {
+    let image = id
+        .map(|id| part_image_path(dir, id))
+        .filter(|path| asset_file(path).exists())
+        .map(|path| asset_server.load(path));
+    spawn_image_preview_with(parent, image, 96.0, EditorImagePreview);
+}
+
+/// Delete the editing part's image file and show the placeholder. The cached
+/// image is dropped too (from `GameAssets` and `Assets<Image>`), so battles and
+/// previews fall back instead of showing the deleted file. Unsaved parts,
+/// built-ins and parts without a custom image are left alone.
+fn clear_image_system(
+    mut commands: Commands,
+    mut q: Query<(&Interaction, &ClearImageButton, &mut BackgroundColor), Changed<Interaction>>,
+    previews: Query<Entity, With<EditorImagePreview>>,
+    state: Res<DesignState>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut game_assets: ResMut<GameAssets>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        if *interaction == Interaction::Pressed {
+            let Some(id) = state.editing_part_id.as_ref().filter(|id| !is_builtin(id)) else { continue };
+            let path = part_image_path(button.0, id);
+            remove_asset_file(&path);
+            if let Some(handle) = asset_server.get_handle::<Image>(&path) {
+                images.remove(&handle);
+                game_assets.wheel_sprites.retain(|_, sprite| *sprite != handle);
+                game_assets.weapon_sprites.retain(|_, sprite| *sprite != handle);
+            }
+            for entity in &previews {
+                commands.entity(entity)
+                    .remove::<ImageNode>()
+                    .insert(BackgroundColor(COLOR_IMAGE_PLACEHOLDER));
+            }
+        }
+        hover_system(interaction, &mut bg);
+    }
+}
+
 fn pick_and_copy_image(slot_dir: &str, part_id: &str) {
-    let dest = format!("assets/{}/{}.png", slot_dir, part_id);
     if let Some(path) = rfd::FileDialog::new()
-        .add_filter("PNG Image", &["png"])
+        .add_filter("Image", IMAGE_EXTENSIONS)
         .pick_file()
     {
-        let _ = std::fs::create_dir_all(format!("assets/{}", slot_dir));
-        let _ = std::fs::copy(&path, &dest);
+        if let Err(e) = import_image(&path, &part_image_path(slot_dir, part_id)) {
+            warn!("Failed to import image for '{part_id}': {e}");
+        }
     }
 }
 
 /// Open a file picker for an OGG audio file and copy it to `assets/audio/sfx/{prefix}_{weapon_id}.ogg`.
 fn pick_and_copy_audio(prefix: &str, weapon_id: &str) {
-    let dest = format!("assets/audio/sfx/{}_{}.ogg", prefix, weapon_id);
     if let Some(path) = rfd::FileDialog::new()
         .add_filter("OGG Audio", &["ogg"])
         .pick_file()
     {
-        let _ = std::fs::create_dir_all("assets/audio/sfx");
-        let _ = std::fs::copy(&path, &dest);
+        let _ = copy_into_assets(&path, &weapon_sfx_path(prefix, weapon_id));
     }
 }
@@ -1,22 +1,31 @@
 use bevy::prelude::*;
 use bevy::camera::ScalingMode;
+use bevy::window::PrimaryWindow;
 use std::collections::HashMap;
-use std::f32::consts::PI;
+use std::f32::consts::{PI, TAU};
 
 use crate::assets_map::GameAssets;
 use crate::assets_map::SfxHandles;
+use crate::assets_map::{part_image_path, projectile_image_path, weapon_sfx_path};
+use crate::config::logging::DebugLogging;
 use crate::config::tuning::Tuning;
 use crate::game::{
     arena::{circle, obstacle},
     collision, combat,
     components::*,
-    events::{CollisionMessage, GameEvent},
+    daily::DailyChallenge,
+    events::{CollisionMessage, DamageTakenMessage, GameEvent},
+    ghost::{self, BattleRecorder, GhostRecording},
     hooks,
-    parts::registry::PartRegistry,
+    parts::{registry::PartRegistry, Build},
     physics,
-    stats::types::*,
+    rng::GameRng,
+    spatial::{self, ObstacleGrid},
+    stats::{effective::EffectiveStats, types::*},
 };
 use crate::plugins::menu_plugin::{GameMode, GameSelection};
+use crate::plugins::settings_plugin::{KeyBindings, UserSettings};
+use crate::plugins::tutorial_plugin::tutorial_closed;
 
 // ── SystemSets (strict FixedUpdate ordering, battle-phase only) ─────
 
@@ -30,30 +39,35 @@ pub enum FixedGameSet {
     CleanupSet,
 }
 
-pub struct GamePlugin;
+/// Battle simulation only: state, messages, FixedUpdate sets and the systems in
+/// them. Needs no window, renderer or loaded assets, so it also runs headless
+/// (see `crate::headless`).
+pub struct SimulationPlugin;
 
-impl Plugin for GamePlugin {
+impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<GameEvent>();
         app.add_message::<CollisionMessage>();
+        app.add_message::<DamageTakenMessage>();
         app.init_state::<GamePhase>();
-
-        // Configure FixedUpdate set ordering (each set gated to Battle phase)
+        app.init_resource::<GameRng>();
+        app.init_resource::<BattleRecorder>();
+        app.init_resource::<ObstacleGrid>();
+        app.init_resource::<ProjectilePool>();
+        app.init_resource::<MatchTimer>();
+        app.init_resource::<MatchResult>();
+
+        // Configure FixedUpdate set ordering (each set gated to Battle phase;
+        // a paused battle pauses `Time<Virtual>`, so no fixed ticks run at all)
         app.configure_sets(
             FixedUpdate,
             (
-                FixedGameSet::PhysicsSet
-                    .run_if(in_state(GamePhase::Battle)),
-                FixedGameSet::CollisionDetectSet
-                    .run_if(in_state(GamePhase::Battle)),
-                FixedGameSet::EventGenerateSet
-                    .run_if(in_state(GamePhase::Battle)),
-                FixedGameSet::HookProcessSet
-                    .run_if(in_state(GamePhase::Battle)),
-                FixedGameSet::EventApplySet
-                    .run_if(in_state(GamePhase::Battle)),
-                FixedGameSet::CleanupSet
-                    .run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::PhysicsSet.run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::CollisionDetectSet.run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::EventGenerateSet.run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::HookProcessSet.run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::EventApplySet.run_if(in_state(GamePhase::Battle)),
+                FixedGameSet::CleanupSet.run_if(in_state(GamePhase::Battle)),
             )
                 .chain(),
         );
@@ -67,15 +81,30 @@ impl Plugin for GamePlugin {
                 speed_boost_system,
                 speed_boost_tick,
                 damage_boost_system,
+                weapon_pickup_system,
+                weapon_surge_tick,
+                repair_pack_system,
+                last_stand_system.run_if(last_stand_enabled),
                 gravity_device_system,
-                physics::integrate_physics,
+                moving_obstacle_system,
+                // AI steering feeds this tick's integration
+                (physics::ai_battle_steer, physics::integrate_physics).chain(),
+                ghost::drive_ghost_tops,
                 physics::update_seek_weapon_visual,
                 physics::integrate_projectiles,
+                physics::record_projectile_trails,
                 physics::spin_drain,
                 physics::tick_control_state,
                 physics::tick_melee_trackers,
                 circle::wall_reflection,
-                obstacle::static_obstacle_bounce,
+                circle::wall_hug_nudge,
+                // Broad phase: bucket obstacles once they've moved this tick
+                (
+                    spatial::rebuild_obstacle_grid,
+                    obstacle::static_obstacle_bounce,
+                    obstacle::projectile_obstacle_contact,
+                )
+                    .chain(),
             )
                 .chain()
                 .in_set(FixedGameSet::PhysicsSet),
@@ -94,6 +123,7 @@ impl Plugin for GamePlugin {
                 combat::generate_collision_damage,
                 combat::detect_melee_hits,
                 combat::fire_ranged_weapons,
+                ghost::fire_ghost_shots,
             )
                 .chain()
                 .in_set(FixedGameSet::EventGenerateSet),
@@ -110,8 +140,10 @@ impl Plugin for GamePlugin {
             FixedUpdate,
             (
                 combat::apply_damage_events,
+                combat::apply_heal_events,
                 combat::apply_control_events,
                 combat::resolve_top_collisions,
+                obstacle::spawn_obstacles,
                 obstacle::spawn_projectiles,
             )
                 .chain()
@@ -121,42 +153,98 @@ impl Plugin for GamePlugin {
         // CleanupSet
         app.add_systems(
             FixedUpdate,
-            (circle::despawn_projectiles_outside_arena, obstacle::cleanup_ttl, obstacle::handle_despawn_events, play_sound_effects)
+            (
+                circle::despawn_projectiles_outside_arena,
+                obstacle::cleanup_ttl,
+                obstacle::handle_despawn_events,
+                physics::tick_match_timer,
+                ghost::record_ghost_frames,
+                physics::record_top_trails,
+                obstacle::recycle_projectiles,
+            )
                 .chain()
                 .in_set(FixedGameSet::CleanupSet),
         );
 
+        // ── OnEnter(Battle): launch tops + fresh ghost tracks ─────────
+        app.add_systems(OnEnter(GamePhase::Battle), (launch_tops, ghost::reset_recorder, physics::reset_match_timer));
+    }
+}
+
+/// Full game: the simulation plus camera, assets, arena visuals, aiming and audio.
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SimulationPlugin);
+        app.init_resource::<ArenaAudio>();
+        app.init_resource::<RoundScore>();
+
+        // Battle SFX read the same tick's messages as the CleanupSet systems
+        app.add_systems(FixedUpdate, play_sound_effects.in_set(FixedGameSet::CleanupSet));
+
         // ── Startup: camera + registry + assets ──────────────────────
-        app.add_systems(Startup, (setup_camera, load_game_assets).chain());
+        app.add_systems(Startup, (setup_camera, load_game_assets, spawn_sfx_voices).chain());
 
         // ── OnEnter(Aiming): spawn arena + tops from selection ───────
-        app.add_systems(OnEnter(GamePhase::Aiming), setup_arena);
+        app.add_systems(OnEnter(GamePhase::Aiming), (setup_arena, frame_arena_view).chain());
 
         // ── Aiming phase (Update) ───────────────────────────────────────
         app.add_systems(
             Update,
-            (read_aim_input, read_aim_input_p2, ai_auto_aim, check_all_confirmed, update_aim_arrow)
+            (read_aim_input, read_aim_input_p2, ai_auto_aim, check_all_confirmed, update_aim_arrow, update_aim_angle_labels, pvp_camera_flip)
                 .chain()
-                .run_if(in_state(GamePhase::Aiming)),
+                .run_if(in_state(GamePhase::Aiming))
+                .run_if(tutorial_closed),
         );
 
-        // ── OnEnter(Battle): launch tops + despawn aim arrows ───────────
+        // ── OnEnter(Battle): launch sound + despawn aim arrows ──────────
         app.add_systems(
             OnEnter(GamePhase::Battle),
-            (launch_tops, despawn_aim_arrows),
+            (play_launch_sound, despawn_aim_arrows, reset_camera_rotation),
+        );
+
+        // ── Battle visuals (Update) ─────────────────────────────────────
+        app.add_systems(
+            Update,
+            (draw_projectile_trails, draw_top_trails.run_if(top_trails_enabled), update_hp_bars)
+                .run_if(in_state(GamePhase::Battle)),
+        );
+        app.add_systems(
+            Update,
+            (animate_burst_particles, spawn_damage_numbers, animate_damage_numbers)
+                .run_if(in_state(GamePhase::Battle).or(in_state(GamePhase::GameOver))),
         );
 
+        // ── Battle pause (P) / single step (N) ──────────────────────────
+        app.add_systems(Update, battle_pause_input.run_if(in_state(GamePhase::Battle)));
+        app.add_systems(OnExit(GamePhase::Battle), unpause_battle);
+
         // ── Battle → GameOver check ─────────────────────────────────────
         app.add_systems(
             Update,
             check_game_over.run_if(in_state(GamePhase::Battle)),
         );
+        app.add_systems(OnEnter(GamePhase::GameOver), keep_winning_run);
 
-        // ── Cleanup on return to MainMenu ────────────────────────────
-        app.add_systems(OnEnter(GamePhase::MainMenu), cleanup_game);
+        // ── Next round of a series: clear the arena, then Aiming rebuilds it
+        app.add_systems(
+            OnTransition { exited: GamePhase::Battle, entered: GamePhase::Aiming },
+            cleanup_game,
+        );
+
+        // ── Cleanup on return to MainMenu / leaving GameOver ─────────
+        app.add_systems(OnEnter(GamePhase::MainMenu), (cleanup_game, reset_round_score));
+        app.add_systems(OnExit(GamePhase::GameOver), (cleanup_game, reset_round_score));
 
         // ── Always-on ───────────────────────────────────────────────────
         app.add_systems(Update, tuning_reload_input);
+        app.add_systems(
+            Update,
+            frame_arena_view
+                .run_if(reset_camera_pressed)
+                .run_if(in_state(GamePhase::Aiming).or(in_state(GamePhase::Battle))),
+        );
     }
 }
 
@@ -181,6 +269,7 @@ fn setup_camera(
 
     // Part registry: hardcoded defaults + custom parts/builds from DB
     let mut registry = PartRegistry::with_defaults();
+    registry.merge_ai_profiles_file();
     if let (Some(repo), Some(rt)) = (repo, tokio_rt) {
         registry.merge_custom_parts(&repo, &rt.0);
         registry.merge_custom_builds(&repo, &rt.0);
@@ -204,20 +293,20 @@ fn load_game_assets(
     // Load wheel sprites
     for (id, stats) in &registry.wheels {
         let path = stats.sprite_path.clone()
-            .unwrap_or_else(|| format!("tops/{}.png", id));
+            .unwrap_or_else(|| part_image_path("tops", id));
         wheel_sprites.insert(id.clone(), asset_server.load(&path));
     }
 
     // Load weapon sprites
     for (id, weapon) in &registry.weapons {
         let path = weapon.sprite_path.clone()
-            .unwrap_or_else(|| format!("weapons/{}.png", id));
+            .unwrap_or_else(|| part_image_path("weapons", id));
         weapon_sprites.insert(id.clone(), asset_server.load(&path));
 
         // Load projectile sprite for ranged weapons
         if weapon.ranged.is_some() {
             let proj_path = weapon.projectile_sprite_path.clone()
-                .unwrap_or_else(|| format!("projectiles/{}_projectile.png", id));
+                .unwrap_or_else(|| projectile_image_path(id));
             projectile_sprites.insert(id.clone(), asset_server.load(&proj_path));
         }
     }
@@ -232,8 +321,8 @@ fn load_game_assets(
     let mut weapon_fire_sfx = HashMap::new();
     let mut weapon_hit_sfx = HashMap::new();
     for id in registry.weapons.keys() {
-        weapon_fire_sfx.insert(id.clone(), asset_server.load(format!("audio/sfx/fire_{}.ogg", id)));
-        weapon_hit_sfx.insert(id.clone(), asset_server.load(format!("audio/sfx/hit_{}.ogg", id)));
+        weapon_fire_sfx.insert(id.clone(), asset_server.load(weapon_sfx_path("fire", id)));
+        weapon_hit_sfx.insert(id.clone(), asset_server.load(weapon_sfx_path("hit", id)));
     }
 
     // Load SFX
@@ -244,6 +333,7 @@ fn load_game_assets(
         melee_hit: asset_server.load("audio/sfx/melee_hit.ogg"),
         ranged_fire: asset_server.load("audio/sfx/ranged_fire.ogg"),
         projectile_hit: asset_server.load("audio/sfx/projectile_hit.ogg"),
+        top_defeated: asset_server.load("audio/sfx/top_defeated.ogg"),
         weapon_fire_sfx,
         weapon_hit_sfx,
     };
@@ -297,13 +387,8 @@ fn spawn_weapon_visual(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
 ) {
-    let (len, thick) = if weapon.kind.is_ranged() {
-        let r = weapon.ranged.as_ref().expect("Ranged weapon missing RangedSpec");
-        (r.barrel_len, r.barrel_thick)
-    } else {
-        let m = weapon.melee.as_ref().expect("Melee weapon missing MeleeSpec");
-        (m.blade_len, m.blade_thick)
-    };
+    // Unarmed builds have nothing to draw
+    let Some((len, thick)) = weapon.visual_dims() else { return };
     let tf = Transform::from_translation(Vec3::new(top_radius + len * 0.5, 0.0, 0.5));
 
     if let Some(sprite_handle) = game_assets.weapon_sprite(&weapon.id) {
@@ -339,9 +424,14 @@ fn setup_arena(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    ghost: Option<Res<GhostRecording>>,
+    daily: Option<Res<DailyChallenge>>,
+    audio_settings: Res<AudioSettings>,
 ) {
     let ppu = tuning.pixels_per_unit.max(1.0);
 
+    let ghost = ghost.filter(|_| selection.mode == GameMode::Ghost);
+
     // Look up map from registry
     let map_spec = registry.maps.get(&selection.map_id);
     let arena_radius = map_spec.map(|m| m.arena_radius).unwrap_or(tuning.arena_radius);
@@ -358,24 +448,80 @@ fn setup_arena(
     // Store the actual arena radius for use by physics systems
     commands.insert_resource(ArenaRadius(arena_radius));
 
+    // Fresh RNG per match; a fixed `rng_seed` makes the whole battle reproducible,
+    // and the daily challenge uses the day's seed
+    let rng = GameRng::for_battle(daily.map(|d| d.seed).or(tuning.rng_seed));
+    info!("GameRng seeded with {}", rng.seed());
+    commands.insert_resource(rng);
+
+    let options = map_spec.map(|m| m.options.clone()).unwrap_or_default();
+
+    // Spiked wall: red ring on the boundary, speed-scaled wall damage
+    let tilt = options.tilt.map_or(Vec2::ZERO, |t| t.accel());
+    commands.insert_resource(ArenaHazards { wall_spikes: options.wall_spikes, tilt });
+    if options.wall_spikes {
+        commands.spawn((
+            InGame,
+            Mesh2d(meshes.add(Annulus::new(arena_radius - 0.15, arena_radius))),
+            MeshMaterial2d(materials.add(Color::srgba(0.9, 0.15, 0.15, 0.9))),
+            Transform::from_translation(Vec3::new(0.0, 0.0, -0.9)),
+        ));
+    }
+
+    // Tilted arena: faint arrow on the floor pointing downhill
+    if tilt != Vec2::ZERO {
+        let arrow_len = arena_radius * 0.6;
+        commands.spawn((
+            InGame,
+            Sprite {
+                image: game_assets.aim_arrow.clone(),
+                custom_size: Some(Vec2::new(arrow_len, arrow_len * 0.5)),
+                color: Color::srgba(1.0, 1.0, 1.0, 0.08),
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(0.0, 0.0, -0.8))
+                .with_rotation(Quat::from_rotation_z(tilt.to_angle())),
+        ));
+    }
+
+    // Map audio: SFX profile + optional looping ambient track
+    commands.insert_resource(ArenaAudio(options.audio_profile));
+    if let Some(path) = options.ambient_path.filter(|_| !audio_settings.muted) {
+        commands.spawn((
+            InGame,
+            AmbientLoop,
+            AudioPlayer::<AudioSource>(asset_server.load(path)),
+            PlaybackSettings::LOOP.with_volume(bevy::audio::Volume::Linear(0.5 * audio_settings.gain())),
+        ));
+    }
+
     // Spawn map placements
     if let Some(map) = map_spec {
         let mut obs_count = 0u32;
         let mut gravity_count = 0u32;
         let mut speed_count = 0u32;
         let mut damage_count = 0u32;
+        let mut pickup_count = 0u32;
+        let mut repair_count = 0u32;
+        let mut moving_count = 0u32;
+        let mut bumper_count = 0u32;
 
         for placement in &map.placements {
             let wx = placement.grid_x as f32 * crate::game::map::GRID_CELL_SIZE;
             let wy = placement.grid_y as f32 * crate::game::map::GRID_CELL_SIZE;
             let pos = Vec3::new(wx, wy, 0.0);
             let cell_radius = crate::game::map::GRID_CELL_SIZE * 0.5;
+            // Area zones span width × height cells
+            let (ax, ay) = placement.world_center();
+            let area_pos = Vec3::new(ax, ay, 0.0);
+            let area_size = Vec2::from(placement.world_size());
 
             match placement.item {
                 crate::game::map::MapItem::Obstacle => {
                     obs_count += 1;
                     commands.spawn((
                         InGame,
+                        MapItemTag(placement.item),
                         StaticObstacle,
                         ObstacleMarker,
                         CollisionRadius(cell_radius),
@@ -391,10 +537,11 @@ fn setup_arena(
                 }
                 crate::game::map::MapItem::GravityDevice => {
                     gravity_count += 1;
-                    // Effect radius 3.0; visual circle sized to match
-                    let effect_radius = 3.0_f32;
+                    // Visual circle sized to the effect radius
+                    let effect_radius = placement.effect_radius().unwrap_or(cell_radius);
                     commands.spawn((
                         InGame,
+                        MapItemTag(placement.item),
                         GravityDevice {
                             radius: effect_radius,
                         },
@@ -409,44 +556,133 @@ fn setup_arena(
                 }
                 crate::game::map::MapItem::SpeedBoost => {
                     speed_count += 1;
-                    // Detection radius = half a grid cell; place 2×2 in editor for area coverage
+                    // One entity covers the whole editor-drawn rectangle
                     commands.spawn((
                         InGame,
+                        MapItemTag(placement.item),
                         SpeedBoostZone {
-                            multiplier: 1.5,
-                            duration: 3.0,
+                            multiplier: placement.boost_multiplier(),
+                            duration: tuning.speed_boost_duration,
+                        },
+                        ZoneArea { half_extents: area_size * 0.5 },
+                        CollisionRadius(area_size.max_element() * 0.5),
+                        zone_sprite(asset_server.load("obstacles/speed_boost.png"), area_size),
+                        Transform::from_translation(area_pos.with_z(-0.5)),
+                    ));
+                }
+                crate::game::map::MapItem::DamageBoost => {
+                    damage_count += 1;
+                    commands.spawn((
+                        InGame,
+                        MapItemTag(placement.item),
+                        DamageBoostZone { multiplier: placement.boost_multiplier() },
+                        ZoneArea { half_extents: area_size * 0.5 },
+                        CollisionRadius(area_size.max_element() * 0.5),
+                        zone_sprite(asset_server.load("obstacles/damage_boost.png"), area_size),
+                        Transform::from_translation(area_pos.with_z(-0.5)),
+                    ));
+                }
+                crate::game::map::MapItem::WeaponPickup => {
+                    pickup_count += 1;
+                    commands.spawn((
+                        InGame,
+                        MapItemTag(placement.item),
+                        WeaponPickup {
+                            multiplier: tuning.weapon_pickup_multiplier,
+                            duration: tuning.weapon_pickup_duration,
+                            respawn: tuning.weapon_pickup_respawn,
+                            ready_at: 0.0,
+                        },
+                        CollisionRadius(cell_radius),
+                        Sprite::from_color(
+                            placement.item.color(),
+                            Vec2::splat(crate::game::map::GRID_CELL_SIZE * 0.8),
+                        ),
+                        Transform::from_translation(pos.with_z(-0.4))
+                            .with_rotation(Quat::from_rotation_z(PI / 4.0)),
+                        Visibility::Visible,
+                    ));
+                }
+                crate::game::map::MapItem::RepairPack => {
+                    repair_count += 1;
+                    commands.spawn((
+                        InGame,
+                        MapItemTag(placement.item),
+                        RepairPack {
+                            amount: tuning.repair_pack_amount,
+                            respawn: tuning.repair_pack_respawn,
+                            ready_at: 0.0,
                         },
                         CollisionRadius(cell_radius),
+                        Sprite::from_color(
+                            placement.item.color(),
+                            Vec2::splat(crate::game::map::GRID_CELL_SIZE * 0.8),
+                        ),
+                        Transform::from_translation(pos.with_z(-0.4)),
+                        Visibility::Visible,
+                    ));
+                }
+                crate::game::map::MapItem::MovingObstacle => {
+                    moving_count += 1;
+                    // Same contact physics as a static obstacle; PathFollower moves it
+                    let path = placement.path.unwrap_or(crate::game::map::ObstaclePath::new(
+                        placement.grid_x,
+                        placement.grid_y,
+                        crate::game::map::PathMode::PingPong,
+                    ));
+                    let end = Vec2::new(path.end_x as f32, path.end_y as f32) * crate::game::map::GRID_CELL_SIZE;
+                    commands.spawn((
+                        InGame,
+                        MapItemTag(placement.item),
+                        StaticObstacle,
+                        ObstacleMarker,
+                        CollisionRadius(cell_radius),
+                        ObstacleBehavior(CollisionBehavior::DamageOnHit),
+                        ObstacleOwner,
+                        PathFollower {
+                            start: pos.truncate(),
+                            end,
+                            mode: path.mode,
+                            period: path.period,
+                            elapsed: 0.0,
+                        },
                         Sprite {
-                            image: asset_server.load("obstacles/speed_boost.png"),
+                            image: asset_server.load("obstacles/obstacle.png"),
+                            color: placement.item.color(),
                             custom_size: Some(Vec2::splat(crate::game::map::GRID_CELL_SIZE)),
                             ..default()
                         },
-                        Transform::from_translation(pos.with_z(-0.5)),
+                        Transform::from_translation(pos),
                     ));
                 }
-                crate::game::map::MapItem::DamageBoost => {
-                    damage_count += 1;
+                crate::game::map::MapItem::Bumper => {
+                    bumper_count += 1;
+                    // Solid like an obstacle, but bounces harder and deals no damage
                     commands.spawn((
                         InGame,
-                        DamageBoostZone { multiplier: 1.5 },
+                        MapItemTag(placement.item),
+                        StaticObstacle,
+                        ObstacleMarker,
                         CollisionRadius(cell_radius),
+                        ObstacleBehavior(CollisionBehavior::Bumper),
+                        ObstacleOwner,
                         Sprite {
-                            image: asset_server.load("obstacles/damage_boost.png"),
+                            image: asset_server.load("obstacles/obstacle.png"),
+                            color: placement.item.color(),
                             custom_size: Some(Vec2::splat(crate::game::map::GRID_CELL_SIZE)),
                             ..default()
                         },
-                        Transform::from_translation(pos.with_z(-0.5)),
+                        Transform::from_translation(pos),
                     ));
                 }
             }
         }
-        info!(
-            "Map '{}' loaded: {} obstacles, {} gravity, {} speed-boost, {} damage-boost zones",
-            selection.map_id, obs_count, gravity_count, speed_count, damage_count
+        debug!(
+            "Map '{}' loaded: {} obstacles, {} moving, {} bumpers, {} gravity, {} speed-boost, {} damage-boost zones, {} weapon pickups, {} repair packs",
+            selection.map_id, obs_count, moving_count, bumper_count, gravity_count, speed_count, damage_count, pickup_count, repair_count
         );
     } else {
-        info!("Map '{}' not found in registry — using default arena (no placements)", selection.map_id);
+        debug!("Map '{}' not found in registry — using default arena (no placements)", selection.map_id);
     }
 
     // Projectile assets (mesh fallback + sprite handles)
@@ -457,42 +693,35 @@ fn setup_arena(
         material: proj_mat,
         sprites: game_assets.projectile_sprites.clone(),
     });
+    commands.insert_resource(ObstacleAssets {
+        image: asset_server.load("obstacles/obstacle.png"),
+        color: Color::srgba(0.5, 0.85, 1.0, 0.85),
+    });
 
-    // ── Player 1 ─────────────────────────────────────────────────────
-    let p1_ref = registry.builds.get(&selection.p1_build_id)
-        .expect("P1 build not found in registry");
-    let p1_build = registry
-        .resolve_build(
-            &p1_ref.id,
-            &p1_ref.name,
-            &p1_ref.wheel_id,
-            &p1_ref.weapon_id,
-            &p1_ref.shaft_id,
-            &p1_ref.chassis_id,
-            &p1_ref.screw_id,
-        )
-        .expect("P1 build parts not found in registry");
-    let p1_wheel_id = p1_ref.wheel_id.clone();
+    // A deleted build or part falls back to the default build instead of crashing
+    let p1_build = registry.resolve_build_or_default(&selection.p1_build_id);
+    let p1_wheel_id = p1_build.wheel.id.clone();
     let p1_mods = p1_build.combined_modifiers();
     let p1_effective = p1_mods.compute_effective(&p1_build.wheel, &tuning);
     let p1_radius = p1_effective.radius.0;
 
-    let mut p1_entity = commands.spawn((
-        InGame,
-        Top,
-        PlayerControlled,
-        Transform::from_translation(Vec3::new(-3.0, 0.0, 0.0)),
-        Velocity(Vec2::ZERO),
-        RotationAngle(AngleRad::new(0.0)),
-        SpinHpCurrent(p1_effective.spin_hp_max),
-        TopEffectiveStats(p1_effective.clone()),
-        TopBuild(p1_build.clone()),
-        ControlState::default(),
-        (LaunchAim::default(), MeleeHitTracker::default(), combat::RangedFireTimer::default()),
-        SpeedBoostEffect { expires_at: 0.0, multiplier: 1.0 },
-        DamageBoostActive { multiplier: 1.0 },
-        WeaponAimAngle::default(),
-    ));
+    let p2_build = registry.resolve_build_or_default(&selection.p2_build_id);
+    let p2_wheel_id = p2_build.wheel.id.clone();
+    let p2_mods = p2_build.combined_modifiers();
+    let p2_effective = p2_mods.compute_effective(&p2_build.wheel, &tuning);
+    let p2_radius = p2_effective.radius.0;
+
+    let ((p1_start, p1_angle), (p2_start, p2_angle)) = start_slots(
+        ghost.as_ref().map(|g| g.start()),
+        selection.center_start,
+        p1_radius,
+        p2_radius,
+    );
+
+    // ── Player 1 ─────────────────────────────────────────────────────
+
+    let mut p1_entity = spawn_top(&mut commands, &p1_build, p1_effective, p1_start, p1_angle);
+    p1_entity.insert(PlayerControlled);
     insert_wheel_visual(&mut p1_entity, &p1_wheel_id, p1_radius, &game_assets, &mut meshes, &mut materials);
     p1_entity.with_children(|parent| {
         spawn_weapon_visual(parent, &p1_build.weapon, p1_radius, &game_assets, &mut meshes, &mut materials);
@@ -500,6 +729,7 @@ fn setup_arena(
 
     // P1 aim arrow
     let arrow_len = tuning.aim_arrow_len_px / ppu;
+    let p1_arrow_center = p1_start + Vec2::from_angle(p1_angle) * (arrow_len * 0.5);
     commands.spawn((
         InGame,
         AimArrow,
@@ -509,47 +739,27 @@ fn setup_arena(
             color: Color::srgba(0.2, 1.0, 0.2, 0.9),
             ..default()
         },
-        Transform::from_translation(Vec3::new(-3.0 + arrow_len * 0.5, 0.0, 1.0)),
+        Transform::from_translation(p1_arrow_center.extend(1.0)).with_rotation(Quat::from_rotation_z(p1_angle)),
     ));
+    spawn_aim_angle_label(&mut commands, p1_start, p1_angle, &tuning, false);
 
     // ── Player 2 / AI ────────────────────────────────────────────────
-    let p2_ref = registry.builds.get(&selection.p2_build_id)
-        .expect("P2 build not found in registry");
-    let p2_build = registry
-        .resolve_build(
-            &p2_ref.id,
-            &p2_ref.name,
-            &p2_ref.wheel_id,
-            &p2_ref.weapon_id,
-            &p2_ref.shaft_id,
-            &p2_ref.chassis_id,
-            &p2_ref.screw_id,
-        )
-        .expect("P2 build parts not found in registry");
-    let p2_wheel_id = p2_ref.wheel_id.clone();
-    let p2_mods = p2_build.combined_modifiers();
-    let p2_effective = p2_mods.compute_effective(&p2_build.wheel, &tuning);
-    let p2_radius = p2_effective.radius.0;
-
-    let mut p2_entity = commands.spawn((
-        InGame,
-        Top,
-        Transform::from_translation(Vec3::new(3.0, 0.0, 0.0)),
-        Velocity(Vec2::ZERO),
-        RotationAngle(AngleRad::new(PI)),
-        SpinHpCurrent(p2_effective.spin_hp_max),
-        TopEffectiveStats(p2_effective),
-        TopBuild(p2_build.clone()),
-        ControlState::default(),
-        (LaunchAim { angle: PI, confirmed: false }, MeleeHitTracker::default(), combat::RangedFireTimer::default()),
-        SpeedBoostEffect { expires_at: 0.0, multiplier: 1.0 },
-        DamageBoostActive { multiplier: 1.0 },
-        WeaponAimAngle::default(),
-    ));
+    let mut p2_entity = spawn_top(&mut commands, &p2_build, p2_effective, p2_start, p2_angle);
 
     match selection.mode {
-        GameMode::PvAI => { p2_entity.insert(AiControlled); }
+        GameMode::PvAI => {
+            let profile = registry.ai_profiles.get(&selection.ai_profile_id).cloned().unwrap_or_default();
+            p2_entity.insert((AiControlled, AiBehavior(profile), AiSteer::new(selection.ai_difficulty, &tuning)));
+        }
         GameMode::PvP => { p2_entity.insert(Player2Controlled); }
+        // Replays the recorded run; no aiming needed
+        GameMode::Ghost => {
+            p2_entity.insert((
+                AiControlled,
+                GhostTop::default(),
+                LaunchAim { angle: p2_angle, confirmed: true },
+            ));
+        }
     }
 
     insert_wheel_visual(&mut p2_entity, &p2_wheel_id, p2_radius, &game_assets, &mut meshes, &mut materials);
@@ -559,9 +769,7 @@ fn setup_arena(
 
     // P2 aim arrow (PvP only — AI auto-aims so no arrow needed)
     if selection.mode == GameMode::PvP {
-        // P2 faces left (angle=PI), so offset arrow to the left
-        let p2_dir = Vec2::new(PI.cos(), PI.sin());
-        let p2_arrow_center = Vec2::new(3.0, 0.0) + p2_dir * (arrow_len * 0.5);
+        let p2_arrow_center = p2_start + Vec2::from_angle(p2_angle) * (arrow_len * 0.5);
         commands.spawn((
             InGame,
             AimArrow,
@@ -573,31 +781,139 @@ fn setup_arena(
                 ..default()
             },
             Transform::from_translation(Vec3::new(p2_arrow_center.x, p2_arrow_center.y, 1.0))
-                .with_rotation(Quat::from_rotation_z(PI)),
+                .with_rotation(Quat::from_rotation_z(p2_angle)),
         ));
+        spawn_aim_angle_label(&mut commands, p2_start, p2_angle, &tuning, true);
+    }
+}
+
+/// Gap left between the two tops on a center start.
+const CENTER_START_GAP: f32 = 0.1;
+
+/// Start position and facing of P1 and P2. Normally they face each other from
+/// x = ∓3; vs Ghost the player takes the slot opposite the ghost's recorded
+/// start. Center start puts them back to back at the middle, just far enough
+/// apart that their radii don't overlap, facing outward.
+fn start_slots(
+    ghost_start: Option<Vec2>,
+    center_start: bool,
+    p1_radius: f32,
+    p2_radius: f32,
+) -> ((Vec2, f32), (Vec2, f32)) {
+    match ghost_start {
+        Some(start) if start.x < 0.0 => ((Vec2::new(3.0, 0.0), PI), (start, PI)),
+        Some(start) => ((Vec2::new(-3.0, 0.0), 0.0), (start, PI)),
+        None if center_start => {
+            let half_gap = CENTER_START_GAP * 0.5;
+            (
+                (Vec2::new(-(p1_radius + half_gap), 0.0), PI),
+                (Vec2::new(p2_radius + half_gap, 0.0), 0.0),
+            )
+        }
+        None => ((Vec2::new(-3.0, 0.0), 0.0), (Vec2::new(3.0, 0.0), PI)),
+    }
+}
+
+/// Spawn a top's simulation components (no visuals), facing and aimed along
+/// `angle`. Shared by `setup_arena` and the headless runner.
+pub fn spawn_top<'a>(
+    commands: &'a mut Commands,
+    build: &Build,
+    stats: EffectiveStats,
+    start: Vec2,
+    angle: f32,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        InGame,
+        Top,
+        Transform::from_translation(start.extend(0.0)),
+        Velocity(Vec2::ZERO),
+        RotationAngle(AngleRad::new(angle)),
+        SpinHpCurrent(stats.spin_hp_max),
+        TopEffectiveStats(stats),
+        TopBuild(build.clone()),
+        ControlState::default(),
+        (
+            LaunchAim { angle, confirmed: false },
+            MeleeHitTracker::default(),
+            combat::RangedFireTimer::default(),
+            WallHugTicks::default(),
+            HookCooldown::default(),
+            TopTrail::default(),
+        ),
+        SpeedBoostEffect { expires_at: 0.0, multiplier: 1.0 },
+        WeaponSurgeEffect { expires_at: 0.0, multiplier: 1.0 },
+        DamageBoostActive { multiplier: 1.0 },
+        WeaponAimAngle::default(),
+    ))
+}
+
+/// Spawn the world-space degree readout for an aim arrow.
+fn spawn_aim_angle_label(
+    commands: &mut Commands,
+    top_pos: Vec2,
+    angle: f32,
+    tuning: &Tuning,
+    is_p2: bool,
+) {
+    let ppu = tuning.pixels_per_unit.max(1.0);
+    let pos = aim_label_position(top_pos, angle, tuning);
+    let mut label = commands.spawn((
+        InGame,
+        AimAngleLabel,
+        Text2d::new(format_aim_degrees(angle)),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(if is_p2 { Color::srgb(1.0, 0.6, 0.4) } else { Color::srgb(0.6, 1.0, 0.6) }),
+        // Camera is scaled to 1/ppu, so scale text back down to pixel size.
+        Transform::from_translation(pos.extend(2.0)).with_scale(Vec3::splat(1.0 / ppu)),
+    ));
+    if is_p2 {
+        label.insert(Player2Controlled);
     }
 }
 
-// ── Cleanup on return to MainMenu ────────────────────────────────────
+/// Readout sits just past the arrow tip.
+fn aim_label_position(top_pos: Vec2, angle: f32, tuning: &Tuning) -> Vec2 {
+    let ppu = tuning.pixels_per_unit.max(1.0);
+    let dist = (tuning.aim_arrow_offset_px + tuning.aim_arrow_len_px * 0.5 + 24.0) / ppu;
+    top_pos + Vec2::new(angle.cos(), angle.sin()) * dist
+}
+
+fn format_aim_degrees(angle: f32) -> String {
+    format!("{:.0}°", angle.to_degrees().rem_euclid(360.0))
+}
+
+// ── Cleanup on return to MainMenu / leaving GameOver ─────────────────
 
 fn cleanup_game(
     mut commands: Commands,
     query: Query<Entity, With<InGame>>,
+    mut pool: ResMut<ProjectilePool>,
 ) {
     for entity in &query {
         commands.entity(entity).despawn();
     }
+    // Pooled projectiles are InGame too; drop the stale ids
+    pool.clear();
     commands.remove_resource::<ProjectileAssets>();
+    commands.remove_resource::<ObstacleAssets>();
     commands.remove_resource::<ArenaRadius>();
+    commands.remove_resource::<ArenaHazards>();
+    commands.insert_resource(ArenaAudio::default());
+}
+
+fn reset_round_score(mut score: ResMut<RoundScore>) {
+    *score = RoundScore::default();
 }
 
 // ── Aiming phase systems ────────────────────────────────────────────
 
-/// Player 1 rotates with Arrow keys, confirms with Space.
+/// Player 1 rotates and confirms with their `KeyBindings` (default Arrows + Space).
 fn read_aim_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
     tuning: Res<Tuning>,
+    bindings: Res<KeyBindings>,
     mut query: Query<&mut LaunchAim, With<PlayerControlled>>,
 ) {
     let aim_speed = tuning.aim_speed;
@@ -605,23 +921,25 @@ fn read_aim_input(
         if aim.confirmed {
             continue;
         }
-        if keyboard.pressed(KeyCode::ArrowLeft) {
+        if keyboard.pressed(bindings.p1_left) {
             aim.angle += aim_speed * time.delta_secs();
         }
-        if keyboard.pressed(KeyCode::ArrowRight) {
+        if keyboard.pressed(bindings.p1_right) {
             aim.angle -= aim_speed * time.delta_secs();
         }
-        if keyboard.just_pressed(KeyCode::Space) {
+        aim.angle = aim.angle.rem_euclid(TAU);
+        if keyboard.just_pressed(bindings.p1_confirm) {
             aim.confirmed = true;
         }
     }
 }
 
-/// Player 2 (PvP) rotates with A/D, confirms with Enter.
+/// Player 2 (PvP) rotates and confirms with their `KeyBindings` (default A/D + Enter).
 fn read_aim_input_p2(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
     tuning: Res<Tuning>,
+    bindings: Res<KeyBindings>,
     mut query: Query<&mut LaunchAim, With<Player2Controlled>>,
 ) {
     let aim_speed = tuning.aim_speed;
@@ -629,37 +947,48 @@ fn read_aim_input_p2(
         if aim.confirmed {
             continue;
         }
-        if keyboard.pressed(KeyCode::KeyA) {
+        if keyboard.pressed(bindings.p2_left) {
             aim.angle += aim_speed * time.delta_secs();
         }
-        if keyboard.pressed(KeyCode::KeyD) {
+        if keyboard.pressed(bindings.p2_right) {
             aim.angle -= aim_speed * time.delta_secs();
         }
-        if keyboard.just_pressed(KeyCode::Enter) {
+        aim.angle = aim.angle.rem_euclid(TAU);
+        if keyboard.just_pressed(bindings.p2_confirm) {
             aim.confirmed = true;
         }
     }
 }
 
-/// AI auto-aims with a pseudo-random direction and confirms immediately.
-fn ai_auto_aim(mut query: Query<&mut LaunchAim, With<AiControlled>>) {
-    for mut aim in &mut query {
-        if !aim.confirmed {
-            aim.angle = pseudo_random_angle();
-            aim.confirmed = true;
+/// AI aims per its `AiBehavior` profile, off by up to its difficulty's aim
+/// error (random draws from `GameRng`), and confirms immediately.
+fn ai_auto_aim(
+    mut query: Query<(&mut LaunchAim, &Transform, Option<&AiBehavior>, Option<&AiSteer>), With<AiControlled>>,
+    opponents: Query<&Transform, (With<Top>, Without<AiControlled>)>,
+    tuning: Res<Tuning>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (mut aim, tf, behavior, steer) in &mut query {
+        if aim.confirmed {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        let toward = opponents
+            .iter()
+            .next()
+            .map(|o| (o.translation.truncate() - pos).to_angle())
+            .unwrap_or(aim.angle);
+        let profile = behavior.map(|b| b.0.clone()).unwrap_or_default();
+        let mut angle = profile.launch_angle(toward, &mut rng);
+        let error = steer.map_or(0.0, |s| s.difficulty.tuning(&tuning).aim_error);
+        if error > 0.0 {
+            angle += rng.range_f32(-error, error);
         }
+        aim.angle = angle.rem_euclid(TAU);
+        aim.confirmed = true;
     }
 }
 
-fn pseudo_random_angle() -> f32 {
-    use std::time::SystemTime;
-    let nanos = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos();
-    (nanos as f32 / 1_000_000_000.0) * 2.0 * PI
-}
-
 /// When all tops have confirmed their aim, transition to Battle.
 fn check_all_confirmed(
     query: Query<&LaunchAim, With<Top>>,
@@ -710,30 +1039,128 @@ fn update_aim_arrow(
     }
 }
 
-// ── OnEnter(Battle) systems ─────────────────────────────────────────
+/// Visual: keep each degree readout next to its arrow, showing the live angle.
+fn update_aim_angle_labels(
+    tuning: Res<Tuning>,
+    player: Query<(&Transform, &LaunchAim), (With<PlayerControlled>, Without<AimAngleLabel>)>,
+    p2_top: Query<(&Transform, &LaunchAim), (With<Player2Controlled>, With<Top>, Without<AimAngleLabel>)>,
+    mut labels_p1: Query<
+        (&mut Transform, &mut Text2d),
+        (With<AimAngleLabel>, Without<PlayerControlled>, Without<Player2Controlled>),
+    >,
+    mut labels_p2: Query<
+        (&mut Transform, &mut Text2d),
+        (With<AimAngleLabel>, With<Player2Controlled>, Without<Top>),
+    >,
+) {
+    if let Some((top_tf, aim)) = player.iter().next() {
+        let pos = aim_label_position(top_tf.translation.truncate(), aim.angle, &tuning);
+        for (mut tf, mut text) in &mut labels_p1 {
+            tf.translation = pos.extend(2.0);
+            **text = format_aim_degrees(aim.angle);
+        }
+    }
+    if let Some((top_tf, aim)) = p2_top.iter().next() {
+        let pos = aim_label_position(top_tf.translation.truncate(), aim.angle, &tuning);
+        for (mut tf, mut text) in &mut labels_p2 {
+            tf.translation = pos.extend(2.0);
+            **text = format_aim_degrees(aim.angle);
+        }
+    }
+}
 
-/// Set each top's velocity from its aim direction * move_speed. Play launch sound.
-fn launch_tops(
-    mut commands: Commands,
-    mut query: Query<(&LaunchAim, &mut Velocity, &TopEffectiveStats), With<Top>>,
-    game_assets: Res<GameAssets>,
+/// PvP camera flip (opt-in setting): once P1 has locked in and P2 is still aiming,
+/// rotate the view 180° so the arena is seen from P2's side. A rotation (not a mirror)
+/// keeps A/D turning the arrow the same way on screen.
+fn pvp_camera_flip(
+    settings: Res<UserSettings>,
+    selection: Res<GameSelection>,
+    p1: Query<&LaunchAim, With<PlayerControlled>>,
+    p2: Query<&LaunchAim, (With<Player2Controlled>, Without<PlayerControlled>)>,
+    mut camera: Query<&mut Transform, (With<Camera2d>, Without<AimAngleLabel>)>,
+    mut labels: Query<&mut Transform, (With<AimAngleLabel>, Without<Camera2d>)>,
 ) {
-    let mut launched = false;
+    if !settings.pvp_camera_flip || selection.mode != GameMode::PvP {
+        return;
+    }
+    let p2_turn = p1.iter().all(|a| a.confirmed) && p2.iter().any(|a| !a.confirmed);
+    let rotation = Quat::from_rotation_z(if p2_turn { PI } else { 0.0 });
+    for mut tf in &mut camera {
+        tf.rotation = rotation;
+    }
+    // Keep the degree readouts upright relative to the screen.
+    for mut tf in &mut labels {
+        tf.rotation = rotation;
+    }
+}
+
+/// Undo any PvP camera flip once the battle starts.
+fn reset_camera_rotation(mut camera: Query<&mut Transform, With<Camera2d>>) {
+    for mut tf in &mut camera {
+        tf.rotation = Quat::IDENTITY;
+    }
+}
+
+/// Default battle view: centered, unrotated, and zoomed out just enough for the
+/// whole arena to fit the window (never closer than `pixels_per_unit`), then
+/// scaled by the "Battle Zoom" setting. Runs on arena setup and on R.
+fn frame_arena_view(
+    tuning: Res<Tuning>,
+    settings: Res<UserSettings>,
+    arena: Option<Res<ArenaRadius>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    let radius = arena.map_or(tuning.arena_radius, |a| a.0);
+    let mut scale = 1.0 / tuning.pixels_per_unit.max(1.0);
+    if let Ok(window) = windows.single() {
+        let short_side = window.width().min(window.height()).max(1.0);
+        // Half a unit of margin so the wall isn't flush with the window edge.
+        scale = scale.max((radius + 0.5) * 2.0 / short_side);
+    }
+    scale /= settings.camera_zoom_factor().max(0.1);
+    for (mut tf, mut projection) in &mut camera {
+        tf.translation = Vec3::new(0.0, 0.0, tf.translation.z);
+        tf.rotation = Quat::IDENTITY;
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale = scale;
+        }
+    }
+}
+
+fn reset_camera_pressed(keyboard: Res<ButtonInput<KeyCode>>) -> bool {
+    keyboard.just_pressed(KeyCode::KeyR)
+}
+
+// ── OnEnter(Battle) systems ─────────────────────────────────────────
+
+/// Set each top's velocity from its aim direction * move_speed.
+fn launch_tops(mut query: Query<(&LaunchAim, &mut Velocity, &TopEffectiveStats), With<Top>>) {
     for (aim, mut vel, stats) in &mut query {
         let dir = Vec2::new(aim.angle.cos(), aim.angle.sin());
         vel.0 = dir * stats.0.move_speed.0;
-        launched = true;
     }
-    if launched {
-        commands.spawn((
-            AudioPlayer::<AudioSource>(game_assets.sfx.launch.clone()),
-            PlaybackSettings::DESPAWN,
-        ));
+}
+
+/// Play the launch sound once if any top was launched.
+fn play_launch_sound(
+    mut commands: Commands,
+    tops: Query<(), With<Top>>,
+    game_assets: Res<GameAssets>,
+    audio: Res<ArenaAudio>,
+    audio_settings: Res<AudioSettings>,
+    voices: Query<Entity, (With<SfxVoice>, Without<AudioPlayer<AudioSource>>)>,
+) {
+    if !tops.is_empty() && !audio_settings.muted {
+        play_on_voice(&mut commands, &voices, game_assets.sfx.launch.clone(), audio.voice(1, &audio_settings));
     }
 }
 
-/// Despawn aim arrow entities.
-fn despawn_aim_arrows(mut commands: Commands, arrows: Query<Entity, With<AimArrow>>) {
+/// Despawn aim arrow entities and their degree readouts.
+fn despawn_aim_arrows(
+    mut commands: Commands,
+    arrows: Query<Entity, Or<(With<AimArrow>, With<AimAngleLabel>)>>,
+) {
     for entity in &arrows {
         commands.entity(entity).despawn();
     }
@@ -741,35 +1168,347 @@ fn despawn_aim_arrows(mut commands: Commands, arrows: Query<Entity, With<AimArro
 
 // ── Battle phase systems ────────────────────────────────────────────
 
-/// Transition to GameOver when any top's spin HP reaches 0.
+/// P pauses / resumes the battle by pausing `Time<Virtual>`: no fixed ticks
+/// run and every `elapsed_secs` deadline (boost expiry, pickup respawn) stops
+/// with it. Virtual time gains nothing while paused, so resuming never
+/// bursts catch-up ticks. N feeds `Time<Fixed>` exactly one timestep, so the
+/// next frame runs a single fixed tick.
+fn battle_pause_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut fixed: ResMut<Time<Fixed>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyP) {
+        if virtual_time.is_paused() {
+            virtual_time.unpause();
+        } else {
+            virtual_time.pause();
+        }
+    }
+    if virtual_time.is_paused() && keyboard.just_pressed(KeyCode::KeyN) {
+        let timestep = fixed.timestep();
+        fixed.accumulate_overstep(timestep);
+    }
+}
+
+/// A new round or match always starts unpaused.
+fn unpause_battle(mut virtual_time: ResMut<Time<Virtual>>) {
+    virtual_time.unpause();
+}
+
+/// End the round when any top's spin HP reaches 0, or when the match timer
+/// decides it on spin HP: record the `MatchResult`, score it, then go to
+/// GameOver once the series is decided, else back to Aiming for the next round.
+/// Each defeated top is hidden and replaced by a death burst + sound.
 fn check_game_over(
-    query: Query<&SpinHpCurrent, With<Top>>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &SpinHpCurrent, &Transform, &TopEffectiveStats, &mut Visibility, Has<PlayerControlled>), With<Top>>,
+    game_assets: Res<GameAssets>,
+    audio: Res<ArenaAudio>,
+    audio_settings: Res<AudioSettings>,
+    voices: Query<Entity, (With<SfxVoice>, Without<AudioPlayer<AudioSource>>)>,
+    selection: Res<GameSelection>,
+    timer: Res<MatchTimer>,
+    mut score: ResMut<RoundScore>,
+    mut match_result: ResMut<MatchResult>,
     mut next_state: ResMut<NextState<GamePhase>>,
 ) {
-    for spin in &query {
+    let tops: Vec<(Entity, f32)> = query.iter().map(|(entity, spin, ..)| (entity, spin.0 .0)).collect();
+    let Some(result) = MatchResult::from_spin_hp(&tops, timer.decided) else {
+        return;
+    };
+    let mut defeated = false;
+    let mut p1_lost = false;
+    for (entity, spin, tf, stats, mut visibility, is_p1) in &mut query {
         if spin.0 .0 <= 0.0 {
-            next_state.set(GamePhase::GameOver);
-            return;
+            *visibility = Visibility::Hidden;
+            spawn_death_burst(&mut commands, tf.translation.truncate(), stats.0.radius.0);
+            defeated = true;
+        }
+        p1_lost |= is_p1 && result.loser == Some(entity);
+    }
+    if defeated && !audio_settings.muted {
+        play_on_voice(&mut commands, &voices, game_assets.sfx.top_defeated.clone(), audio.voice(1, &audio_settings));
+    }
+    // A double KO scores for nobody and the round is replayed
+    if !result.draw {
+        if p1_lost {
+            score.p2 += 1;
+        } else {
+            score.p1 += 1;
+        }
+    }
+    *match_result = result;
+    if selection.best_of <= 1 || score.clinched(selection.best_of) {
+        next_state.set(GamePhase::GameOver);
+    } else {
+        info!("Round over, series {} - {}", score.p1, score.p2);
+        next_state.set(GamePhase::Aiming);
+    }
+}
+
+/// Keep the winning top's track as the ghost for "vs Ghost" practice, whether
+/// it won by KO or on time. Draws and ghost wins leave the previous recording in place.
+fn keep_winning_run(
+    mut commands: Commands,
+    mut recorder: ResMut<BattleRecorder>,
+    selection: Res<GameSelection>,
+    tuning: Res<Tuning>,
+    match_result: Res<MatchResult>,
+    tops: Query<(Entity, &TopBuild, Has<GhostTop>), With<Top>>,
+) {
+    let Some(loser) = match_result.loser.filter(|_| !match_result.draw) else {
+        return;
+    };
+    let Some((winner, build, is_ghost)) = tops.iter().find(|(entity, _, _)| *entity != loser) else {
+        return;
+    };
+    if is_ghost {
+        return;
+    }
+    let Some(frames) = recorder.tracks.remove(&winner).filter(|f| !f.is_empty()) else {
+        return;
+    };
+    info!("Ghost recorded: {} on '{}' ({} ticks)", build.0.name, selection.map_id, frames.len());
+    commands.insert_resource(GhostRecording {
+        build_id: build.0.id.clone(),
+        map_id: selection.map_id.clone(),
+        dt: tuning.dt,
+        duration: frames.len() as f32 * tuning.dt,
+        frames,
+    });
+}
+
+/// Ring of shards flying outward from where a top was eliminated.
+fn spawn_death_burst(commands: &mut Commands, pos: Vec2, radius: f32) {
+    const SHARDS: usize = 16;
+    for i in 0..SHARDS {
+        let angle = i as f32 / SHARDS as f32 * TAU;
+        let dir = Vec2::new(angle.cos(), angle.sin());
+        let start = pos + dir * radius * 0.5;
+        commands.spawn((
+            InGame,
+            BurstParticle { velocity: dir * 6.0, ttl: BurstParticle::TTL },
+            Sprite {
+                color: Color::srgb(1.0, 0.7, 0.2),
+                custom_size: Some(Vec2::splat(radius * 0.35)),
+                ..default()
+            },
+            Transform::from_translation(start.extend(2.0)),
+        ));
+    }
+}
+
+/// Move and fade death-burst shards; runs through GameOver so the burst plays out.
+fn animate_burst_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut BurstParticle, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut tf, mut sprite) in &mut query {
+        particle.ttl -= dt;
+        if particle.ttl <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
         }
+        tf.translation += (particle.velocity * dt).extend(0.0);
+        particle.velocity *= 1.0 - 2.0 * dt;
+        sprite.color.set_alpha(particle.ttl / BurstParticle::TTL);
+    }
+}
+
+/// World-space hit readout over each top that lost at least half a point of
+/// spin HP, colored by what dealt the damage. Runs through GameOver so the
+/// finishing blow still shows.
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    tuning: Res<Tuning>,
+    mut taken: MessageReader<DamageTakenMessage>,
+    tops: Query<(&Transform, &TopEffectiveStats), With<Top>>,
+) {
+    let ppu = tuning.pixels_per_unit.max(1.0);
+    for event in taken.read() {
+        if event.amount < 0.5 {
+            continue;
+        }
+        let Ok((tf, stats)) = tops.get(event.dst) else {
+            continue;
+        };
+        let pos = tf.translation.truncate() + Vec2::Y * stats.0.radius.0;
+        let color = match event.kind {
+            DamageKind::Melee => Color::srgb(1.0, 0.55, 0.2),
+            DamageKind::Projectile => Color::srgb(0.4, 0.85, 1.0),
+            DamageKind::Collision => Color::srgb(1.0, 1.0, 1.0),
+            DamageKind::Wall | DamageKind::Obstacle => Color::srgb(0.75, 0.75, 0.75),
+        };
+        commands.spawn((
+            InGame,
+            DamageNumber { ttl: DamageNumber::TTL, velocity: Vec2::new(0.0, 1.5) },
+            Text2d::new(format!("{:.0}", event.amount)),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(color),
+            // Camera is scaled to 1/ppu, so scale text back down to pixel size.
+            Transform::from_translation(pos.extend(3.0)).with_scale(Vec3::splat(1.0 / ppu)),
+        ));
+    }
+}
+
+/// Float hit numbers upward, fading out until their ttl runs out.
+fn animate_damage_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DamageNumber, &mut Transform, &mut TextColor)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut number, mut tf, mut color) in &mut query {
+        number.ttl -= dt;
+        if number.ttl <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        tf.translation += (number.velocity * dt).extend(0.0);
+        color.0.set_alpha(number.ttl / DamageNumber::TTL);
+    }
+}
+
+/// Draw each projectile's recent path as a line fading out toward its tail.
+fn draw_projectile_trails(mut gizmos: Gizmos, query: Query<&ProjectileTrail>) {
+    for trail in &query {
+        let n = trail.points.len();
+        if n < 2 {
+            continue;
+        }
+        gizmos.linestrip_gradient_2d(trail.points.iter().enumerate().map(|(i, p)| {
+            let alpha = (i + 1) as f32 / n as f32 * 0.6;
+            (*p, Color::srgba(1.0, 0.9, 0.5, alpha))
+        }));
+    }
+}
+
+fn top_trails_enabled(settings: Option<Res<UserSettings>>) -> bool {
+    settings.is_some_and(|s| s.top_trails)
+}
+
+/// Draw each top's recent path, P1 green and the opponent orange (the aim label colors).
+fn draw_top_trails(mut gizmos: Gizmos, query: Query<(&TopTrail, Has<PlayerControlled>)>) {
+    for (trail, is_p1) in &query {
+        let n = trail.points.len();
+        if n < 2 {
+            continue;
+        }
+        let (r, g, b) = if is_p1 { (0.6, 1.0, 0.6) } else { (1.0, 0.6, 0.4) };
+        gizmos.linestrip_gradient_2d(trail.points.iter().enumerate().map(|(i, p)| {
+            let alpha = (i + 1) as f32 / n as f32 * 0.5;
+            (*p, Color::srgba(r, g, b, alpha))
+        }));
+    }
+}
+
+/// HP bar size and gap above the top's rim, in world units.
+const HP_BAR_WIDTH: f32 = 2.0;
+const HP_BAR_HEIGHT: f32 = 0.22;
+const HP_BAR_GAP: f32 = 0.4;
+
+/// Spawn a bar for each top that lacks one, then move every bar above its
+/// top and size / color the fill by spin HP (green → yellow → red).
+fn update_hp_bars(
+    mut commands: Commands,
+    tops: Query<(Entity, &Transform, &SpinHpCurrent, &TopEffectiveStats), (With<Top>, Without<HpBar>, Without<HpBarFill>)>,
+    mut bars: Query<(Entity, &HpBar, &mut Transform, &Children), Without<HpBarFill>>,
+    mut fills: Query<(&mut Transform, &mut Sprite), (With<HpBarFill>, Without<HpBar>)>,
+) {
+    let mut owners = Vec::new();
+    for (bar_entity, bar, mut bar_tf, children) in &mut bars {
+        let Ok((_, top_tf, spin, stats)) = tops.get(bar.owner) else {
+            commands.entity(bar_entity).despawn();
+            continue;
+        };
+        owners.push(bar.owner);
+        let top_pos = top_tf.translation.truncate();
+        bar_tf.translation = (top_pos + Vec2::Y * (stats.0.radius.0 + HP_BAR_GAP)).extend(bar_tf.translation.z);
+
+        let ratio = (spin.0 .0 / stats.0.spin_hp_max.0.max(0.001)).clamp(0.0, 1.0);
+        for child in children.iter() {
+            if let Ok((mut fill_tf, mut sprite)) = fills.get_mut(child) {
+                fill_tf.scale.x = ratio;
+                fill_tf.translation.x = -(1.0 - ratio) * HP_BAR_WIDTH * 0.5;
+                sprite.color = Color::srgb(((1.0 - ratio) * 2.0).min(1.0), (ratio * 2.0).min(1.0), 0.15);
+            }
+        }
+    }
+
+    for (top, top_tf, _, stats) in &tops {
+        if owners.contains(&top) {
+            continue;
+        }
+        let pos = top_tf.translation.truncate() + Vec2::Y * (stats.0.radius.0 + HP_BAR_GAP);
+        commands.spawn((
+            InGame,
+            HpBar { owner: top },
+            Sprite::from_color(Color::srgba(0.05, 0.05, 0.08, 0.8), Vec2::new(HP_BAR_WIDTH, HP_BAR_HEIGHT)),
+            Transform::from_translation(pos.extend(5.0)),
+        )).with_children(|bar| {
+            bar.spawn((
+                HpBarFill,
+                Sprite::from_color(Color::srgb(0.0, 1.0, 0.15), Vec2::new(HP_BAR_WIDTH, HP_BAR_HEIGHT)),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ));
+        });
     }
 }
 
 // ── Audio system ────────────────────────────────────────────────────
 
+/// Number of persistent `SfxVoice` players. More simultaneous distinct
+/// sounds than this are dropped.
+const SFX_VOICES: usize = 12;
+
+fn spawn_sfx_voices(mut commands: Commands) {
+    for _ in 0..SFX_VOICES {
+        commands.spawn(SfxVoice);
+    }
+}
+
+/// Start `handle` on a free pooled voice; dropped if every voice is busy.
+fn play_on_voice(
+    commands: &mut Commands,
+    voices: &Query<Entity, (With<SfxVoice>, Without<AudioPlayer<AudioSource>>)>,
+    handle: Handle<AudioSource>,
+    playback: PlaybackSettings,
+) {
+    if let Some(voice) = voices.iter().next() {
+        commands.entity(voice).insert((AudioPlayer::<AudioSource>(handle), playback));
+    }
+}
+
 /// Play sound effects in response to game events (runs in CleanupSet).
 fn play_sound_effects(
     mut commands: Commands,
     mut game_events: MessageReader<GameEvent>,
     mut collision_events: MessageReader<CollisionMessage>,
     game_assets: Res<GameAssets>,
+    audio: Res<ArenaAudio>,
+    audio_settings: Res<AudioSettings>,
     tops: Query<&TopBuild, With<Top>>,
+    voices: Query<Entity, (With<SfxVoice>, Without<AudioPlayer<AudioSource>>)>,
 ) {
+    if audio_settings.muted {
+        // Drain this tick's messages so they don't play later
+        game_events.clear();
+        collision_events.clear();
+        return;
+    }
+    // Identical sounds this tick are merged into one, louder playback
+    let mut batch: Vec<(Handle<AudioSource>, u32)> = Vec::new();
+    let mut queue = |handle: Handle<AudioSource>| match batch.iter_mut().find(|(h, _)| *h == handle) {
+        Some((_, layers)) => *layers += 1,
+        None => batch.push((handle, 1)),
+    };
+
     // Top-top collision
     for _event in collision_events.read() {
-        commands.spawn((
-            AudioPlayer::<AudioSource>(game_assets.sfx.collision_top.clone()),
-            PlaybackSettings::DESPAWN,
-        ));
+        queue(game_assets.sfx.collision_top.clone());
     }
 
     for event in game_events.read() {
@@ -789,10 +1528,7 @@ fn play_sound_effects(
                     _ => None,
                 };
                 if let Some(h) = handle {
-                    commands.spawn((
-                        AudioPlayer::<AudioSource>(h),
-                        PlaybackSettings::DESPAWN,
-                    ));
+                    queue(h);
                 }
             }
             GameEvent::SpawnProjectile { weapon_id, .. } => {
@@ -800,14 +1536,15 @@ fn play_sound_effects(
                     .get(weapon_id)
                     .unwrap_or(&game_assets.sfx.ranged_fire)
                     .clone();
-                commands.spawn((
-                    AudioPlayer::<AudioSource>(handle),
-                    PlaybackSettings::DESPAWN,
-                ));
+                queue(handle);
             }
             _ => {}
         }
     }
+
+    for ((handle, layers), voice) in batch.into_iter().zip(&voices) {
+        commands.entity(voice).insert((AudioPlayer::<AudioSource>(handle), audio.voice(layers, &audio_settings)));
+    }
 }
 
 // ── Map item battle systems ─────────────────────────────────────────
@@ -846,11 +1583,38 @@ fn gravity_device_system(
     }
 }
 
+/// Moving obstacles: advance along their path. Runs before `static_obstacle_bounce`,
+/// which pushes tops out of the obstacle's new position.
+fn moving_obstacle_system(tuning: Res<Tuning>, mut movers: Query<(&mut Transform, &mut PathFollower)>) {
+    for (mut tf, mut follower) in &mut movers {
+        follower.elapsed = (follower.elapsed + tuning.dt) % follower.period.max(0.01);
+        let pos = follower.position();
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
+    }
+}
+
+/// Boost-zone sprite tiled one texture per grid cell, so large areas don't stretch.
+fn zone_sprite(image: Handle<Image>, size: Vec2) -> Sprite {
+    Sprite {
+        image,
+        custom_size: Some(size),
+        image_mode: SpriteImageMode::Tiled {
+            tile_x: true,
+            tile_y: true,
+            // Sprites are 64 px; one tile per cell
+            stretch_value: crate::game::map::GRID_CELL_SIZE / 64.0,
+        },
+        ..default()
+    }
+}
+
 /// Speed boost: tops overlapping a SpeedBoostZone get a speed multiplier.
 /// Mutates the always-present SpeedBoostEffect directly (no deferred Commands).
 fn speed_boost_system(
     time: Res<Time>,
-    zones: Query<(&Transform, &CollisionRadius, &SpeedBoostZone)>,
+    log: Res<DebugLogging>,
+    zones: Query<(&Transform, &ZoneArea, &SpeedBoostZone)>,
     mut tops: Query<(&Transform, &TopEffectiveStats, &mut SpeedBoostEffect), With<Top>>,
 ) {
     let now = time.elapsed_secs_f64();
@@ -862,9 +1626,8 @@ fn speed_boost_system(
         let mut best_mult = 1.0_f32;
         let mut best_dur = 0.0_f32;
 
-        for (zone_tf, zone_r, zone) in &zones {
-            let zone_pos = zone_tf.translation.truncate();
-            if top_pos.distance(zone_pos) < top_radius + zone_r.0 {
+        for (zone_tf, area, zone) in &zones {
+            if area.overlaps(zone_tf.translation.truncate(), top_pos, top_radius) {
                 in_zone = true;
                 best_mult = best_mult.max(zone.multiplier);
                 best_dur = best_dur.max(zone.duration);
@@ -873,7 +1636,7 @@ fn speed_boost_system(
 
         if in_zone {
             if effect.multiplier <= 1.0 {
-                info!("SpeedBoost ACTIVATED: multiplier={:.2}, duration={:.1}s", best_mult, best_dur);
+                log.info(format_args!("SpeedBoost ACTIVATED: multiplier={:.2}, duration={:.1}s", best_mult, best_dur));
             }
             effect.expires_at = now + best_dur as f64;
             effect.multiplier = best_mult;
@@ -897,7 +1660,8 @@ fn speed_boost_tick(
 /// Damage boost: tops overlapping a DamageBoostZone get a damage multiplier.
 /// Mutates the always-present DamageBoostActive directly (no deferred Commands).
 fn damage_boost_system(
-    zones: Query<(&Transform, &CollisionRadius, &DamageBoostZone)>,
+    log: Res<DebugLogging>,
+    zones: Query<(&Transform, &ZoneArea, &DamageBoostZone)>,
     mut tops: Query<(&Transform, &TopEffectiveStats, &mut DamageBoostActive), With<Top>>,
 ) {
     for (top_tf, top_stats, mut boost) in &mut tops {
@@ -906,9 +1670,8 @@ fn damage_boost_system(
         let mut in_zone = false;
         let mut best_mult = 1.0_f32;
 
-        for (zone_tf, zone_r, zone) in &zones {
-            let zone_pos = zone_tf.translation.truncate();
-            if top_pos.distance(zone_pos) < top_radius + zone_r.0 {
+        for (zone_tf, area, zone) in &zones {
+            if area.overlaps(zone_tf.translation.truncate(), top_pos, top_radius) {
                 in_zone = true;
                 best_mult = best_mult.max(zone.multiplier);
             }
@@ -916,7 +1679,7 @@ fn damage_boost_system(
 
         if in_zone {
             if boost.multiplier <= 1.0 {
-                info!("DamageBoost ACTIVATED: multiplier={:.2}", best_mult);
+                log.info(format_args!("DamageBoost ACTIVATED: multiplier={:.2}", best_mult));
             }
             boost.multiplier = best_mult;
         } else {
@@ -925,6 +1688,129 @@ fn damage_boost_system(
     }
 }
 
+/// Last stand is a Game Setup toggle; headless runs have no selection and skip it.
+fn last_stand_enabled(selection: Option<Res<GameSelection>>) -> bool {
+    selection.is_some_and(|s| s.last_stand)
+}
+
+/// Last stand: the first time a live top falls below `tuning.last_stand_hp_frac`
+/// of its max spin HP it gets a speed + damage buff for `last_stand_duration`.
+/// Runs after the zone systems so the damage buff survives their per-tick reset.
+fn last_stand_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    tuning: Res<Tuning>,
+    log: Res<DebugLogging>,
+    mut tops: Query<
+        (Entity, &SpinHpCurrent, &TopEffectiveStats, &mut SpeedBoostEffect, &mut DamageBoostActive, Option<&LastStandUsed>),
+        With<Top>,
+    >,
+) {
+    let now = time.elapsed_secs_f64();
+    for (entity, spin, stats, mut speed, mut damage, used) in &mut tops {
+        let expires_at = match used {
+            Some(used) => used.expires_at,
+            None => {
+                let hp = spin.0 .0;
+                if hp <= 0.0 || hp >= stats.0.spin_hp_max.0 * tuning.last_stand_hp_frac {
+                    continue;
+                }
+                let expires_at = now + tuning.last_stand_duration as f64;
+                commands.entity(entity).insert(LastStandUsed { expires_at });
+                log.info(format_args!("LastStand TRIGGERED: hp={:.1}, {:.1}s", hp, tuning.last_stand_duration));
+                expires_at
+            }
+        };
+        if now >= expires_at {
+            continue;
+        }
+        // A stronger zone boost wins; the buff is re-applied once it runs out
+        if speed.multiplier <= tuning.last_stand_speed_mult {
+            speed.multiplier = tuning.last_stand_speed_mult;
+            speed.expires_at = speed.expires_at.max(expires_at);
+        }
+        damage.multiplier = damage.multiplier.max(tuning.last_stand_damage_mult);
+    }
+}
+
+/// Weapon pickup: the first top touching an available pickup gets a weapon surge
+/// (ranged fire rate / melee damage multiplier); the pickup hides until it respawns.
+fn weapon_pickup_system(
+    time: Res<Time>,
+    log: Res<DebugLogging>,
+    mut pickups: Query<(&Transform, &CollisionRadius, &mut WeaponPickup, &mut Visibility)>,
+    mut tops: Query<(&Transform, &TopEffectiveStats, &mut WeaponSurgeEffect), With<Top>>,
+) {
+    let now = time.elapsed_secs_f64();
+
+    for (pickup_tf, pickup_r, mut pickup, mut visibility) in &mut pickups {
+        if now < pickup.ready_at {
+            continue;
+        }
+        *visibility = Visibility::Visible;
+        let pickup_pos = pickup_tf.translation.truncate();
+
+        for (top_tf, top_stats, mut surge) in &mut tops {
+            if top_tf.translation.truncate().distance(pickup_pos) < top_stats.0.radius.0 + pickup_r.0 {
+                log.info(format_args!("WeaponPickup TAKEN: multiplier={:.2}, duration={:.1}s", pickup.multiplier, pickup.duration));
+                surge.multiplier = pickup.multiplier;
+                surge.expires_at = now + pickup.duration as f64;
+                pickup.ready_at = now + pickup.respawn as f64;
+                *visibility = Visibility::Hidden;
+                break;
+            }
+        }
+    }
+}
+
+/// Repair pack: the first damaged top touching an available pack gets a `Heal`
+/// event (applied, clamped to max, in EventApplySet); the pack hides until it
+/// respawns. Full-HP tops pass over it.
+fn repair_pack_system(
+    time: Res<Time>,
+    log: Res<DebugLogging>,
+    mut packs: Query<(&Transform, &CollisionRadius, &mut RepairPack, &mut Visibility), Without<Top>>,
+    tops: Query<(Entity, &Transform, &TopEffectiveStats, &SpinHpCurrent), With<Top>>,
+    mut events: MessageWriter<GameEvent>,
+) {
+    let now = time.elapsed_secs_f64();
+
+    for (pack_tf, pack_r, mut pack, mut visibility) in &mut packs {
+        if now < pack.ready_at {
+            continue;
+        }
+        *visibility = Visibility::Visible;
+        let pack_pos = pack_tf.translation.truncate();
+
+        for (top, top_tf, top_stats, spin) in &tops {
+            let max = top_stats.0.spin_hp_max;
+            if spin.0 .0 <= 0.0 || spin.0 .0 >= max.0 {
+                continue;
+            }
+            if top_tf.translation.truncate().distance(pack_pos) < top_stats.0.radius.0 + pack_r.0 {
+                log.info(format_args!("RepairPack TAKEN: +{:.1} spin HP", pack.amount));
+                events.write(GameEvent::Heal { dst: top, amount: pack.amount });
+                pack.ready_at = now + pack.respawn as f64;
+                *visibility = Visibility::Hidden;
+                break;
+            }
+        }
+    }
+}
+
+/// Reset expired weapon surges to neutral (multiplier 1.0).
+fn weapon_surge_tick(
+    time: Res<Time>,
+    mut query: Query<&mut WeaponSurgeEffect>,
+) {
+    let now = time.elapsed_secs_f64();
+    for mut effect in &mut query {
+        if now >= effect.expires_at {
+            effect.multiplier = 1.0;
+        }
+    }
+}
+
 // ── Always-on ───────────────────────────────────────────────────────
 
 /// Reload tuning with F5.
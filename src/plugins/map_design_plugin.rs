@@ -1,11 +1,22 @@
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
+use std::borrow::Cow;
 use std::time::SystemTime;
 
+use crate::assets_map::{ambient_audio_path, copy_into_assets};
+use crate::config::tuning::Tuning;
 use crate::game::components::GamePhase;
-use crate::game::map::{is_valid_placement, MapItem, MapPlacement, MapSpec, GRID_CELL_SIZE};
+use crate::game::map::{
+    is_valid_placement, open_cell_count, ArenaTilt, ColorBlindMode, MapItem, MapOptions, MapPlacement, MapSpec, ObstaclePath, PathMode, DEFAULT_BOOST_MULTIPLIER,
+    DEFAULT_GRAVITY_RADIUS, DEFAULT_PATH_PERIOD, GRID_CELL_SIZE, MIN_OPEN_CELLS, TILT_STRENGTHS,
+};
 use crate::game::parts::registry::PartRegistry;
+use crate::plugins::design_plugin::{
+    clear_pending_delete, clipboard_line, confirm_delete_message, is_paste, next_char_boundary, prev_char_boundary,
+    spawn_confirm_dialog, ConfirmDialog, ConfirmDialogButton, DesignState, PendingDelete,
+};
+use crate::plugins::settings_plugin::UserSettings;
 use crate::plugins::storage_plugin::TokioRuntime;
 use crate::storage::sqlite_repo::SqliteRepo;
 
@@ -25,6 +36,11 @@ const COLOR_TOOL_SELECTED: Color = Color::srgba(0.15, 0.35, 0.60, 1.0);
 const COLOR_GRID_EMPTY: Color = Color::srgba(0.12, 0.12, 0.18, 1.0);
 const COLOR_GRID_INVALID: Color = Color::srgba(0.06, 0.06, 0.08, 1.0);
 const COLOR_GRID_HOVER: Color = Color::srgba(0.25, 0.25, 0.35, 1.0);
+const COLOR_GRID_SELECTED: Color = Color::srgba(0.20, 0.30, 0.45, 1.0);
+
+/// Map editor steps for tuned item parameters.
+const GRAVITY_RADIUS_STEP: f32 = 0.5;
+const BOOST_MULTIPLIER_STEP: f32 = 0.25;
 
 // ── Plugin ──────────────────────────────────────────────────────────
 
@@ -36,10 +52,10 @@ impl Plugin for MapDesignPlugin {
 
         // DesignMapHub
         app.add_systems(OnEnter(GamePhase::DesignMapHub), spawn_map_hub);
-        app.add_systems(OnExit(GamePhase::DesignMapHub), despawn::<MapScreenRoot>);
+        app.add_systems(OnExit(GamePhase::DesignMapHub), (clear_pending_delete, despawn::<MapScreenRoot>));
         app.add_systems(
             Update,
-            map_hub_system.run_if(in_state(GamePhase::DesignMapHub)),
+            (map_hub_system, map_confirm_delete_system).chain().run_if(in_state(GamePhase::DesignMapHub)),
         );
 
         // EditMap
@@ -47,7 +63,7 @@ impl Plugin for MapDesignPlugin {
         app.add_systems(OnExit(GamePhase::EditMap), despawn::<MapScreenRoot>);
         app.add_systems(
             Update,
-            (map_text_input_system, map_editor_system)
+            (map_text_input_system, map_editor_system, sync_cell_glyphs, sync_radius_overlays, sync_item_param_panel)
                 .chain()
                 .run_if(in_state(GamePhase::EditMap)),
         );
@@ -72,25 +88,74 @@ enum MapEditorButton {
     Save,
     Cancel,
     SelectTool(ToolSelection),
+    SetAmbient,
+    CycleAudioProfile,
+    ToggleWallSpikes,
+    CycleTiltDirection,
+    CycleTiltStrength,
+    TogglePreview,
+    CopySelection,
+    InvertSelection,
+    ParamDown,
+    ParamUp,
+}
+
+/// Map-level option shown on a top-bar button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MapOption {
+    AudioProfile,
+    WallSpikes,
+    TiltDirection,
+    TiltStrength,
 }
 
+#[derive(Component)]
+struct MapOptionLabel(MapOption);
+
 #[derive(Component)]
 struct GridCell {
     grid_x: i32,
     grid_y: i32,
 }
 
+/// Letter over a grid cell naming its item (high-contrast mode only).
+#[derive(Component)]
+struct CellGlyph;
+
+/// Grid root; keeps the layout so overlays can map grid cells to pixels.
+#[derive(Component)]
+struct GridContainer {
+    half_cells: i32,
+    cell_px: f32,
+}
+
+/// Translucent effect-radius circle drawn over the grid in preview mode.
 #[derive(Component)]
-struct GridContainer;
+struct RadiusOverlay;
+
+#[derive(Component)]
+struct PreviewLabel;
 
 #[derive(Component)]
 struct StatusText;
 
+#[derive(Component)]
+struct ToolDescriptionText;
+
+/// Parameter controls for the placed item picked with the Select tool.
+#[derive(Component)]
+struct ItemParamPanel;
+
+#[derive(Component)]
+struct ItemParamLabel;
+
 #[derive(Component)]
 struct MapTextInput {
     value: String,
     focused: bool,
     field_key: String,
+    /// Caret position as a byte index into `value` (always on a char boundary).
+    cursor: usize,
 }
 
 #[derive(Component)]
@@ -104,7 +169,15 @@ pub enum ToolSelection {
     GravityDevice,
     SpeedBoost,
     DamageBoost,
+    WeaponPickup,
+    RepairPack,
+    Bumper,
+    Mover,
+    Orbiter,
+    RectFill,
     Erase,
+    Select,
+    Paste,
 }
 
 impl ToolSelection {
@@ -114,7 +187,86 @@ impl ToolSelection {
             Self::GravityDevice => "Gravity",
             Self::SpeedBoost => "Speed",
             Self::DamageBoost => "Damage",
+            Self::WeaponPickup => "Weapon",
+            Self::RepairPack => "Repair",
+            Self::Bumper => "Bumper",
+            Self::Mover => "Mover",
+            Self::Orbiter => "Orbiter",
+            Self::RectFill => "Rect Fill",
             Self::Erase => "Erase",
+            Self::Select => "Select",
+            Self::Paste => "Paste",
+        }
+    }
+
+    /// What the item does once the map is played (shown under the tool palette).
+    /// Numbers come from `Tuning` and the map defaults, so they match battle.
+    fn description(self, tuning: &Tuning) -> Cow<'static, str> {
+        let text = match self {
+            Self::Obstacle => "Obstacle: solid block. Tops bounce off and take spin damage on contact.",
+            Self::GravityDevice => {
+                return format!(
+                    "Gravity: pulls tops within {} units toward its center. Click it with Select to tune.",
+                    DEFAULT_GRAVITY_RADIUS
+                ).into();
+            }
+            Self::SpeedBoost => {
+                return format!(
+                    "Speed: drag an area (click = 2x2). Tops crossing it move {}x faster for {}s. Click it with Select to tune.",
+                    DEFAULT_BOOST_MULTIPLIER, tuning.speed_boost_duration
+                ).into();
+            }
+            Self::DamageBoost => {
+                return format!(
+                    "Damage: drag an area (click = 2x2). Tops standing on it deal {}x damage. Click it with Select to tune.",
+                    DEFAULT_BOOST_MULTIPLIER
+                ).into();
+            }
+            Self::WeaponPickup => {
+                return format!(
+                    "Weapon: pickup. {}x fire rate / melee damage for {}s, respawns after {}s.",
+                    tuning.weapon_pickup_multiplier, tuning.weapon_pickup_duration, tuning.weapon_pickup_respawn
+                ).into();
+            }
+            Self::RepairPack => {
+                return format!(
+                    "Repair: pickup. Restores {} spin HP to a damaged top, respawns after {}s.",
+                    tuning.repair_pack_amount, tuning.repair_pack_respawn
+                ).into();
+            }
+            Self::Bumper => {
+                return format!("Bumper: pinball block. Tops bounce off {}x faster and take no damage.", tuning.bumper_gain).into();
+            }
+            Self::Mover => {
+                return format!("Mover: drag start to end. An obstacle sliding back and forth every {}s.", DEFAULT_PATH_PERIOD).into();
+            }
+            Self::Orbiter => {
+                return format!("Orbiter: drag across the circle. An obstacle circling it every {}s.", DEFAULT_PATH_PERIOD).into();
+            }
+            Self::RectFill => "Rect Fill: click one corner, then the opposite one (or drag), to fill the rectangle with the last single-cell item used.",
+            Self::Erase => "Erase: click or drag to remove the items under the cursor.",
+            Self::Select => "Select: drag a region, then Copy (Invert selects everything outside it). Click a gravity device or boost zone to tune it.",
+            Self::Paste => "Paste: click to stamp the copied items with their top-left at the cell.",
+        };
+        text.into()
+    }
+
+    /// Drag tools draw a rectangle (one zone, or a selection) or a path.
+    fn is_drag(self) -> bool {
+        matches!(self, Self::SpeedBoost | Self::DamageBoost | Self::Select) || self.path_mode().is_some()
+    }
+
+    /// Paint tools (single-cell items and Erase) apply to every cell dragged over.
+    fn is_paint(self) -> bool {
+        !self.is_drag() && !matches!(self, Self::Paste | Self::RectFill)
+    }
+
+    /// Path tools drag from a moving obstacle's start cell to its end cell.
+    fn path_mode(self) -> Option<PathMode> {
+        match self {
+            Self::Mover => Some(PathMode::PingPong),
+            Self::Orbiter => Some(PathMode::Circle),
+            _ => None,
         }
     }
 
@@ -124,7 +276,11 @@ impl ToolSelection {
             Self::GravityDevice => Some(MapItem::GravityDevice),
             Self::SpeedBoost => Some(MapItem::SpeedBoost),
             Self::DamageBoost => Some(MapItem::DamageBoost),
-            Self::Erase => None,
+            Self::WeaponPickup => Some(MapItem::WeaponPickup),
+            Self::RepairPack => Some(MapItem::RepairPack),
+            Self::Bumper => Some(MapItem::Bumper),
+            Self::Mover | Self::Orbiter => Some(MapItem::MovingObstacle),
+            Self::RectFill | Self::Erase | Self::Select | Self::Paste => None,
         }
     }
 }
@@ -135,6 +291,52 @@ pub struct MapDesignState {
     pub current_spec: MapSpec,
     pub selected_tool: ToolSelection,
     pub delete_error: Option<String>,
+    /// First cell of an area-tool drag in progress.
+    pub area_anchor: Option<(i32, i32)>,
+    /// Cell under the cursor during an area-tool drag.
+    pub area_cursor: (i32, i32),
+    /// Arena preview: overlay each device/zone's in-battle effect radius.
+    pub preview_radii: bool,
+    /// Region picked with the Select tool.
+    pub selection: Option<GridRect>,
+    /// Selection covers everything outside `selection` instead.
+    pub selection_inverted: bool,
+    /// Copied placements, relative to the selection's top-left cell.
+    pub clipboard: Vec<MapPlacement>,
+    /// Cell of the tunable item clicked with the Select tool.
+    pub selected_item: Option<(i32, i32)>,
+    /// Last cell painted while a paint-tool drag is in progress.
+    pub paint_last: Option<(i32, i32)>,
+    /// First corner picked with Rect Fill, until the second one is clicked.
+    pub fill_anchor: Option<(i32, i32)>,
+    /// Item Rect Fill places: the last single-cell item tool selected.
+    pub fill_item: MapItem,
+    /// Radius whose placement pruning was already shown; Save again commits it.
+    pub confirm_prune: Option<f32>,
+}
+
+/// Inclusive rectangle of grid cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridRect {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+impl GridRect {
+    pub fn from_corners(a: (i32, i32), b: (i32, i32)) -> Self {
+        Self {
+            min_x: a.0.min(b.0),
+            min_y: a.1.min(b.1),
+            max_x: a.0.max(b.0),
+            max_y: a.1.max(b.1),
+        }
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
 }
 
 impl Default for MapDesignState {
@@ -144,6 +346,17 @@ impl Default for MapDesignState {
             current_spec: MapSpec::default_arena(),
             selected_tool: ToolSelection::Obstacle,
             delete_error: None,
+            area_anchor: None,
+            area_cursor: (0, 0),
+            preview_radii: false,
+            selection: None,
+            selection_inverted: false,
+            clipboard: Vec::new(),
+            selected_item: None,
+            paint_last: None,
+            fill_anchor: None,
+            fill_item: MapItem::Obstacle,
+            confirm_prune: None,
         }
     }
 }
@@ -156,16 +369,18 @@ fn despawn<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>)
     }
 }
 
-fn gen_custom_id() -> String {
-    let nanos = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos();
-    format!("map_{:08x}", nanos)
-}
-
-fn is_builtin_map(id: &str) -> bool {
-    id == "default_arena"
+/// Fresh map id that nothing in `registry` uses yet.
+fn gen_custom_id(registry: &PartRegistry) -> String {
+    loop {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos();
+        let id = format!("map_{:08x}", nanos);
+        if !registry.id_in_use(&id) {
+            return id;
+        }
+    }
 }
 
 fn spawn_button<C: Component>(parent: &mut ChildSpawnerCommands, label: &str, marker: C) {
@@ -232,6 +447,7 @@ fn spawn_map_hub(
     mut commands: Commands,
     registry: Res<PartRegistry>,
     asset_server: Res<AssetServer>,
+    settings: Res<UserSettings>,
     mut state: ResMut<MapDesignState>,
 ) {
     let edit_icon: Handle<Image> = asset_server.load("ui/edit.png");
@@ -350,7 +566,10 @@ fn spawn_map_hub(
                                         ..default()
                                     },
                                     BackgroundColor(Color::srgba(0.15, 0.15, 0.2, 1.0)),
-                                ));
+                                ))
+                                .with_children(|circle| {
+                                    spawn_preview_items(circle, map, 80.0, settings.color_blind);
+                                });
 
                                 // Name
                                 card.spawn((
@@ -373,7 +592,7 @@ fn spawn_map_hub(
                                 ));
 
                                 // Buttons row
-                                if is_builtin_map(&map.id) {
+                                if map.is_builtin() {
                                     card.spawn((
                                         Text::new("(built-in)"),
                                         TextFont {
@@ -428,25 +647,28 @@ fn spawn_map_hub(
 }
 
 fn map_hub_system(
+    mut commands: Commands,
     mut q: Query<(&Interaction, &MapHubButton, &mut BackgroundColor), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GamePhase>>,
     mut state: ResMut<MapDesignState>,
-    mut registry: ResMut<PartRegistry>,
-    repo: Option<Res<SqliteRepo>>,
-    rt: Option<Res<TokioRuntime>>,
+    mut design: ResMut<DesignState>,
+    registry: Res<PartRegistry>,
 ) {
     for (interaction, button, mut bg) in &mut q {
         match button {
             MapHubButton::NewMap => match *interaction {
                 Interaction::Pressed => {
-                    let id = gen_custom_id();
+                    let id = gen_custom_id(&registry);
                     state.editing_map_id = Some(id.clone());
                     state.current_spec = MapSpec {
                         id,
                         name: "New Map".into(),
                         arena_radius: 12.0,
                         placements: vec![],
+                        options: Default::default(),
                     };
+                    state.selected_item = None;
+                    state.confirm_prune = None;
                     next_state.set(GamePhase::EditMap);
                 }
                 Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
@@ -457,6 +679,8 @@ fn map_hub_system(
                     if let Some(map) = registry.maps.get(id) {
                         state.editing_map_id = Some(id.clone());
                         state.current_spec = map.clone();
+                        state.selected_item = None;
+                        state.confirm_prune = None;
                         next_state.set(GamePhase::EditMap);
                     }
                 }
@@ -465,11 +689,9 @@ fn map_hub_system(
             },
             MapHubButton::DeleteMap(id) => match *interaction {
                 Interaction::Pressed => {
-                    if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
-                        let _ = repo.delete_map_sync(&rt.0, id);
-                        registry.maps.remove(id);
-                    }
-                    next_state.set(GamePhase::DesignMapHub);
+                    let name = registry.maps.get(id).map_or(id.as_str(), |m| m.name.as_str());
+                    spawn_confirm_dialog(&mut commands, MapScreenRoot, &confirm_delete_message(name));
+                    design.pending_delete = Some(PendingDelete::Map(id.clone()));
                 }
                 Interaction::Hovered => *bg = BackgroundColor(Color::srgba(0.4, 0.4, 0.5, 0.3)),
                 Interaction::None => *bg = BackgroundColor(Color::NONE),
@@ -485,6 +707,42 @@ fn map_hub_system(
     }
 }
 
+/// Map hub dialog: Confirm deletes the pending map from the DB and registry
+/// and re-enters the hub; Cancel just closes.
+fn map_confirm_delete_system(
+    mut commands: Commands,
+    mut q: Query<(&Interaction, &ConfirmDialogButton, &mut BackgroundColor), Changed<Interaction>>,
+    dialogs: Query<Entity, With<ConfirmDialog>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    mut design: ResMut<DesignState>,
+    mut registry: ResMut<PartRegistry>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        match *interaction {
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            Interaction::Pressed => {}
+        }
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let pending = design.pending_delete.take();
+        let Some(PendingDelete::Map(id)) = pending.filter(|_| matches!(button, ConfirmDialogButton::Confirm)) else {
+            for entity in &dialogs {
+                commands.entity(entity).despawn();
+            }
+            continue;
+        };
+        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+            let _ = repo.delete_map_sync(&rt.0, &id);
+            registry.maps.remove(&id);
+        }
+        next_state.set(GamePhase::DesignMapHub);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // MAP EDITOR (EditMap)
 // ═══════════════════════════════════════════════════════════════════════
@@ -492,6 +750,8 @@ fn map_hub_system(
 fn spawn_map_editor(
     mut commands: Commands,
     state: Res<MapDesignState>,
+    settings: Res<UserSettings>,
+    tuning: Res<Tuning>,
 ) {
     let spec = &state.current_spec;
     let half_cells = (spec.arena_radius / GRID_CELL_SIZE).ceil() as i32;
@@ -544,6 +804,40 @@ fn spawn_map_editor(
                     ));
                     spawn_text_input(bar, "radius", &format!("{}", spec.arena_radius));
 
+                    // Audio
+                    spawn_button(bar, "Ambient...", MapEditorButton::SetAmbient);
+                    spawn_option_button(bar, MapEditorButton::CycleAudioProfile, MapOption::AudioProfile, &spec.options);
+
+                    // Hazards
+                    spawn_option_button(bar, MapEditorButton::ToggleWallSpikes, MapOption::WallSpikes, &spec.options);
+                    spawn_option_button(bar, MapEditorButton::CycleTiltDirection, MapOption::TiltDirection, &spec.options);
+                    spawn_option_button(bar, MapEditorButton::CycleTiltStrength, MapOption::TiltStrength, &spec.options);
+
+                    // Effect radius preview
+                    bar.spawn((
+                        MapEditorButton::TogglePreview,
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border_radius: BorderRadius::all(Val::Px(6.0)),
+                            ..default()
+                        },
+                        BackgroundColor(if state.preview_radii { COLOR_TOOL_SELECTED } else { COLOR_BTN }),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            PreviewLabel,
+                            Text::new(preview_label(state.preview_radii)),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(COLOR_TEXT),
+                        ));
+                    });
+
                     // Save / Cancel
                     spawn_button(bar, "Save", MapEditorButton::Save);
                     spawn_button(bar, "Cancel", MapEditorButton::Cancel);
@@ -585,7 +879,15 @@ fn spawn_map_editor(
                                 ToolSelection::GravityDevice,
                                 ToolSelection::SpeedBoost,
                                 ToolSelection::DamageBoost,
+                                ToolSelection::WeaponPickup,
+                                ToolSelection::RepairPack,
+                                ToolSelection::Bumper,
+                                ToolSelection::Mover,
+                                ToolSelection::Orbiter,
+                                ToolSelection::RectFill,
                                 ToolSelection::Erase,
+                                ToolSelection::Select,
+                                ToolSelection::Paste,
                             ];
                             for tool in tool_items {
                                 let is_selected = state.selected_tool == tool;
@@ -619,6 +921,113 @@ fn spawn_map_editor(
                                         ));
                                     });
                             }
+
+                            // Selection actions
+                            tools
+                                .spawn(Node {
+                                    column_gap: Val::Px(6.0),
+                                    ..default()
+                                })
+                                .with_children(|row| {
+                                    for (label, marker) in [
+                                        ("Copy", MapEditorButton::CopySelection),
+                                        ("Invert", MapEditorButton::InvertSelection),
+                                    ] {
+                                        row.spawn((
+                                            marker,
+                                            Button,
+                                            Node {
+                                                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                                                justify_content: JustifyContent::Center,
+                                                border_radius: BorderRadius::all(Val::Px(4.0)),
+                                                ..default()
+                                            },
+                                            BackgroundColor(COLOR_BTN),
+                                        ))
+                                        .with_children(|btn| {
+                                            btn.spawn((
+                                                Text::new(label),
+                                                TextFont {
+                                                    font_size: 13.0,
+                                                    ..default()
+                                                },
+                                                TextColor(COLOR_TEXT),
+                                            ));
+                                        });
+                                    }
+                                });
+
+                            tools.spawn((
+                                ToolDescriptionText,
+                                Text::new(state.selected_tool.description(&tuning)),
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(COLOR_TEXT_DIM),
+                                Node {
+                                    width: Val::Px(160.0),
+                                    margin: UiRect::top(Val::Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+
+                            // Selected item parameters
+                            tools
+                                .spawn((
+                                    ItemParamPanel,
+                                    Node {
+                                        flex_direction: FlexDirection::Column,
+                                        row_gap: Val::Px(6.0),
+                                        margin: UiRect::top(Val::Px(8.0)),
+                                        display: Display::None,
+                                        ..default()
+                                    },
+                                ))
+                                .with_children(|panel| {
+                                    panel.spawn((
+                                        ItemParamLabel,
+                                        Text::new(""),
+                                        TextFont {
+                                            font_size: 14.0,
+                                            ..default()
+                                        },
+                                        TextColor(COLOR_ACCENT),
+                                    ));
+                                    panel
+                                        .spawn(Node {
+                                            column_gap: Val::Px(6.0),
+                                            ..default()
+                                        })
+                                        .with_children(|row| {
+                                            for (label, marker) in [
+                                                ("-", MapEditorButton::ParamDown),
+                                                ("+", MapEditorButton::ParamUp),
+                                            ] {
+                                                row.spawn((
+                                                    marker,
+                                                    Button,
+                                                    Node {
+                                                        padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                                                        justify_content: JustifyContent::Center,
+                                                        border_radius: BorderRadius::all(Val::Px(4.0)),
+                                                        ..default()
+                                                    },
+                                                    BackgroundColor(COLOR_BTN),
+                                                ))
+                                                .with_children(|btn| {
+                                                    btn.spawn((
+                                                        Text::new(label),
+                                                        TextFont {
+                                                            font_size: 15.0,
+                                                            ..default()
+                                                        },
+                                                        TextColor(COLOR_TEXT),
+                                                    ));
+                                                });
+                                            }
+                                        });
+                                });
                         });
 
                     // ── Grid area ──
@@ -634,14 +1043,14 @@ fn spawn_map_editor(
                         .with_children(|center| {
                             center
                                 .spawn((
-                                    GridContainer,
+                                    GridContainer { half_cells, cell_px },
                                     Node {
                                         flex_direction: FlexDirection::Column,
                                         ..default()
                                     },
                                 ))
                                 .with_children(|grid| {
-                                    spawn_grid_cells(grid, spec, half_cells, cell_px);
+                                    spawn_grid_cells(grid, spec, half_cells, cell_px, settings.color_blind);
                                 });
                         });
                 });
@@ -656,7 +1065,7 @@ fn spawn_map_editor(
                     bar.spawn((
                         StatusText,
                         Text::new(format!(
-                            "Tool: {} | Grid: {}x{} | Click to place/remove",
+                            "Tool: {} | Grid: {}x{} | Click or drag to place/remove",
                             state.selected_tool.display_name(),
                             grid_dim,
                             grid_dim
@@ -676,6 +1085,7 @@ fn spawn_grid_cells(
     spec: &MapSpec,
     half_cells: i32,
     cell_px: f32,
+    mode: ColorBlindMode,
 ) {
     // Iterate Y from +half_cells down to -half_cells so top of UI = +Y in game world
     for gy in ((-half_cells)..=half_cells).rev() {
@@ -689,11 +1099,11 @@ fn spawn_grid_cells(
                 let placed_item = spec
                     .placements
                     .iter()
-                    .find(|p| p.grid_x == gx && p.grid_y == gy)
+                    .find(|p| p.covers(gx, gy))
                     .map(|p| p.item);
 
                 let cell_color = if let Some(item) = placed_item {
-                    item.color()
+                    item.display_color(mode)
                 } else if valid {
                     COLOR_GRID_EMPTY
                 } else {
@@ -710,10 +1120,22 @@ fn spawn_grid_cells(
                         width: Val::Px(cell_px),
                         height: Val::Px(cell_px),
                         margin: UiRect::all(Val::Px(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
                         ..default()
                     },
                     BackgroundColor(cell_color),
-                ));
+                ))
+                .with_children(|cell| {
+                    let glyph = placed_item.filter(|_| mode.glyphs());
+                    cell.spawn((
+                        CellGlyph,
+                        Pickable::IGNORE,
+                        Text::new(glyph.map_or("", MapItem::glyph)),
+                        TextFont { font_size: (cell_px * 0.8).max(6.0), ..default() },
+                        TextColor(glyph.map_or(Color::BLACK, |item| glyph_color(item.display_color(mode)))),
+                    ));
+                });
             }
         });
     }
@@ -726,6 +1148,7 @@ fn spawn_text_input(parent: &mut ChildSpawnerCommands, key: &str, default_value:
                 value: default_value.to_string(),
                 focused: false,
                 field_key: key.to_string(),
+                cursor: default_value.len(),
             },
             Button,
             Node {
@@ -758,6 +1181,7 @@ fn map_text_input_system(
     )>,
     mut displays: Query<&mut Text, With<MapTextInputDisplay>>,
     mut keyboard_events: MessageReader<KeyboardInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     // Focus on click
     for (interaction, mut input, mut bg, _) in &mut inputs {
@@ -767,52 +1191,78 @@ fn map_text_input_system(
         }
     }
 
+    // Unfocus all others when one is clicked
+    let any_clicked = inputs
+        .iter()
+        .any(|(i, _, _, _)| *i == Interaction::Pressed);
+    if any_clicked {
+        for (interaction, mut input, _, _) in &mut inputs {
+            if *interaction != Interaction::Pressed {
+                input.focused = false;
+            }
+        }
+    }
+
     let events: Vec<_> = keyboard_events.read().cloned().collect();
 
     for (_interaction, mut input, mut bg, children) in &mut inputs {
-        if !input.focused {
-            *bg = BackgroundColor(COLOR_INPUT_BG);
-            continue;
-        }
-        *bg = BackgroundColor(COLOR_INPUT_FOCUS);
-
-        for event in &events {
-            if !event.state.is_pressed() {
-                continue;
-            }
-            match &event.logical_key {
-                Key::Backspace => {
-                    input.value.pop();
+        if input.focused {
+            *bg = BackgroundColor(COLOR_INPUT_FOCUS);
+            let input = &mut *input;
+            input.cursor = input.cursor.min(input.value.len());
+            for event in &events {
+                if !event.state.is_pressed() {
+                    continue;
                 }
-                Key::Escape | Key::Enter => {
-                    input.focused = false;
+                if is_paste(&event.logical_key, &keyboard) {
+                    if let Some((line, ended)) = clipboard_line() {
+                        input.value.insert_str(input.cursor, &line);
+                        input.cursor += line.len();
+                        if ended {
+                            input.focused = false;
+                        }
+                    }
+                    continue;
                 }
-                Key::Character(c) => {
-                    input.value.push_str(c.as_str());
+                match &event.logical_key {
+                    Key::Backspace => {
+                        if input.cursor > 0 {
+                            let prev = prev_char_boundary(&input.value, input.cursor);
+                            input.value.replace_range(prev..input.cursor, "");
+                            input.cursor = prev;
+                        }
+                    }
+                    Key::ArrowLeft => input.cursor = prev_char_boundary(&input.value, input.cursor),
+                    Key::ArrowRight => input.cursor = next_char_boundary(&input.value, input.cursor),
+                    Key::Home => input.cursor = 0,
+                    Key::End => input.cursor = input.value.len(),
+                    Key::Escape | Key::Enter => {
+                        input.focused = false;
+                    }
+                    Key::Character(c) => {
+                        input.value.insert_str(input.cursor, c.as_str());
+                        input.cursor += c.len();
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
+        } else {
+            *bg = BackgroundColor(COLOR_INPUT_BG);
         }
 
+        // Caret while focused; only write when changed to avoid relayout every frame
+        let shown = if input.focused {
+            format!("{}|{}", &input.value[..input.cursor], &input.value[input.cursor..])
+        } else if input.value.is_empty() {
+            "...".into()
+        } else {
+            input.value.clone()
+        };
         for child in children.iter() {
             if let Ok(mut text) = displays.get_mut(child) {
-                **text = if input.value.is_empty() {
-                    "...".into()
-                } else {
-                    input.value.clone()
-                };
-            }
-        }
-    }
-
-    // Unfocus all others when one is clicked
-    let any_clicked = inputs
-        .iter()
-        .any(|(i, _, _, _)| *i == Interaction::Pressed);
-    if any_clicked {
-        for (interaction, mut input, _, _) in &mut inputs {
-            if *interaction != Interaction::Pressed {
-                input.focused = false;
+                if **text != shown {
+                    **text = shown.clone();
+                }
             }
         }
     }
@@ -840,98 +1290,249 @@ fn map_editor_system(
         Without<GridCell>,
     >,
     inputs: Query<(&Interaction, &mut MapTextInput, &mut BackgroundColor, &Children), (Without<GridCell>, Without<MapEditorButton>)>,
-    mut status_q: Query<&mut Text, With<StatusText>>,
+    mut status_q: Query<&mut Text, (With<StatusText>, Without<ToolDescriptionText>, Without<MapOptionLabel>)>,
+    mut desc_q: Query<&mut Text, (With<ToolDescriptionText>, Without<StatusText>, Without<MapOptionLabel>)>,
+    mut option_labels: Query<(&mut Text, &MapOptionLabel), (Without<StatusText>, Without<ToolDescriptionText>)>,
+    mut preview_q: Query<&mut Text, (With<PreviewLabel>, Without<StatusText>, Without<ToolDescriptionText>, Without<MapOptionLabel>)>,
     mut state: ResMut<MapDesignState>,
     mut next_state: ResMut<NextState<GamePhase>>,
     mut registry: ResMut<PartRegistry>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    settings: Res<UserSettings>,
+    tuning: Res<Tuning>,
     repo: Option<Res<SqliteRepo>>,
     rt: Option<Res<TokioRuntime>>,
 ) {
+    let mode = settings.color_blind;
+    // Drag tools: rectangle from the pressed cell to the hovered one
+    let drag_rect = state
+        .area_anchor
+        .or(state.fill_anchor)
+        .map(|anchor| GridRect::from_corners(anchor, state.area_cursor));
+    let drag_path = state.selected_tool.path_mode().and(state.area_anchor).map(|anchor| (anchor, state.area_cursor));
+    let selection = state.selection;
+    let inverted = state.selection_inverted;
+
     // Handle grid cell clicks
     for (interaction, cell, mut bg) in &mut grid_q {
         let valid = is_valid_placement(cell.grid_x, cell.grid_y, state.current_spec.arena_radius);
 
+        let here = (cell.grid_x, cell.grid_y);
+        // Paint tools: the pressed cell starts the stroke (it stays Pressed for
+        // the whole drag), every cell entered while held continues it
+        if state.selected_tool.is_paint() {
+            let cells = match (*interaction, state.paint_last) {
+                (Interaction::Pressed, None) => vec![here],
+                (Interaction::Hovered, Some(last)) if last != here && mouse.pressed(MouseButton::Left) => {
+                    grid_line(last, here)
+                }
+                _ => Vec::new(),
+            };
+            if let Some(&end) = cells.last() {
+                state.paint_last = Some(end);
+            }
+            let item = state.selected_tool.to_map_item();
+            for (x, y) in cells {
+                if let Some(color) = paint_cell(&mut state, x, y, item, mode) {
+                    if (x, y) == here {
+                        *bg = BackgroundColor(color);
+                    }
+                }
+            }
+            if *interaction == Interaction::Pressed {
+                continue;
+            }
+        }
+
         match *interaction {
             Interaction::Pressed => {
                 if !valid {
                     continue;
                 }
 
-                if let Some(item) = state.selected_tool.to_map_item() {
-                    // SpeedBoost and DamageBoost stamp a 2×2 block;
-                    // other tools place a single cell.
-                    let offsets: &[(i32, i32)] = match item {
-                        MapItem::SpeedBoost | MapItem::DamageBoost => {
-                            &[(0, 0), (1, 0), (0, -1), (1, -1)]
+                if state.selected_tool.is_drag() {
+                    // The anchor cell stays Pressed for the whole drag; only the
+                    // first press sets it, hovered cells move the cursor
+                    if state.area_anchor.is_none() {
+                        state.area_anchor = Some((cell.grid_x, cell.grid_y));
+                        state.area_cursor = (cell.grid_x, cell.grid_y);
+                    }
+                } else if state.selected_tool == ToolSelection::RectFill {
+                    // First click sets the anchor, the second fills
+                    if mouse.just_pressed(MouseButton::Left) {
+                        match state.fill_anchor.take() {
+                            Some(anchor) => {
+                                let msg = fill_rect(&mut state, GridRect::from_corners(anchor, here), mode);
+                                if let Ok(mut status) = status_q.single_mut() {
+                                    **status = msg;
+                                }
+                            }
+                            None => {
+                                state.fill_anchor = Some(here);
+                                state.area_cursor = here;
+                            }
                         }
-                        _ => &[(0, 0)],
-                    };
-
-                    for &(dx, dy) in offsets {
-                        let nx = cell.grid_x + dx;
-                        let ny = cell.grid_y + dy;
-                        // Remove whatever was at each target cell
-                        state.current_spec.placements.retain(|p| p.grid_x != nx || p.grid_y != ny);
-                        // Only place if the cell is inside the arena
-                        if is_valid_placement(nx, ny, state.current_spec.arena_radius) {
-                            state.current_spec.placements.push(MapPlacement {
-                                grid_x: nx,
-                                grid_y: ny,
-                                item,
-                            });
+                    }
+                } else if state.selected_tool == ToolSelection::Paste {
+                    if mouse.just_pressed(MouseButton::Left) {
+                        let msg = paste_clipboard(&mut state, cell.grid_x, cell.grid_y);
+                        if let Ok(mut status) = status_q.single_mut() {
+                            **status = msg;
                         }
                     }
-                    *bg = BackgroundColor(item.color());
-                } else {
-                    // Erase: remove just the clicked cell
-                    state.current_spec.placements.retain(|p| {
-                        p.grid_x != cell.grid_x || p.grid_y != cell.grid_y
-                    });
-                    *bg = BackgroundColor(COLOR_GRID_EMPTY);
                 }
             }
-            Interaction::Hovered => {
-                if valid {
-                    // Only change hover if not already colored by placement
-                    let has_placement = state
-                        .current_spec
-                        .placements
-                        .iter()
-                        .any(|p| p.grid_x == cell.grid_x && p.grid_y == cell.grid_y);
-                    if !has_placement {
-                        *bg = BackgroundColor(COLOR_GRID_HOVER);
-                    }
+            Interaction::Hovered | Interaction::None => {
+                if *interaction == Interaction::Hovered && (state.area_anchor.is_some() || state.fill_anchor.is_some()) {
+                    state.area_cursor = here;
                 }
-            }
-            Interaction::None => {
+                let in_drag = match drag_path {
+                    Some((start, end)) => here == start || here == end,
+                    None => drag_rect.is_some_and(|r| r.contains(cell.grid_x, cell.grid_y)),
+                };
+                // Path ends of moving obstacles are drawn dimmed
+                let path_end = state
+                    .current_spec
+                    .placements
+                    .iter()
+                    .any(|p| p.path.is_some_and(|path| (path.end_x, path.end_y) == here));
+                let selected = valid
+                    && selection.is_some_and(|r| r.contains(cell.grid_x, cell.grid_y) != inverted);
                 let placed = state
                     .current_spec
                     .placements
                     .iter()
-                    .find(|p| p.grid_x == cell.grid_x && p.grid_y == cell.grid_y)
+                    .find(|p| p.covers(cell.grid_x, cell.grid_y))
                     .map(|p| p.item);
-                let color = if let Some(item) = placed {
-                    item.color()
-                } else if valid {
-                    COLOR_GRID_EMPTY
-                } else {
+                let color = if in_drag {
+                    COLOR_GRID_HOVER
+                } else if let Some(item) = placed {
+                    let color = item.display_color(mode);
+                    if selected { color.lighter(0.15) } else { color }
+                } else if path_end {
+                    MapItem::MovingObstacle.display_color(mode).darker(0.25)
+                } else if selected {
+                    COLOR_GRID_SELECTED
+                } else if !valid {
                     COLOR_GRID_INVALID
+                } else if *interaction == Interaction::Hovered {
+                    COLOR_GRID_HOVER
+                } else {
+                    COLOR_GRID_EMPTY
                 };
                 *bg = BackgroundColor(color);
             }
         }
     }
 
-    // Handle editor buttons
+    // Commit the drag on release: a selection, or an area zone (a plain click places 2×2)
+    if mouse.just_released(MouseButton::Left) {
+        state.paint_last = None;
+        // Rect Fill also fills on release after dragging to a second corner
+        if let Some(anchor) = state.fill_anchor.filter(|anchor| *anchor != state.area_cursor) {
+            state.fill_anchor = None;
+            let rect = GridRect::from_corners(anchor, state.area_cursor);
+            let msg = fill_rect(&mut state, rect, mode);
+            if let Ok(mut status) = status_q.single_mut() {
+                **status = msg;
+            }
+        }
+        let anchor = state.area_anchor.take();
+        if let (Some(anchor), ToolSelection::Select) = (anchor, state.selected_tool) {
+            let cursor = state.area_cursor;
+            state.selection = Some(GridRect::from_corners(anchor, cursor));
+            state.selection_inverted = false;
+            // A plain click on a gravity device or boost zone opens its parameters
+            state.selected_item = (anchor == cursor)
+                .then(|| state.current_spec.placements.iter().find(|p| p.covers(anchor.0, anchor.1)))
+                .flatten()
+                .filter(|p| item_param_label(p).is_some())
+                .map(|_| anchor);
+        } else if let (Some(anchor), Some(mode)) = (anchor, state.selected_tool.path_mode()) {
+            let (end_x, end_y) = state.area_cursor;
+            let mover = MapPlacement {
+                path: Some(ObstaclePath::new(end_x, end_y, mode)),
+                ..MapPlacement::cell(anchor.0, anchor.1, MapItem::MovingObstacle)
+            };
+            let msg = if anchor == state.area_cursor {
+                Some("Drag from the start cell to the end cell")
+            } else if !mover.is_valid(state.current_spec.arena_radius) {
+                Some("Path must stay inside the arena")
+            } else {
+                state.current_spec.placements.retain(|p| !p.overlaps(&mover));
+                state.current_spec.placements.push(mover);
+                None
+            };
+            if let (Some(msg), Ok(mut status)) = (msg, status_q.single_mut()) {
+                **status = msg.into();
+            }
+        } else if let (Some(anchor), Some(item)) = (anchor, state.selected_tool.to_map_item()) {
+            let rect = area_rect(anchor, state.area_cursor, item);
+            if rect.is_valid(state.current_spec.arena_radius) {
+                state.current_spec.placements.retain(|p| !p.overlaps(&rect));
+                state.current_spec.placements.push(rect);
+            } else if let Ok(mut status) = status_q.single_mut() {
+                **status = "Area must lie fully inside the arena".into();
+            }
+        }
+    }
+
+    // Tool description: hovered tool wins, otherwise the selected one
+    let described = btn_q
+        .iter()
+        .find_map(|(interaction, button, _)| match (interaction, button) {
+            (Interaction::Hovered, MapEditorButton::SelectTool(tool)) => Some(*tool),
+            _ => None,
+        })
+        .unwrap_or(state.selected_tool);
+    if let Ok(mut desc) = desc_q.single_mut() {
+        let description = described.description(&tuning);
+        if desc.as_str() != description {
+            **desc = description.into_owned();
+        }
+    }
+
+    // Handle editor buttons. Interaction stays Pressed while the mouse is held,
+    // so toggles only act on the click frame.
+    let clicked = mouse.just_pressed(MouseButton::Left);
     for (interaction, button, mut bg) in &mut btn_q {
         match button {
             MapEditorButton::Save => match *interaction {
                 Interaction::Pressed => {
+                    // Held presses stay Pressed; a confirming Save needs a second click
+                    if !clicked {
+                        continue;
+                    }
                     // Read name and radius from inputs
                     let name = read_input_field(&inputs, "name");
                     let radius_str = read_input_field(&inputs, "radius");
                     let radius = radius_str.parse::<f32>().unwrap_or(12.0).clamp(6.0, 24.0);
 
+                    // Refuse a map with too little room to fight in
+                    let open = open_cell_count(radius, &state.current_spec.placements);
+                    if open < MIN_OPEN_CELLS {
+                        if let Ok(mut status) = status_q.single_mut() {
+                            **status = format!("Only {} open cells at radius {} (need {}): clear obstacles or grow the arena", open, radius, MIN_OPEN_CELLS);
+                        }
+                        continue;
+                    }
+
+                    // Placements the new radius would drop: show the count, Save again commits
+                    let pruned = state.current_spec.placements.iter().filter(|p| !p.is_valid(radius)).count();
+                    if pruned > 0 && state.confirm_prune != Some(radius) {
+                        state.confirm_prune = Some(radius);
+                        if let Ok(mut status) = status_q.single_mut() {
+                            **status = format!(
+                                "Radius {} removes {} placement{} outside the arena. Save again to confirm",
+                                radius,
+                                pruned,
+                                if pruned == 1 { "" } else { "s" },
+                            );
+                        }
+                        continue;
+                    }
+                    state.confirm_prune = None;
+
                     state.current_spec.name = if name.is_empty() {
                         "Unnamed Map".into()
                     } else {
@@ -943,19 +1544,23 @@ fn map_editor_system(
                     state
                         .current_spec
                         .placements
-                        .retain(|p| is_valid_placement(p.grid_x, p.grid_y, radius));
+                        .retain(|p| p.is_valid(radius));
 
                     // Save to DB
                     if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
                         let placements_json =
                             serde_json::to_string(&state.current_spec.placements)
                                 .unwrap_or_else(|_| "[]".into());
+                        let options_json =
+                            serde_json::to_string(&state.current_spec.options)
+                                .unwrap_or_else(|_| "{}".into());
                         let _ = repo.save_map_sync(
                             &rt.0,
                             &state.current_spec.id,
                             &state.current_spec.name,
                             state.current_spec.arena_radius,
                             &placements_json,
+                            &options_json,
                         );
                     }
 
@@ -976,15 +1581,113 @@ fn map_editor_system(
                 Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
                 Interaction::None => *bg = BackgroundColor(COLOR_BTN),
             },
+            MapEditorButton::SetAmbient => match *interaction {
+                Interaction::Pressed => if clicked {
+                    if let Some(path) = pick_and_copy_ambient(&state.current_spec.id) {
+                        if let Ok(mut status) = status_q.single_mut() {
+                            **status = format!("Ambient set: {}", path);
+                        }
+                        state.current_spec.options.ambient_path = Some(path);
+                    }
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::CycleAudioProfile => match *interaction {
+                Interaction::Pressed => if clicked {
+                    let next = state.current_spec.options.audio_profile.next();
+                    state.current_spec.options.audio_profile = next;
+                    refresh_option_labels(&mut option_labels, &state.current_spec.options);
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::ToggleWallSpikes => match *interaction {
+                Interaction::Pressed => if clicked {
+                    state.current_spec.options.wall_spikes = !state.current_spec.options.wall_spikes;
+                    refresh_option_labels(&mut option_labels, &state.current_spec.options);
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::CycleTiltDirection => match *interaction {
+                Interaction::Pressed => if clicked {
+                    let options = &mut state.current_spec.options;
+                    options.tilt = next_tilt_direction(options.tilt);
+                    refresh_option_labels(&mut option_labels, &state.current_spec.options);
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::CycleTiltStrength => match *interaction {
+                Interaction::Pressed => if clicked {
+                    if let Some(tilt) = state.current_spec.options.tilt.as_mut() {
+                        let i = TILT_STRENGTHS.iter().position(|s| *s == tilt.strength).map_or(0, |i| i + 1);
+                        tilt.strength = TILT_STRENGTHS[i % TILT_STRENGTHS.len()];
+                        refresh_option_labels(&mut option_labels, &state.current_spec.options);
+                    }
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::TogglePreview => match *interaction {
+                Interaction::Pressed => if clicked {
+                    state.preview_radii = !state.preview_radii;
+                    if let Ok(mut label) = preview_q.single_mut() {
+                        **label = preview_label(state.preview_radii);
+                    }
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => {
+                    *bg = BackgroundColor(if state.preview_radii { COLOR_TOOL_SELECTED } else { COLOR_BTN });
+                }
+            },
+            MapEditorButton::CopySelection => match *interaction {
+                Interaction::Pressed => if clicked {
+                    let msg = copy_selection(&mut state);
+                    if let Ok(mut status) = status_q.single_mut() {
+                        **status = msg;
+                    }
+                },
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::InvertSelection => match *interaction {
+                Interaction::Pressed => if clicked && state.selection.is_some() {
+                    state.selection_inverted = !state.selection_inverted;
+                },
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
+            MapEditorButton::ParamDown | MapEditorButton::ParamUp => match *interaction {
+                Interaction::Pressed => if clicked {
+                    let dir = if matches!(button, MapEditorButton::ParamUp) { 1.0 } else { -1.0 };
+                    if let Some((x, y)) = state.selected_item {
+                        if let Some(p) = state.current_spec.placements.iter_mut().find(|p| p.covers(x, y)) {
+                            step_item_param(p, dir);
+                        }
+                    }
+                },
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
             MapEditorButton::SelectTool(tool) => match *interaction {
                 Interaction::Pressed => {
                     state.selected_tool = *tool;
+                    state.fill_anchor = None;
+                    if let Some(item) = tool.to_map_item().filter(|_| tool.is_paint()) {
+                        state.fill_item = item;
+                    }
                     // Update status text
                     if let Ok(mut status) = status_q.single_mut() {
-                        **status = format!(
-                            "Tool: {} | Click to place/remove",
-                            tool.display_name()
-                        );
+                        **status = if *tool == ToolSelection::RectFill {
+                            format!(
+                                "Tool: Rect Fill ({}) | Click one corner, then click the opposite corner",
+                                state.fill_item.display_name()
+                            )
+                        } else {
+                            format!("Tool: {} | Click or drag to place/remove", tool.display_name())
+                        };
                     }
                     *bg = BackgroundColor(COLOR_TOOL_SELECTED);
                 }
@@ -1004,3 +1707,382 @@ fn map_editor_system(
         }
     }
 }
+
+/// Paint one cell: place `item` (replacing whatever covers the cell), or
+/// erase when `None`. Returns the cell's new color, `None` if nothing changed.
+fn paint_cell(state: &mut MapDesignState, x: i32, y: i32, item: Option<MapItem>, mode: ColorBlindMode) -> Option<Color> {
+    if !is_valid_placement(x, y, state.current_spec.arena_radius) {
+        return None;
+    }
+    let placements = &mut state.current_spec.placements;
+    match item {
+        Some(item) => {
+            if placements.iter().any(|p| p.covers(x, y) && p.item == item && !p.is_area() && p.path.is_none()) {
+                return None;
+            }
+            placements.retain(|p| !p.covers(x, y));
+            placements.push(MapPlacement::cell(x, y, item));
+            Some(item.display_color(mode))
+        }
+        None => {
+            // Erase: remove the item (or whole area) covering the cell
+            let before = placements.len();
+            placements.retain(|p| !p.covers(x, y));
+            (placements.len() != before).then_some(COLOR_GRID_EMPTY)
+        }
+    }
+}
+
+/// Rect Fill: one `fill_item` placement per cell of `rect`, replacing what
+/// covers it. Cells outside the arena are skipped. Returns a status message.
+fn fill_rect(state: &mut MapDesignState, rect: GridRect, mode: ColorBlindMode) -> String {
+    let item = state.fill_item;
+    let (mut filled, mut skipped) = (0, 0);
+    for y in rect.min_y..=rect.max_y {
+        for x in rect.min_x..=rect.max_x {
+            if !is_valid_placement(x, y, state.current_spec.arena_radius) {
+                skipped += 1;
+            } else if paint_cell(state, x, y, Some(item), mode).is_some() {
+                filled += 1;
+            }
+        }
+    }
+    let name = item.display_name();
+    if skipped == 0 {
+        format!("Filled {} cell(s) with {}", filled, name)
+    } else {
+        format!("Filled {} cell(s) with {}, skipped {} outside the arena", filled, name, skipped)
+    }
+}
+
+/// Cells on the straight line from `a` (exclusive) to `b` (inclusive), so a
+/// fast stroke leaves no gaps between the cells seen on successive frames.
+fn grid_line(a: (i32, i32), b: (i32, i32)) -> Vec<(i32, i32)> {
+    let (dx, dy) = ((b.0 - a.0).abs(), -(b.1 - a.1).abs());
+    let (sx, sy) = ((b.0 - a.0).signum(), (b.1 - a.1).signum());
+    let (mut x, mut y, mut err) = (a.0, a.1, dx + dy);
+    let mut cells = Vec::new();
+    while (x, y) != b {
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+        cells.push((x, y));
+    }
+    cells
+}
+
+/// Rectangle spanning two dragged corners; a single-cell drag becomes 2×2.
+fn area_rect(a: (i32, i32), b: (i32, i32), item: MapItem) -> MapPlacement {
+    if a == b {
+        return MapPlacement { width: 2, height: 2, ..MapPlacement::cell(a.0, a.1, item) };
+    }
+    let r = GridRect::from_corners(a, b);
+    MapPlacement {
+        width: r.max_x - r.min_x + 1,
+        height: r.max_y - r.min_y + 1,
+        ..MapPlacement::cell(r.min_x, r.max_y, item)
+    }
+}
+
+/// Copy placements in (or, inverted, outside) the selection to the clipboard,
+/// relative to the selection's top-left cell. Returns a status message.
+fn copy_selection(state: &mut MapDesignState) -> String {
+    let Some(rect) = state.selection else {
+        return "Nothing selected: drag a region with the Select tool".into();
+    };
+    let inverted = state.selection_inverted;
+    state.clipboard = state
+        .current_spec
+        .placements
+        .iter()
+        .filter(|p| rect.contains(p.grid_x, p.grid_y) != inverted)
+        .map(|p| p.translated(-rect.min_x, -rect.max_y))
+        .collect();
+    format!("Copied {} item(s) - use the Paste tool", state.clipboard.len())
+}
+
+/// Stamp the clipboard with its top-left at (x, y). Items that would leave
+/// the arena are skipped; existing items under pasted ones are replaced.
+fn paste_clipboard(state: &mut MapDesignState, x: i32, y: i32) -> String {
+    if state.clipboard.is_empty() {
+        return "Clipboard is empty: Select a region and Copy first".into();
+    }
+    let radius = state.current_spec.arena_radius;
+    let (pasted, skipped): (Vec<_>, Vec<_>) = state
+        .clipboard
+        .iter()
+        .map(|c| c.translated(x, y))
+        .partition(|p| p.is_valid(radius));
+    let placements = &mut state.current_spec.placements;
+    for p in &pasted {
+        placements.retain(|q| !q.overlaps(p));
+    }
+    let count = pasted.len();
+    placements.extend(pasted);
+    if skipped.is_empty() {
+        format!("Pasted {} item(s)", count)
+    } else {
+        format!("Pasted {} item(s), skipped {} outside the arena", count, skipped.len())
+    }
+}
+
+/// Label for a placement's tunable parameter; `None` if the item has none.
+fn item_param_label(p: &MapPlacement) -> Option<String> {
+    match p.item {
+        MapItem::GravityDevice => p.effect_radius().map(|r| format!("Gravity radius: {:.1}", r)),
+        MapItem::SpeedBoost => Some(format!("Speed boost: {:.2}x", p.boost_multiplier())),
+        MapItem::DamageBoost => Some(format!("Damage boost: {:.2}x", p.boost_multiplier())),
+        _ => None,
+    }
+}
+
+/// Step a placement's tunable parameter up (`dir` = 1) or down (-1) within its range.
+fn step_item_param(p: &mut MapPlacement, dir: f32) {
+    match p.item {
+        MapItem::GravityDevice => {
+            let radius = p.effect_radius().unwrap_or(DEFAULT_GRAVITY_RADIUS);
+            p.params.effect_radius = Some((radius + dir * GRAVITY_RADIUS_STEP).clamp(1.0, 8.0));
+        }
+        MapItem::SpeedBoost | MapItem::DamageBoost => {
+            p.params.multiplier = Some((p.boost_multiplier() + dir * BOOST_MULTIPLIER_STEP).clamp(1.0, 3.0));
+        }
+        _ => {}
+    }
+}
+
+/// Show the parameter panel while a tunable item is selected.
+fn sync_item_param_panel(
+    state: Res<MapDesignState>,
+    mut panel: Query<&mut Node, With<ItemParamPanel>>,
+    mut label: Query<&mut Text, With<ItemParamLabel>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let text = state.selected_item.and_then(|(x, y)| {
+        state.current_spec.placements.iter().find(|p| p.covers(x, y)).and_then(item_param_label)
+    });
+    for mut node in &mut panel {
+        node.display = if text.is_some() { Display::Flex } else { Display::None };
+    }
+    if let (Some(text), Ok(mut label)) = (text, label.single_mut()) {
+        **label = text;
+    }
+}
+
+fn preview_label(on: bool) -> String {
+    format!("Preview: {}", if on { "On" } else { "Off" })
+}
+
+/// High-contrast mode: whenever the map changes, keep each grid cell's glyph
+/// (and its text color) on the item now covering it.
+fn sync_cell_glyphs(
+    state: Res<MapDesignState>,
+    settings: Res<UserSettings>,
+    cells: Query<(&GridCell, &Children)>,
+    mut glyphs: Query<(&mut Text, &mut TextColor), With<CellGlyph>>,
+) {
+    let mode = settings.color_blind;
+    if !mode.glyphs() || !state.is_changed() {
+        return;
+    }
+    for (cell, children) in &cells {
+        let placed = state
+            .current_spec
+            .placements
+            .iter()
+            .find(|p| p.covers(cell.grid_x, cell.grid_y))
+            .map(|p| p.item);
+        for child in children.iter() {
+            let Ok((mut text, mut color)) = glyphs.get_mut(child) else {
+                continue;
+            };
+            let glyph = placed.map_or("", MapItem::glyph);
+            if **text != glyph {
+                **text = glyph.to_string();
+            }
+            if let Some(item) = placed {
+                color.0 = glyph_color(item.display_color(mode));
+            }
+        }
+    }
+}
+
+/// Black or white, whichever reads better on `background`.
+fn glyph_color(background: Color) -> Color {
+    if background.luminance() > 0.35 { Color::BLACK } else { Color::WHITE }
+}
+
+/// Dots for a map's placements inside its `size`-px arena preview circle,
+/// lettered in high-contrast mode.
+fn spawn_preview_items(circle: &mut ChildSpawnerCommands, map: &MapSpec, size: f32, mode: ColorBlindMode) {
+    let scale = size * 0.5 / map.arena_radius.max(GRID_CELL_SIZE);
+    let dot = if mode.glyphs() { 10.0 } else { 5.0 };
+    for p in &map.placements {
+        let (x, y) = p.world_center();
+        let color = p.item.display_color(mode);
+        let mut node = circle.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(size * 0.5 + x * scale - dot * 0.5),
+                top: Val::Px(size * 0.5 - y * scale - dot * 0.5),
+                width: Val::Px(dot),
+                height: Val::Px(dot),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            BackgroundColor(color),
+        ));
+        if mode.glyphs() {
+            node.with_children(|dot_ui| {
+                dot_ui.spawn((
+                    Text::new(p.item.glyph()),
+                    TextFont { font_size: 8.0, ..default() },
+                    TextColor(glyph_color(color)),
+                ));
+            });
+        }
+    }
+}
+
+/// Rebuild the effect-radius circles whenever the map or preview toggle changes.
+fn sync_radius_overlays(
+    mut commands: Commands,
+    state: Res<MapDesignState>,
+    settings: Res<UserSettings>,
+    grid_q: Query<(Entity, &GridContainer)>,
+    overlays: Query<Entity, With<RadiusOverlay>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    for entity in &overlays {
+        commands.entity(entity).despawn();
+    }
+    if !state.preview_radii {
+        return;
+    }
+    let Ok((grid_entity, grid)) = grid_q.single() else {
+        return;
+    };
+
+    // Each cell is cell_px wide plus a 0.5px margin on both sides
+    let pitch = grid.cell_px + 1.0;
+    commands.entity(grid_entity).with_children(|grid_ui| {
+        for p in &state.current_spec.placements {
+            let Some(radius) = p.effect_radius() else {
+                continue;
+            };
+            // Area zones cover their footprint; everything else a circle
+            let (half_w, half_h, corner) = if p.is_area() {
+                (p.width as f32 * pitch * 0.5, p.height as f32 * pitch * 0.5, BorderRadius::ZERO)
+            } else {
+                let radius_px = radius / GRID_CELL_SIZE * pitch;
+                (radius_px, radius_px, BorderRadius::MAX)
+            };
+            let cx = (p.grid_x + grid.half_cells) as f32 * pitch + p.width as f32 * pitch * 0.5;
+            let cy = (grid.half_cells - p.grid_y) as f32 * pitch + p.height as f32 * pitch * 0.5;
+            grid_ui.spawn((
+                RadiusOverlay,
+                Pickable::IGNORE,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(cx - half_w),
+                    top: Val::Px(cy - half_h),
+                    width: Val::Px(half_w * 2.0),
+                    height: Val::Px(half_h * 2.0),
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: corner,
+                    ..default()
+                },
+                BackgroundColor(p.item.display_color(settings.color_blind).with_alpha(0.15)),
+                BorderColor::all(p.item.display_color(settings.color_blind).with_alpha(0.6)),
+            ));
+        }
+    });
+}
+
+fn map_option_label(option: MapOption, options: &MapOptions) -> String {
+    match option {
+        MapOption::AudioProfile => format!("SFX: {}", options.audio_profile.display_name()),
+        MapOption::WallSpikes => format!("Spiked Wall: {}", if options.wall_spikes { "On" } else { "Off" }),
+        MapOption::TiltDirection => match options.tilt {
+            Some(tilt) => format!("Tilt: {}", tilt.direction_name()),
+            None => "Tilt: Off".into(),
+        },
+        MapOption::TiltStrength => match options.tilt {
+            Some(tilt) => format!("Pull: {:.1}", tilt.strength),
+            None => "Pull: —".into(),
+        },
+    }
+}
+
+/// Off → E → NE → … → SE → Off, keeping the strength while tilted.
+fn next_tilt_direction(tilt: Option<ArenaTilt>) -> Option<ArenaTilt> {
+    match tilt {
+        None => Some(ArenaTilt { angle_deg: 0.0, strength: TILT_STRENGTHS[1] }),
+        Some(t) if t.angle_deg >= 315.0 => None,
+        Some(t) => Some(ArenaTilt { angle_deg: t.angle_deg + 45.0, ..t }),
+    }
+}
+
+fn refresh_option_labels(
+    labels: &mut Query<(&mut Text, &MapOptionLabel), (Without<StatusText>, Without<ToolDescriptionText>)>,
+    options: &MapOptions,
+) {
+    for (mut text, label) in labels.iter_mut() {
+        **text = map_option_label(label.0, options);
+    }
+}
+
+/// Top-bar button whose label shows the current value of a map option.
+fn spawn_option_button(
+    parent: &mut ChildSpawnerCommands,
+    marker: MapEditorButton,
+    option: MapOption,
+    options: &MapOptions,
+) {
+    parent
+        .spawn((
+            marker,
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(COLOR_BTN),
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                MapOptionLabel(option),
+                Text::new(map_option_label(option, options)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(COLOR_TEXT),
+            ));
+        });
+}
+
+/// Open a file picker for an OGG loop and copy it to `assets/audio/ambient/{map_id}.ogg`.
+/// Returns the asset-relative path on success.
+fn pick_and_copy_ambient(map_id: &str) -> Option<String> {
+    let rel = ambient_audio_path(map_id);
+    let path = rfd::FileDialog::new()
+        .add_filter("OGG Audio", &["ogg"])
+        .pick_file()?;
+    copy_into_assets(&path, &rel).ok()?;
+    Some(rel)
+}
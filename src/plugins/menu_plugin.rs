@@ -2,8 +2,16 @@ use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 
 use crate::assets_map::GameAssets;
+use crate::config::tuning::Tuning;
+use crate::game::ai::AiDifficulty;
 use crate::game::components::GamePhase;
+use crate::game::daily::{self, DailyChallenge};
+use crate::game::ghost::GhostRecording;
+use crate::game::rng::GameRng;
 use crate::game::parts::registry::PartRegistry;
+use crate::plugins::settings_plugin::UserSettings;
+use crate::plugins::storage_plugin::TokioRuntime;
+use crate::storage::sqlite_repo::SqliteRepo;
 
 // ── Data types ───────────────────────────────────────────────────────
 
@@ -11,14 +19,29 @@ use crate::game::parts::registry::PartRegistry;
 pub enum GameMode {
     PvP,
     PvAI,
+    /// Practice against the last winning run (`GhostRecording`).
+    Ghost,
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct GameSelection {
     pub mode: GameMode,
     pub map_id: String,
     pub p1_build_id: String,
     pub p2_build_id: String,
+    /// Ranked/fair play: builds over `tuning.power_budget` can't be picked.
+    /// Off = sandbox, anything goes.
+    pub fair_mode: bool,
+    /// Near-death tops get a one-time buff (`last_stand_system`).
+    pub last_stand: bool,
+    /// Both tops start back to back at the arena center, facing outward.
+    pub center_start: bool,
+    /// PvAI opponent personality (`PartRegistry::ai_profiles` key).
+    pub ai_profile_id: String,
+    /// Rounds in a series (1 = single battle); the first to a majority wins.
+    pub best_of: u32,
+    /// PvAI opponent skill; remembered in `UserSettings.ai_difficulty`.
+    pub ai_difficulty: AiDifficulty,
 }
 
 impl Default for GameSelection {
@@ -28,10 +51,21 @@ impl Default for GameSelection {
             map_id: "default_arena".into(),
             p1_build_id: "default_blaster".into(),
             p2_build_id: "default_blade".into(),
+            fair_mode: false,
+            last_stand: false,
+            center_start: false,
+            ai_profile_id: crate::game::ai::DEFAULT_AI_PROFILE_ID.into(),
+            best_of: 1,
+            ai_difficulty: AiDifficulty::default(),
         }
     }
 }
 
+/// The player's own selection, set aside while a daily challenge overrides
+/// the mode, map and opponent; `end_daily_challenge` puts it back.
+#[derive(Resource)]
+struct SelectionBeforeDaily(GameSelection);
+
 /// Tracks which player is currently picking in the PickTop screen.
 #[derive(Resource, Default)]
 pub struct PickingFor(pub u8); // 1 = P1, 2 = P2
@@ -44,26 +78,47 @@ struct MainMenuRoot;
 #[derive(Component)]
 struct SelectionRoot;
 
+/// Today's daily challenge result under the main menu button.
+#[derive(Component)]
+struct DailySummaryText;
+
 #[derive(Component)]
 struct PickerRoot;
 
 #[derive(Component)]
 struct GameOverOverlay;
 
+#[derive(Component)]
+enum GameOverButton {
+    Rematch,
+    ChangeBuilds,
+    MainMenu,
+}
+
 #[derive(Component)]
 enum MenuButton {
     StartGame,
+    DailyChallenge,
     DesignMap,
     DesignWheel,
+    Settings,
 }
 
 #[derive(Component)]
 enum SelectionButton {
     ModePvP,
     ModePvAI,
+    ModeGhost,
+    ToggleFairMode,
+    ToggleLastStand,
+    ToggleCenterStart,
+    ToggleBestOfThree,
     ChooseMap,
-    ChooseP1Top,
-    ChooseP2Top,
+    ChooseP1Build,
+    ChooseP2Build,
+    CycleAiProfile,
+    Difficulty(AiDifficulty),
+    SwapPlayers,
     StartBattle,
     Back,
 }
@@ -77,6 +132,10 @@ struct P2Section;
 #[derive(Component)]
 struct P2AiLabel;
 
+/// Wraps the AI personality and difficulty buttons (PvAI only).
+#[derive(Component)]
+struct AiProfilePanel;
+
 #[derive(Component)]
 struct P2ChoosePanel;
 
@@ -85,6 +144,12 @@ struct P2ChoosePanel;
 struct CurrentMapLabel;
 #[derive(Component)]
 struct CurrentP1BuildLabel;
+#[derive(Component)]
+struct CurrentP2BuildLabel;
+
+/// Shows why Start is blocked in fair mode (empty otherwise).
+#[derive(Component)]
+struct FairModeWarning;
 
 // Picker screen buttons
 #[derive(Component)]
@@ -102,6 +167,17 @@ struct PickerHighlight;
 #[derive(Component)]
 struct PreviewCircle;
 
+/// "Show Details" button on a build card; expands that card's `CardDetails`.
+#[derive(Component)]
+struct CardDetailsToggle {
+    build_id: String,
+    shown: bool,
+}
+
+/// Full effective stat line of a build card, hidden until toggled.
+#[derive(Component)]
+struct CardDetails(String);
+
 // ── Colors ───────────────────────────────────────────────────────────
 
 const COLOR_BG: Color = Color::srgba(0.08, 0.08, 0.12, 1.0);
@@ -115,6 +191,7 @@ const COLOR_TEXT_DIM: Color = Color::srgba(0.5, 0.5, 0.5, 1.0);
 const COLOR_ACCENT: Color = Color::srgba(0.2, 0.7, 1.0, 1.0);
 const COLOR_CARD: Color = Color::srgba(0.12, 0.14, 0.20, 1.0);
 const COLOR_CARD_SELECTED: Color = Color::srgba(0.15, 0.35, 0.60, 1.0);
+const COLOR_WARN: Color = Color::srgba(1.0, 0.45, 0.35, 1.0);
 
 // ── Plugin ───────────────────────────────────────────────────────────
 
@@ -126,12 +203,15 @@ impl Plugin for MenuPlugin {
         app.init_resource::<PickingFor>();
 
         // Main menu
-        app.add_systems(OnEnter(GamePhase::MainMenu), spawn_main_menu);
+        app.add_systems(OnEnter(GamePhase::MainMenu), (spawn_main_menu, end_daily_challenge));
         app.add_systems(OnExit(GamePhase::MainMenu), despawn::<MainMenuRoot>);
-        app.add_systems(Update, menu_button_system.run_if(in_state(GamePhase::MainMenu)));
+        app.add_systems(
+            Update,
+            (menu_button_system, update_daily_summary).run_if(in_state(GamePhase::MainMenu)),
+        );
 
         // Selection hub
-        app.add_systems(OnEnter(GamePhase::Selection), spawn_selection_hub);
+        app.add_systems(OnEnter(GamePhase::Selection), (spawn_selection_hub, end_daily_challenge));
         app.add_systems(OnExit(GamePhase::Selection), despawn::<SelectionRoot>);
         app.add_systems(
             Update,
@@ -150,7 +230,7 @@ impl Plugin for MenuPlugin {
         app.add_systems(OnExit(GamePhase::PickTop), despawn::<PickerRoot>);
         app.add_systems(
             Update,
-            (build_picker_system, update_build_picker_visuals)
+            (build_picker_system, update_build_picker_visuals, card_details_system)
                 .chain()
                 .run_if(in_state(GamePhase::PickTop)),
         );
@@ -158,7 +238,10 @@ impl Plugin for MenuPlugin {
         // Game over overlay
         app.add_systems(OnEnter(GamePhase::GameOver), spawn_game_over_overlay);
         app.add_systems(OnExit(GamePhase::GameOver), despawn::<GameOverOverlay>);
-        app.add_systems(Update, game_over_input.run_if(in_state(GamePhase::GameOver)));
+        app.add_systems(
+            Update,
+            (game_over_input, game_over_button_system).run_if(in_state(GamePhase::GameOver)),
+        );
     }
 }
 
@@ -197,14 +280,26 @@ fn spawn_main_menu(mut commands: Commands) {
                 Node { margin: UiRect::bottom(Val::Px(40.0)), ..default() },
             ));
             spawn_btn(parent, "Start Game", MenuButton::StartGame, COLOR_BTN, COLOR_TEXT, 360.0, 56.0);
+            spawn_btn(parent, "Daily Challenge", MenuButton::DailyChallenge, COLOR_BTN, COLOR_TEXT, 360.0, 56.0);
+            parent.spawn((
+                DailySummaryText,
+                Text::new(""),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(COLOR_TEXT_DIM),
+                Node { margin: UiRect::top(Val::Px(-12.0)), ..default() },
+            ));
             spawn_btn(parent, "Design Map", MenuButton::DesignMap, COLOR_BTN, COLOR_TEXT, 360.0, 56.0);
             spawn_btn(parent, "Design Wheel", MenuButton::DesignWheel, COLOR_BTN, COLOR_TEXT, 360.0, 56.0);
+            spawn_btn(parent, "Settings", MenuButton::Settings, COLOR_BTN, COLOR_TEXT, 360.0, 56.0);
         });
 }
 
 fn menu_button_system(
+    mut commands: Commands,
     mut q: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GamePhase>>,
+    mut selection: ResMut<GameSelection>,
+    registry: Res<PartRegistry>,
 ) {
     for (interaction, button, mut bg) in &mut q {
         match button {
@@ -216,6 +311,22 @@ fn menu_button_system(
                 Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
                 Interaction::None => *bg = BackgroundColor(COLOR_BTN),
             },
+            MenuButton::DailyChallenge => match *interaction {
+                Interaction::Pressed => {
+                    *bg = BackgroundColor(COLOR_BTN_PRESS);
+                    // Player keeps their P1 build; everything else is the day's
+                    let challenge = DailyChallenge::for_day(daily::today(), &registry);
+                    commands.insert_resource(SelectionBeforeDaily(selection.clone()));
+                    selection.mode = GameMode::PvAI;
+                    selection.map_id = challenge.map_id.clone();
+                    selection.p2_build_id = challenge.opponent_build_id.clone();
+                    selection.ai_profile_id = challenge.ai_profile_id.clone();
+                    commands.insert_resource(challenge);
+                    next_state.set(GamePhase::Aiming);
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
             MenuButton::DesignWheel => match *interaction {
                 Interaction::Pressed => {
                     *bg = BackgroundColor(COLOR_BTN_PRESS);
@@ -232,15 +343,54 @@ fn menu_button_system(
                 Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
                 Interaction::None => *bg = BackgroundColor(COLOR_BTN),
             },
+            MenuButton::Settings => match *interaction {
+                Interaction::Pressed => {
+                    *bg = BackgroundColor(COLOR_BTN_PRESS);
+                    next_state.set(GamePhase::Settings);
+                }
+                Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+                Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+            },
         }
     }
 }
 
+/// Settings load at startup, possibly after the first menu spawns, so the
+/// summary is refreshed rather than set once.
+fn update_daily_summary(settings: Res<UserSettings>, mut q: Query<&mut Text, With<DailySummaryText>>) {
+    let summary = format!("Daily #{}: {}", daily::today(), settings.daily.summary(daily::today()));
+    for mut text in &mut q {
+        if text.0 != summary {
+            text.0 = summary.clone();
+        }
+    }
+}
+
+/// Leaving a daily battle for the menu or the hub ends the challenge run and
+/// restores the selection the player had before it.
+fn end_daily_challenge(
+    mut commands: Commands,
+    saved: Option<Res<SelectionBeforeDaily>>,
+    mut selection: ResMut<GameSelection>,
+) {
+    commands.remove_resource::<DailyChallenge>();
+    if let Some(saved) = saved {
+        *selection = saved.0.clone();
+        commands.remove_resource::<SelectionBeforeDaily>();
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // SELECTION HUB
 // ═══════════════════════════════════════════════════════════════════════
 
-fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, registry: Res<PartRegistry>) {
+fn spawn_selection_hub(
+    mut commands: Commands,
+    selection: Res<GameSelection>,
+    registry: Res<PartRegistry>,
+    tuning: Res<Tuning>,
+    ghost: Option<Res<GhostRecording>>,
+) {
     let p1_name = build_display_name(&selection.p1_build_id, &registry);
     let p2_name = build_display_name(&selection.p2_build_id, &registry);
 
@@ -281,6 +431,19 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
                     selection.mode == GameMode::PvAI);
                 spawn_sel_btn(row, "Player vs Player", SelectionButton::ModePvP,
                     selection.mode == GameMode::PvP);
+                // Only offered once a battle has been won
+                if ghost.is_some() {
+                    spawn_sel_btn(row, "vs Ghost", SelectionButton::ModeGhost,
+                        selection.mode == GameMode::Ghost);
+                }
+                spawn_sel_btn(row, "Fair Mode", SelectionButton::ToggleFairMode,
+                    selection.fair_mode);
+                spawn_sel_btn(row, "Last Stand", SelectionButton::ToggleLastStand,
+                    selection.last_stand);
+                spawn_sel_btn(row, "Center Start", SelectionButton::ToggleCenterStart,
+                    selection.center_start);
+                spawn_sel_btn(row, "Best of 3", SelectionButton::ToggleBestOfThree,
+                    selection.best_of > 1);
             });
 
             // ── Map ──
@@ -293,7 +456,7 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
             }).with_children(|row| {
                 row.spawn((
                     CurrentMapLabel,
-                    Text::new(map_display_name(&selection.map_id)),
+                    Text::new(map_display_name(&selection.map_id, &registry)),
                     TextFont { font_size: 20.0, ..default() },
                     TextColor(COLOR_TEXT),
                     Node { margin: UiRect::right(Val::Px(12.0)), ..default() },
@@ -316,7 +479,7 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
                     TextColor(COLOR_TEXT),
                     Node { margin: UiRect::right(Val::Px(12.0)), ..default() },
                 ));
-                spawn_sel_btn(row, "Choose...", SelectionButton::ChooseP1Top, false);
+                spawn_sel_btn(row, "Choose...", SelectionButton::ChooseP1Build, false);
             });
 
             // ── Player 2 ──
@@ -332,14 +495,28 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
                 // AI label
                 p2.spawn((
                     P2AiLabel,
-                    Text::new("Player 2: AI (Random)"),
+                    Text::new(p2_ai_label(&selection, ghost.as_deref(), &registry)),
                     TextFont { font_size: 20.0, ..default() },
                     TextColor(COLOR_TEXT_DIM),
                     Node {
-                        display: if selection.mode == GameMode::PvAI { Display::Flex } else { Display::None },
+                        display: if selection.mode == GameMode::PvP { Display::None } else { Display::Flex },
                         ..default()
                     },
                 ));
+                p2.spawn((
+                    AiProfilePanel,
+                    Node {
+                        column_gap: Val::Px(12.0),
+                        display: if selection.mode == GameMode::PvAI { Display::Flex } else { Display::None },
+                        ..default()
+                    },
+                )).with_children(|row| {
+                    spawn_sel_btn(row, "Change Personality", SelectionButton::CycleAiProfile, false);
+                    for difficulty in AiDifficulty::ALL {
+                        spawn_sel_btn(row, difficulty.label(), SelectionButton::Difficulty(difficulty),
+                            selection.ai_difficulty == difficulty);
+                    }
+                });
                 // PvP choose
                 p2.spawn((
                     P2ChoosePanel,
@@ -353,12 +530,14 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
                 )).with_children(|row| {
                     section_label(row, "Player 2 Build");
                     row.spawn((
+                        CurrentP2BuildLabel,
                         Text::new(p2_name),
                         TextFont { font_size: 20.0, ..default() },
                         TextColor(COLOR_TEXT),
                         Node { margin: UiRect::right(Val::Px(12.0)), ..default() },
                     ));
-                    spawn_sel_btn(row, "Choose...", SelectionButton::ChooseP2Top, false);
+                    spawn_sel_btn(row, "Choose...", SelectionButton::ChooseP2Build, false);
+                    spawn_sel_btn(row, "Swap Players", SelectionButton::SwapPlayers, false);
                 });
             });
 
@@ -372,6 +551,13 @@ fn spawn_selection_hub(mut commands: Commands, selection: Res<GameSelection>, re
                 spawn_sel_btn(row, "Back", SelectionButton::Back, false);
                 spawn_sel_btn(row, "Start Battle!", SelectionButton::StartBattle, false);
             });
+
+            root.spawn((
+                FairModeWarning,
+                Text::new(fair_mode_warning(&selection, &registry, &tuning).unwrap_or_default()),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(COLOR_WARN),
+            ));
         });
 }
 
@@ -381,8 +567,19 @@ fn selection_button_system(
     mut picking: ResMut<PickingFor>,
     mut next_state: ResMut<NextState<GamePhase>>,
     registry: Res<PartRegistry>,
+    tuning: Res<Tuning>,
+    ghost: Option<Res<GhostRecording>>,
+    mut rng: ResMut<GameRng>,
+    mut settings: ResMut<UserSettings>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
 ) {
-    let build_ids: Vec<String> = registry.builds.keys().cloned().collect();
+    // Sorted so a seeded `GameRng` always picks the same build
+    let mut build_ids: Vec<String> = registry.builds.keys()
+        .filter(|id| !selection.fair_mode || !over_budget(id, &registry, &tuning))
+        .cloned()
+        .collect();
+    build_ids.sort();
     for (interaction, button, _bg) in &mut q {
         if *interaction != Interaction::Pressed {
             continue;
@@ -391,22 +588,64 @@ fn selection_button_system(
             SelectionButton::ModePvP => selection.mode = GameMode::PvP,
             SelectionButton::ModePvAI => {
                 selection.mode = GameMode::PvAI;
-                randomize_ai_selection(&mut selection, &build_ids);
+                randomize_ai_selection(&mut selection, &build_ids, &mut rng);
+            }
+            SelectionButton::ModeGhost => {
+                if let Some(ghost) = &ghost {
+                    selection.mode = GameMode::Ghost;
+                    use_ghost_selection(&mut selection, ghost);
+                }
+            }
+            SelectionButton::ToggleFairMode => {
+                selection.fair_mode = !selection.fair_mode;
+                if selection.fair_mode
+                    && selection.mode == GameMode::PvAI
+                    && over_budget(&selection.p2_build_id, &registry, &tuning)
+                {
+                    randomize_ai_selection(&mut selection, &build_ids, &mut rng);
+                }
             }
+            SelectionButton::ToggleLastStand => selection.last_stand = !selection.last_stand,
+            SelectionButton::ToggleCenterStart => selection.center_start = !selection.center_start,
+            SelectionButton::ToggleBestOfThree => selection.best_of = if selection.best_of > 1 { 1 } else { 3 },
             SelectionButton::ChooseMap => {
                 next_state.set(GamePhase::PickMap);
             }
-            SelectionButton::ChooseP1Top => {
+            SelectionButton::ChooseP1Build => {
                 picking.0 = 1;
                 next_state.set(GamePhase::PickTop);
             }
-            SelectionButton::ChooseP2Top => {
+            SelectionButton::ChooseP2Build => {
                 picking.0 = 2;
                 next_state.set(GamePhase::PickTop);
             }
+            SelectionButton::CycleAiProfile => {
+                selection.ai_profile_id = next_ai_profile(&selection.ai_profile_id, &registry);
+            }
+            SelectionButton::Difficulty(difficulty) => {
+                selection.ai_difficulty = *difficulty;
+                settings.ai_difficulty = *difficulty;
+                if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                    settings.save(repo, &rt.0);
+                }
+            }
+            SelectionButton::SwapPlayers => {
+                let selection = &mut *selection;
+                std::mem::swap(&mut selection.p1_build_id, &mut selection.p2_build_id);
+            }
             SelectionButton::StartBattle => {
                 if selection.mode == GameMode::PvAI {
-                    randomize_ai_selection(&mut selection, &build_ids);
+                    randomize_ai_selection(&mut selection, &build_ids, &mut rng);
+                }
+                if selection.mode == GameMode::Ghost {
+                    // The ghost only makes sense on the map it was recorded on
+                    let Some(ghost) = ghost.as_ref().filter(|g| registry.builds.contains_key(&g.build_id)) else {
+                        continue;
+                    };
+                    use_ghost_selection(&mut selection, ghost);
+                }
+                if fair_mode_warning(&selection, &registry, &tuning).is_some() {
+                    continue;
                 }
                 next_state.set(GamePhase::Aiming);
             }
@@ -419,20 +658,59 @@ fn selection_button_system(
 
 fn update_selection_hub_visuals(
     selection: Res<GameSelection>,
+    registry: Res<PartRegistry>,
+    ghost: Option<Res<GhostRecording>>,
     mut mode_btns: Query<(&SelectionButton, &Interaction, &mut BackgroundColor), With<SelectionHighlight>>,
-    mut ai_label: Query<&mut Node, (With<P2AiLabel>, Without<P2ChoosePanel>)>,
+    mut ai_label: Query<
+        (&mut Node, &mut Text),
+        (
+            With<P2AiLabel>,
+            Without<P2ChoosePanel>,
+            Without<CurrentP1BuildLabel>,
+            Without<CurrentP2BuildLabel>,
+            Without<FairModeWarning>,
+        ),
+    >,
     mut p2_panel: Query<&mut Node, (With<P2ChoosePanel>, Without<P2AiLabel>)>,
+    mut profile_panel: Query<&mut Node, (With<AiProfilePanel>, Without<P2AiLabel>, Without<P2ChoosePanel>)>,
+    mut p1_label: Query<&mut Text, (With<CurrentP1BuildLabel>, Without<CurrentP2BuildLabel>)>,
+    mut p2_label: Query<&mut Text, (With<CurrentP2BuildLabel>, Without<CurrentP1BuildLabel>)>,
+    mut warning: Query<&mut Text, (With<FairModeWarning>, Without<CurrentP1BuildLabel>, Without<CurrentP2BuildLabel>)>,
+    tuning: Res<Tuning>,
 ) {
-    for mut node in &mut ai_label {
-        node.display = if selection.mode == GameMode::PvAI { Display::Flex } else { Display::None };
+    if selection.is_changed() {
+        for mut text in &mut p1_label {
+            **text = build_display_name(&selection.p1_build_id, &registry);
+        }
+        for mut text in &mut p2_label {
+            **text = build_display_name(&selection.p2_build_id, &registry);
+        }
+        for mut text in &mut warning {
+            **text = fair_mode_warning(&selection, &registry, &tuning).unwrap_or_default();
+        }
+        for (_, mut text) in &mut ai_label {
+            **text = p2_ai_label(&selection, ghost.as_deref(), &registry);
+        }
+    }
+    for (mut node, _) in &mut ai_label {
+        node.display = if selection.mode == GameMode::PvP { Display::None } else { Display::Flex };
     }
     for mut node in &mut p2_panel {
         node.display = if selection.mode == GameMode::PvP { Display::Flex } else { Display::None };
     }
+    for mut node in &mut profile_panel {
+        node.display = if selection.mode == GameMode::PvAI { Display::Flex } else { Display::None };
+    }
     for (button, interaction, mut bg) in &mut mode_btns {
         let is_selected = match button {
             SelectionButton::ModePvP => selection.mode == GameMode::PvP,
             SelectionButton::ModePvAI => selection.mode == GameMode::PvAI,
+            SelectionButton::ModeGhost => selection.mode == GameMode::Ghost,
+            SelectionButton::ToggleFairMode => selection.fair_mode,
+            SelectionButton::ToggleLastStand => selection.last_stand,
+            SelectionButton::ToggleCenterStart => selection.center_start,
+            SelectionButton::ToggleBestOfThree => selection.best_of > 1,
+            SelectionButton::Difficulty(difficulty) => selection.ai_difficulty == *difficulty,
             _ => false,
         };
         *bg = BackgroundColor(match (is_selected, interaction) {
@@ -484,8 +762,9 @@ fn spawn_map_picker(mut commands: Commands, selection: Res<GameSelection>, regis
                 margin: UiRect::top(Val::Px(20.0)),
                 ..default()
             }).with_children(|grid| {
+                // Built-in maps first, then custom ones alphabetically
                 let mut maps: Vec<_> = registry.maps.values().collect();
-                maps.sort_by(|a, b| a.name.cmp(&b.name));
+                maps.sort_by(|a, b| b.is_builtin().cmp(&a.is_builtin()).then_with(|| a.name.cmp(&b.name)));
                 for map in maps {
                     let desc = format!("R={:.0}, {} items", map.arena_radius, map.placements.len());
                     spawn_map_card(grid, &map.id, &map.name,
@@ -593,6 +872,7 @@ fn spawn_build_picker(
     selection: Res<GameSelection>,
     picking: Res<PickingFor>,
     registry: Res<PartRegistry>,
+    tuning: Res<Tuning>,
     game_assets: Option<Res<GameAssets>>,
 ) {
     let player = picking.0;
@@ -643,9 +923,12 @@ fn spawn_build_picker(
                     let top_sprite = game_assets.as_ref()
                         .and_then(|a| a.wheel_sprites.get(build_ref.wheel_id.as_str()).cloned());
                     let weapon_name = registry.weapons.get(&build_ref.weapon_id)
-                        .map(|w| format!("{:?}", w.kind))
+                        .map(|w| w.stats_line())
                         .unwrap_or_default();
-                    spawn_build_card(grid, id, &build_ref.name, &weapon_name, *cur_build == *id, top_sprite);
+                    let power = registry.build_power_score(id, &tuning).unwrap_or(0.0);
+                    let blocked = selection.fair_mode && power > tuning.power_budget;
+                    let details = build_details_text(id, &registry, &tuning);
+                    spawn_build_card(grid, id, &build_ref.name, &weapon_name, power, blocked, *cur_build == *id, top_sprite, &details);
                 }
             });
 
@@ -667,8 +950,11 @@ fn spawn_build_card(
     id: &str,
     name: &str,
     weapon_kind: &str,
+    power: f32,
+    blocked: bool,
     selected: bool,
     top_sprite: Option<Handle<Image>>,
+    details: &str,
 ) {
     let card_bg = if selected { COLOR_CARD_SELECTED } else { COLOR_CARD };
 
@@ -722,14 +1008,69 @@ fn spawn_build_card(
             TextFont { font_size: 13.0, ..default() },
             TextColor(COLOR_ACCENT),
         ));
+        // Power budget score
+        card.spawn((
+            Text::new(if blocked {
+                format!("Power {:.0} (over budget)", power)
+            } else {
+                format!("Power {:.0}", power)
+            }),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(if blocked { COLOR_WARN } else { COLOR_TEXT_DIM }),
+        ));
+        // Expandable effective stats
+        card.spawn((
+            CardDetailsToggle { build_id: id.into(), shown: false },
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                border_radius: BorderRadius::all(Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(COLOR_BTN),
+        )).with_children(|btn| {
+            btn.spawn((
+                Text::new("Show Details"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(COLOR_TEXT),
+            ));
+        });
+        card.spawn((
+            CardDetails(id.into()),
+            Text::new(details),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(COLOR_TEXT_DIM),
+            Node { display: Display::None, ..default() },
+        ));
     });
 }
 
+/// Effective stats of a build as the battle would compute them.
+fn build_details_text(build_id: &str, registry: &PartRegistry, tuning: &Tuning) -> String {
+    let Some(build) = registry.resolve_build_id(build_id) else {
+        return "Missing parts".into();
+    };
+    let eff = build.combined_modifiers().compute_effective(&build.wheel, tuning);
+    let mut text = format!(
+        "HP {:.0}  R {:.2}  Spd {:.1}\nAccel {:.1}  Stab {:.1}  Ctrl x{:.2}\nDmg Out x{:.2}  In x{:.2}\nFire Rate x{:.2}",
+        eff.spin_hp_max.0, eff.radius.0, eff.move_speed.0,
+        eff.accel, eff.stability, eff.control_multiplier,
+        eff.damage_out_mult.0, eff.damage_in_mult.0,
+        eff.fire_rate_mult.0,
+    );
+    if let Some(cap) = eff.stun_cap {
+        text.push_str(&format!("  Stun Cap {:.1}s", cap));
+    }
+    text
+}
+
 fn build_picker_system(
     mut q: Query<(&Interaction, &PickerButton), Changed<Interaction>>,
     mut selection: ResMut<GameSelection>,
     picking: Res<PickingFor>,
     mut next_state: ResMut<NextState<GamePhase>>,
+    registry: Res<PartRegistry>,
+    tuning: Res<Tuning>,
 ) {
     let player = picking.0;
     for (interaction, button) in &mut q {
@@ -738,6 +1079,9 @@ fn build_picker_system(
         }
         match button {
             PickerButton::SelectBuild(id) => {
+                if selection.fair_mode && over_budget(id, &registry, &tuning) {
+                    continue;
+                }
                 if player == 1 {
                     selection.p1_build_id = id.clone();
                 } else {
@@ -778,22 +1122,60 @@ fn update_build_picker_visuals(
     }
 }
 
+/// Expand / collapse a card's stat details. The toggle blocks focus, so
+/// pressing it doesn't also select the card.
+fn card_details_system(
+    mut toggles: Query<(&Interaction, &mut CardDetailsToggle, &mut BackgroundColor, &Children), Changed<Interaction>>,
+    mut labels: Query<&mut Text, Without<CardDetails>>,
+    mut details: Query<(&CardDetails, &mut Node)>,
+) {
+    for (interaction, mut toggle, mut bg, children) in &mut toggles {
+        match *interaction {
+            Interaction::Pressed => {
+                toggle.shown = !toggle.shown;
+                for (card, mut node) in &mut details {
+                    if card.0 == toggle.build_id {
+                        node.display = if toggle.shown { Display::Flex } else { Display::None };
+                    }
+                }
+                for child in children.iter() {
+                    if let Ok(mut text) = labels.get_mut(child) {
+                        **text = if toggle.shown { "Hide Details" } else { "Show Details" }.into();
+                    }
+                }
+                *bg = BackgroundColor(COLOR_BTN_PRESS);
+            }
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // GAME OVER OVERLAY
 // ═══════════════════════════════════════════════════════════════════════
 
 fn spawn_game_over_overlay(
     mut commands: Commands,
+    selection: Res<GameSelection>,
+    score: Res<crate::game::components::RoundScore>,
+    timer: Res<crate::game::components::MatchTimer>,
+    result: Res<crate::game::components::MatchResult>,
+    rng: Res<GameRng>,
+    challenge: Option<Res<DailyChallenge>>,
+    mut settings: ResMut<UserSettings>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
     player: Query<
-        (&crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
+        (Entity, &crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
         With<crate::game::components::PlayerControlled>,
     >,
     ai: Query<
-        (&crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
+        (Entity, &crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
         (With<crate::game::components::AiControlled>, Without<crate::game::components::PlayerControlled>),
     >,
     p2: Query<
-        (&crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
+        (Entity, &crate::game::components::SpinHpCurrent, &crate::game::components::TopBuild),
         (
             With<crate::game::components::Player2Controlled>,
             Without<crate::game::components::PlayerControlled>,
@@ -801,18 +1183,43 @@ fn spawn_game_over_overlay(
         ),
     >,
 ) {
-    let (player_hp, p1_name) = player.iter().next()
-        .map(|(s, b)| (s.0.0, b.0.name.clone()))
-        .unwrap_or((0.0, "Player 1".into()));
-    let (opponent_hp, p2_name) = ai.iter().next().or_else(|| p2.iter().next())
-        .map(|(s, b)| (s.0.0, b.0.name.clone()))
-        .unwrap_or((0.0, "Player 2".into()));
-    let winner = if player_hp > opponent_hp {
+    let (p1, player_hp, p1_name) = player.iter().next()
+        .map(|(e, s, b)| (Some(e), s.0.0, b.0.name.clone()))
+        .unwrap_or((None, 0.0, "Player 1".into()));
+    let p2_name = ai.iter().next().or_else(|| p2.iter().next())
+        .map(|(_, _, b)| b.0.name.clone())
+        .unwrap_or_else(|| "Player 2".into());
+    // The winner comes from `check_game_over`'s verdict: a double KO is a
+    // draw, otherwise whoever isn't the loser (KO'd or behind at time up).
+    let series = selection.best_of > 1;
+    let player_won = !result.draw && result.loser.is_some() && result.loser != p1;
+    let finish_line = if timer.decided {
+        Some("Time up: decided on spin HP")
+    } else if timer.sudden_death && !result.draw {
+        Some("Won in sudden death")
+    } else {
+        None
+    };
+    let winner = if series {
+        let series_winner = if score.p1 > score.p2 { &p1_name } else { &p2_name };
+        format!("{} Wins the Series!", series_winner)
+    } else if result.draw {
+        "Draw!".to_string()
+    } else if player_won {
         format!("{} Wins!", p1_name)
     } else {
         format!("{} Wins!", p2_name)
     };
 
+    // Daily challenge: count the attempt, keep the best win
+    let daily_line = challenge.map(|challenge| {
+        settings.daily.record(challenge.day, player_won.then_some(player_hp));
+        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+            settings.save(repo, &rt.0);
+        }
+        format!("Daily #{}: {}", challenge.day, settings.daily.summary(challenge.day))
+    });
+
     commands
         .spawn((
             GameOverOverlay,
@@ -835,8 +1242,43 @@ fn spawn_game_over_overlay(
                 TextFont { font_size: 56.0, ..default() },
                 TextColor(Color::srgb(1.0, 1.0, 0.0)),
             ));
+            if let Some(line) = finish_line.filter(|_| !series) {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(COLOR_TEXT),
+                ));
+            }
+            if series {
+                parent.spawn((
+                    Text::new(format!("Final score: {} - {}", score.p1, score.p2)),
+                    TextFont { font_size: 28.0, ..default() },
+                    TextColor(COLOR_TEXT),
+                ));
+            }
+            if let Some(line) = daily_line {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(COLOR_ACCENT),
+                ));
+            }
+            parent.spawn((
+                Text::new(format!("Seed: {}", rng.seed())),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(COLOR_TEXT_DIM),
+            ));
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(20.0),
+                ..default()
+            }).with_children(|row| {
+                spawn_btn(row, "Rematch", GameOverButton::Rematch, COLOR_BTN, COLOR_TEXT, 220.0, 52.0);
+                spawn_btn(row, "Change Builds", GameOverButton::ChangeBuilds, COLOR_BTN, COLOR_TEXT, 220.0, 52.0);
+                spawn_btn(row, "Main Menu", GameOverButton::MainMenu, COLOR_BTN, COLOR_TEXT, 220.0, 52.0);
+            });
             parent.spawn((
-                Text::new("Press ESCAPE to return to menu"),
+                Text::new("R: rematch    C: change builds    ESCAPE: return to menu"),
                 TextFont { font_size: 22.0, ..default() },
                 TextColor(COLOR_TEXT_DIM),
             ));
@@ -847,32 +1289,112 @@ fn game_over_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GamePhase>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) || keyboard.just_pressed(KeyCode::Enter) {
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        // Same GameSelection again; leaving GameOver clears the old arena.
+        next_state.set(GamePhase::Aiming);
+    } else if keyboard.just_pressed(KeyCode::KeyC) {
+        // Back to the hub with the current GameSelection untouched.
+        next_state.set(GamePhase::Selection);
+    } else if keyboard.just_pressed(KeyCode::Escape) || keyboard.just_pressed(KeyCode::Enter) {
         next_state.set(GamePhase::MainMenu);
     }
 }
 
+fn game_over_button_system(
+    mut q: Query<(&Interaction, &GameOverButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg = BackgroundColor(COLOR_BTN_PRESS);
+                next_state.set(match button {
+                    GameOverButton::Rematch => GamePhase::Aiming,
+                    GameOverButton::ChangeBuilds => GamePhase::Selection,
+                    GameOverButton::MainMenu => GamePhase::MainMenu,
+                });
+            }
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // HELPERS
 // ═══════════════════════════════════════════════════════════════════════
 
-fn randomize_ai_selection(selection: &mut GameSelection, build_ids: &[String]) {
+fn randomize_ai_selection(selection: &mut GameSelection, build_ids: &[String], rng: &mut GameRng) {
     if build_ids.is_empty() {
         return;
     }
-    use std::time::SystemTime;
-    let nanos = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos();
-    selection.p2_build_id = build_ids[(nanos as usize) % build_ids.len()].clone();
-}
-
-fn map_display_name(id: &str) -> &str {
-    match id {
-        "default_arena" => "Default Arena",
-        _ => id,
+    selection.p2_build_id = build_ids[(rng.next_u64() % build_ids.len() as u64) as usize].clone();
+}
+
+/// Ghost battles replay the recorded build on the recorded map.
+fn use_ghost_selection(selection: &mut GameSelection, ghost: &GhostRecording) {
+    selection.p2_build_id = ghost.build_id.clone();
+    selection.map_id = ghost.map_id.clone();
+}
+
+fn p2_ai_label(selection: &GameSelection, ghost: Option<&GhostRecording>, registry: &PartRegistry) -> String {
+    match (selection.mode, ghost) {
+        (GameMode::Ghost, Some(ghost)) => format!(
+            "Player 2: Ghost of {} ({:.1}s run on {})",
+            build_display_name(&ghost.build_id, registry),
+            ghost.duration,
+            map_display_name(&ghost.map_id, registry),
+        ),
+        _ => {
+            let profile = registry.ai_profiles.get(&selection.ai_profile_id).cloned().unwrap_or_default();
+            format!(
+                "Player 2: AI (Random) — {} (aggression {:.0}%, evasion {:.0}%, fire discipline {:.0}%)",
+                profile.name,
+                profile.aggression * 100.0,
+                profile.evasion * 100.0,
+                profile.fire_discipline * 100.0,
+            )
+        }
+    }
+}
+
+/// The AI profile after `current`, in name order (wrapping).
+fn next_ai_profile(current: &str, registry: &PartRegistry) -> String {
+    let mut profiles: Vec<_> = registry.ai_profiles.values().collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    let next = profiles.iter().position(|p| p.id == current).map_or(0, |i| (i + 1) % profiles.len());
+    profiles.get(next).map(|p| p.id.clone()).unwrap_or_else(|| crate::game::ai::DEFAULT_AI_PROFILE_ID.into())
+}
+
+/// True if the build's power score exceeds `tuning.power_budget`.
+fn over_budget(build_id: &str, registry: &PartRegistry, tuning: &Tuning) -> bool {
+    registry.build_power_score(build_id, tuning)
+        .is_some_and(|p| p > tuning.power_budget)
+}
+
+/// In fair mode, describe the first selected build that breaks the power budget.
+fn fair_mode_warning(selection: &GameSelection, registry: &PartRegistry, tuning: &Tuning) -> Option<String> {
+    if !selection.fair_mode {
+        return None;
+    }
+    let mut ids = vec![&selection.p1_build_id];
+    if selection.mode == GameMode::PvP {
+        ids.push(&selection.p2_build_id);
     }
+    ids.into_iter().find(|id| over_budget(id, registry, tuning)).map(|id| {
+        format!(
+            "Fair Mode: {} is over the power budget ({:.0} / {:.0})",
+            build_display_name(id, registry),
+            registry.build_power_score(id, tuning).unwrap_or(0.0),
+            tuning.power_budget,
+        )
+    })
+}
+
+fn map_display_name(id: &str, registry: &PartRegistry) -> String {
+    registry.maps.get(id)
+        .map(|m| m.name.clone())
+        .unwrap_or_else(|| id.to_string())
 }
 
 fn build_display_name(id: &str, registry: &PartRegistry) -> String {
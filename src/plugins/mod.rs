@@ -1,6 +1,9 @@
+pub mod debug_plugin;
 pub mod design_plugin;
 pub mod game_plugin;
 pub mod map_design_plugin;
 pub mod menu_plugin;
+pub mod settings_plugin;
 pub mod storage_plugin;
+pub mod tutorial_plugin;
 pub mod ui_plugin;
@@ -0,0 +1,915 @@
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+
+use crate::config::tuning::Tuning;
+use crate::game::ai::AiDifficulty;
+use crate::game::components::{AudioSettings, GamePhase};
+use crate::game::daily::DailyRecord;
+use crate::game::map::ColorBlindMode;
+use crate::plugins::menu_plugin::GameSelection;
+use crate::plugins::storage_plugin::TokioRuntime;
+use crate::plugins::tutorial_plugin::TutorialState;
+use crate::storage::sqlite_repo::SqliteRepo;
+
+// ── Data types ───────────────────────────────────────────────────────
+
+/// Player preferences, persisted as key/value rows in the `settings` table.
+#[derive(Resource, Default)]
+pub struct UserSettings {
+    /// Set once the first-run tutorial has been dismissed.
+    pub seen_tutorial: bool,
+    /// PvP: turn the camera 180° toward Player 2 while only they are still aiming.
+    pub pvp_camera_flip: bool,
+    /// Physics ticks per second; 0 = use `dt` from tuning.ron.
+    pub tick_hz: u32,
+    /// Draw a fading motion trail behind each top in battle.
+    pub top_trails: bool,
+    /// Battle camera zoom in percent of the arena-fitting view; 0 = fit arena.
+    pub camera_zoom: u32,
+    /// Daily challenge results for the last day played.
+    pub daily: DailyRecord,
+    /// Last PvAI difficulty picked in Game Setup.
+    pub ai_difficulty: AiDifficulty,
+    /// Master volume and mute (copied into the `AudioSettings` resource).
+    pub audio: AudioSettings,
+    /// Map editor / map card palette.
+    pub color_blind: ColorBlindMode,
+    /// UI size in percent (text and boxes scale together); 0 = 100%.
+    pub ui_scale: u32,
+}
+
+/// Physics rates offered in Settings (0 = tuning.ron).
+const TICK_RATES: [u32; 4] = [0, 30, 60, 120];
+
+/// Battle zoom levels offered in Settings (0 = fit arena).
+const CAMERA_ZOOMS: [u32; 4] = [0, 75, 125, 150];
+
+/// Master volume change per -/+ press in Settings.
+const VOLUME_STEP: f32 = 0.1;
+
+/// UI size range and step in Settings, in percent. The top end still fits
+/// the tallest fixed-height screen (Settings) in the default window.
+const UI_SCALE_MIN: u32 = 80;
+const UI_SCALE_MAX: u32 = 140;
+const UI_SCALE_STEP: u32 = 10;
+
+impl UserSettings {
+    fn apply(&mut self, key: &str, value: &str) {
+        match key {
+            "seen_tutorial" => self.seen_tutorial = value == "true",
+            "pvp_camera_flip" => self.pvp_camera_flip = value == "true",
+            "tick_hz" => self.tick_hz = value.parse().unwrap_or(0),
+            "top_trails" => self.top_trails = value == "true",
+            "camera_zoom" => self.camera_zoom = value.parse().unwrap_or(0),
+            "daily_day" => self.daily.day = value.parse().unwrap_or(0),
+            "daily_attempts" => self.daily.attempts = value.parse().unwrap_or(0),
+            "daily_best_hp" => self.daily.best_hp = value.parse().ok(),
+            "ai_difficulty" => self.ai_difficulty = AiDifficulty::from_key(value),
+            "audio_master" => self.audio.master = value.parse::<f32>().map_or(1.0, |v| v.clamp(0.0, 1.0)),
+            "audio_muted" => self.audio.muted = value == "true",
+            "color_blind" => self.color_blind = ColorBlindMode::from_key(value),
+            "ui_scale" => self.ui_scale = value.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+
+    fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("seen_tutorial", self.seen_tutorial.to_string()),
+            ("pvp_camera_flip", self.pvp_camera_flip.to_string()),
+            ("tick_hz", self.tick_hz.to_string()),
+            ("top_trails", self.top_trails.to_string()),
+            ("camera_zoom", self.camera_zoom.to_string()),
+            ("daily_day", self.daily.day.to_string()),
+            ("daily_attempts", self.daily.attempts.to_string()),
+            ("daily_best_hp", self.daily.best_hp.map(|hp| hp.to_string()).unwrap_or_default()),
+            ("ai_difficulty", self.ai_difficulty.key().to_string()),
+            ("audio_master", self.audio.master.to_string()),
+            ("audio_muted", self.audio.muted.to_string()),
+            ("color_blind", self.color_blind.key().to_string()),
+            ("ui_scale", self.ui_scale.to_string()),
+        ]
+    }
+
+    /// Battle camera zoom factor (1.0 = the whole arena fits the window).
+    pub fn camera_zoom_factor(&self) -> f32 {
+        match self.camera_zoom {
+            0 => 1.0,
+            pct => pct as f32 / 100.0,
+        }
+    }
+
+    /// UI size in percent, with 0 (never changed) read as 100%.
+    pub fn ui_scale_percent(&self) -> u32 {
+        match self.ui_scale {
+            0 => 100,
+            pct => pct.clamp(UI_SCALE_MIN, UI_SCALE_MAX),
+        }
+    }
+
+    /// Write every preference back to SQLite.
+    pub fn save(&self, repo: &SqliteRepo, rt: &tokio::runtime::Runtime) {
+        for (key, value) in self.entries() {
+            if let Err(e) = repo.save_setting_sync(rt, key, &value) {
+                error!("Failed to save setting '{}': {}", key, e);
+            }
+        }
+    }
+}
+
+/// Aiming keys for both players, persisted as `key_<action>` rows in the
+/// `settings` table (value = the `KeyCode` variant name).
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KeyBindings {
+    pub p1_left: KeyCode,
+    pub p1_right: KeyCode,
+    pub p1_confirm: KeyCode,
+    pub p2_left: KeyCode,
+    pub p2_right: KeyCode,
+    pub p2_confirm: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            p1_left: KeyCode::ArrowLeft,
+            p1_right: KeyCode::ArrowRight,
+            p1_confirm: KeyCode::Space,
+            p2_left: KeyCode::KeyA,
+            p2_right: KeyCode::KeyD,
+            p2_confirm: KeyCode::Enter,
+        }
+    }
+}
+
+/// One rebindable action on the Controls screen.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+enum BindAction {
+    P1Left,
+    P1Right,
+    P1Confirm,
+    P2Left,
+    P2Right,
+    P2Confirm,
+}
+
+impl BindAction {
+    const ALL: [BindAction; 6] = [
+        BindAction::P1Left,
+        BindAction::P1Right,
+        BindAction::P1Confirm,
+        BindAction::P2Left,
+        BindAction::P2Right,
+        BindAction::P2Confirm,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            BindAction::P1Left => "key_p1_left",
+            BindAction::P1Right => "key_p1_right",
+            BindAction::P1Confirm => "key_p1_confirm",
+            BindAction::P2Left => "key_p2_left",
+            BindAction::P2Right => "key_p2_right",
+            BindAction::P2Confirm => "key_p2_confirm",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            BindAction::P1Left => "P1 Rotate Left",
+            BindAction::P1Right => "P1 Rotate Right",
+            BindAction::P1Confirm => "P1 Launch",
+            BindAction::P2Left => "P2 Rotate Left",
+            BindAction::P2Right => "P2 Rotate Right",
+            BindAction::P2Confirm => "P2 Launch",
+        }
+    }
+}
+
+/// Keys that can be bound. Escape is reserved for cancelling a rebind, and
+/// P / R / F-keys are left to the battle and debug shortcuts.
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE,
+    KeyCode::KeyF, KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ,
+    KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO,
+    KeyCode::KeyQ, KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, KeyCode::KeyV,
+    KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::ArrowUp, KeyCode::ArrowDown,
+    KeyCode::Space, KeyCode::Enter, KeyCode::Tab, KeyCode::Backspace,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight,
+    KeyCode::Comma, KeyCode::Period, KeyCode::Slash, KeyCode::Semicolon, KeyCode::Quote,
+    KeyCode::BracketLeft, KeyCode::BracketRight, KeyCode::Minus, KeyCode::Equal,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3, KeyCode::Numpad4,
+    KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7, KeyCode::Numpad8, KeyCode::Numpad9,
+    KeyCode::NumpadEnter,
+];
+
+/// Stored name of a key (the `KeyCode` variant, e.g. "ArrowLeft", "KeyA").
+fn key_name(key: KeyCode) -> String {
+    format!("{:?}", key)
+}
+
+fn parse_key(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS.iter().copied().find(|&k| key_name(k) == name)
+}
+
+/// Short on-screen name: "A" for `KeyA`, "1" for `Digit1`, "Left" for `ArrowLeft`.
+pub fn key_label(key: KeyCode) -> String {
+    let name = key_name(key);
+    ["Key", "Digit", "Arrow"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix).filter(|rest| !rest.is_empty()))
+        .map(str::to_string)
+        .unwrap_or(name)
+}
+
+impl KeyBindings {
+    fn get(&self, action: BindAction) -> KeyCode {
+        match action {
+            BindAction::P1Left => self.p1_left,
+            BindAction::P1Right => self.p1_right,
+            BindAction::P1Confirm => self.p1_confirm,
+            BindAction::P2Left => self.p2_left,
+            BindAction::P2Right => self.p2_right,
+            BindAction::P2Confirm => self.p2_confirm,
+        }
+    }
+
+    fn slot(&mut self, action: BindAction) -> &mut KeyCode {
+        match action {
+            BindAction::P1Left => &mut self.p1_left,
+            BindAction::P1Right => &mut self.p1_right,
+            BindAction::P1Confirm => &mut self.p1_confirm,
+            BindAction::P2Left => &mut self.p2_left,
+            BindAction::P2Right => &mut self.p2_right,
+            BindAction::P2Confirm => &mut self.p2_confirm,
+        }
+    }
+
+    /// Bind `key` to `action`. An action that already used `key` takes the
+    /// old key instead, so no key ever drives two actions.
+    fn bind(&mut self, action: BindAction, key: KeyCode) {
+        let old = self.get(action);
+        if let Some(other) = BindAction::ALL.into_iter().find(|&a| a != action && self.get(a) == key) {
+            *self.slot(other) = old;
+        }
+        *self.slot(action) = key;
+    }
+
+    fn apply(&mut self, key: &str, value: &str) {
+        if let (Some(action), Some(code)) = (BindAction::ALL.into_iter().find(|a| a.key() == key), parse_key(value)) {
+            *self.slot(action) = code;
+        }
+    }
+
+    /// Write every binding back to SQLite.
+    pub fn save(&self, repo: &SqliteRepo, rt: &tokio::runtime::Runtime) {
+        for action in BindAction::ALL {
+            if let Err(e) = repo.save_setting_sync(rt, action.key(), &key_name(self.get(action))) {
+                error!("Failed to save setting '{}': {}", action.key(), e);
+            }
+        }
+    }
+}
+
+// ── Marker components ────────────────────────────────────────────────
+
+#[derive(Component)]
+struct SettingsRoot;
+
+#[derive(Component)]
+struct SettingsStatusText;
+
+#[derive(Component)]
+enum SettingsButton {
+    ShowTutorial,
+    TogglePvpFlip,
+    CycleTickRate,
+    ToggleTopTrails,
+    CycleCameraZoom,
+    VolumeDown,
+    VolumeUp,
+    TextSmaller,
+    TextLarger,
+    ToggleMute,
+    CycleColorBlind,
+    Controls,
+    Back,
+}
+
+#[derive(Component)]
+struct ControlsRoot;
+
+#[derive(Component)]
+enum ControlsButton {
+    Rebind(BindAction),
+    ResetDefaults,
+    Back,
+}
+
+/// Text of a `ControlsButton::Rebind` button.
+#[derive(Component)]
+struct BindingLabel(BindAction);
+
+#[derive(Component)]
+struct ControlsStatusText;
+
+/// Action waiting for its next key press on the Controls screen.
+#[derive(Resource, Default)]
+struct RebindState {
+    waiting: Option<BindAction>,
+}
+
+/// Setting whose current value a Settings screen label shows.
+#[derive(Clone, Copy)]
+enum SettingKind {
+    PvpFlip,
+    TickRate,
+    TopTrails,
+    CameraZoom,
+    Volume,
+    Mute,
+    MapColors,
+    TextSize,
+}
+
+impl SettingKind {
+    fn label(self, settings: &UserSettings) -> String {
+        match self {
+            SettingKind::PvpFlip => pvp_flip_label(settings),
+            SettingKind::TickRate => tick_rate_label(settings),
+            SettingKind::TopTrails => top_trails_label(settings),
+            SettingKind::CameraZoom => camera_zoom_label(settings),
+            SettingKind::Volume => volume_label(settings),
+            SettingKind::Mute => mute_label(settings),
+            SettingKind::MapColors => map_colors_label(settings),
+            SettingKind::TextSize => text_size_label(settings),
+        }
+    }
+}
+
+/// Text of a Settings button or stepper showing a setting's value.
+#[derive(Component)]
+struct SettingLabel(SettingKind);
+
+// ── Colors ───────────────────────────────────────────────────────────
+
+const COLOR_BG: Color = Color::srgba(0.08, 0.08, 0.12, 1.0);
+const COLOR_BTN: Color = Color::srgba(0.18, 0.20, 0.28, 1.0);
+const COLOR_BTN_HOVER: Color = Color::srgba(0.28, 0.32, 0.42, 1.0);
+const COLOR_BTN_PRESS: Color = Color::srgba(0.12, 0.14, 0.20, 1.0);
+const COLOR_TEXT: Color = Color::WHITE;
+const COLOR_TEXT_DIM: Color = Color::srgba(0.5, 0.5, 0.5, 1.0);
+const COLOR_ACCENT: Color = Color::srgba(0.2, 0.7, 1.0, 1.0);
+
+// ── Plugin ───────────────────────────────────────────────────────────
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UserSettings>();
+        app.init_resource::<AudioSettings>();
+        app.init_resource::<KeyBindings>();
+        app.init_resource::<RebindState>();
+        app.add_systems(Startup, load_user_settings);
+
+        app.add_systems(OnEnter(GamePhase::Settings), spawn_settings_screen);
+        app.add_systems(OnExit(GamePhase::Settings), despawn::<SettingsRoot>);
+        app.add_systems(Update, settings_button_system.run_if(in_state(GamePhase::Settings)));
+        app.add_systems(Update, (apply_tick_rate, apply_audio_settings, apply_ui_scale));
+
+        app.add_systems(OnEnter(GamePhase::Controls), spawn_controls_screen);
+        app.add_systems(OnExit(GamePhase::Controls), (despawn::<ControlsRoot>, cancel_rebind));
+        app.add_systems(
+            Update,
+            (controls_button_system, capture_rebind_key, update_binding_labels)
+                .chain()
+                .run_if(in_state(GamePhase::Controls)),
+        );
+    }
+}
+
+// ── Generic despawn ──────────────────────────────────────────────────
+
+fn despawn<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+// ── Startup: load persisted preferences ──────────────────────────────
+
+fn load_user_settings(
+    mut settings: ResMut<UserSettings>,
+    mut bindings: ResMut<KeyBindings>,
+    mut tutorial: ResMut<TutorialState>,
+    selection: Option<ResMut<GameSelection>>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+        match repo.load_all_settings_sync(&rt.0) {
+            Ok(rows) => {
+                for (key, value) in rows {
+                    settings.apply(&key, &value);
+                    bindings.apply(&key, &value);
+                }
+            }
+            Err(e) => error!("Failed to load settings: {}", e),
+        }
+    }
+    if !settings.seen_tutorial {
+        tutorial.queue_all();
+    }
+    if let Some(mut selection) = selection {
+        selection.ai_difficulty = settings.ai_difficulty;
+    }
+}
+
+// ── Physics rate ─────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct TickRateState {
+    /// `dt` as last loaded from tuning.ron (startup or F5).
+    file_dt: f32,
+    /// `dt` this system last wrote.
+    applied: f32,
+}
+
+/// Keep `tuning.dt` and `Time<Fixed>` on the chosen physics rate. Every
+/// dt-based system reads `tuning.dt` each tick, so they follow the change.
+/// F5 reloads reset `dt` to the file value; the override is re-applied.
+fn apply_tick_rate(
+    settings: Res<UserSettings>,
+    mut tuning: ResMut<Tuning>,
+    mut fixed: ResMut<Time<Fixed>>,
+    mut state: Local<TickRateState>,
+) {
+    if !settings.is_changed() && !tuning.is_changed() {
+        return;
+    }
+    if tuning.dt != state.applied {
+        state.file_dt = tuning.dt;
+    }
+    let dt = match settings.tick_hz {
+        0 => state.file_dt,
+        hz => 1.0 / hz as f32,
+    };
+    if tuning.dt != dt {
+        tuning.dt = dt;
+    }
+    state.applied = dt;
+    if fixed.timestep().as_secs_f32() != dt {
+        fixed.set_timestep_seconds(dt as f64);
+        info!("Physics rate set to {:.0} Hz", 1.0 / dt);
+    }
+}
+
+// ── Audio ────────────────────────────────────────────────────────────
+
+/// Mirror the saved volume / mute into `AudioSettings`, read by every SFX spawn.
+fn apply_audio_settings(settings: Res<UserSettings>, mut audio: ResMut<AudioSettings>) {
+    if settings.is_changed() && *audio != settings.audio {
+        *audio = settings.audio;
+    }
+}
+
+// ── UI scale ─────────────────────────────────────────────────────────
+
+/// Mirror the saved UI size into Bevy's `UiScale`. It multiplies every UI
+/// `Val::Px` and font size, so input boxes and buttons grow with their text.
+fn apply_ui_scale(settings: Res<UserSettings>, mut ui_scale: ResMut<UiScale>) {
+    let scale = settings.ui_scale_percent() as f32 / 100.0;
+    if settings.is_changed() && ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// SETTINGS SCREEN
+// ═══════════════════════════════════════════════════════════════════════
+
+fn spawn_settings_screen(mut commands: Commands, settings: Res<UserSettings>) {
+    commands
+        .spawn((
+            SettingsRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(COLOR_BG),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Settings"),
+                TextFont { font_size: 48.0, ..default() },
+                TextColor(COLOR_ACCENT),
+                Node { margin: UiRect::bottom(Val::Px(24.0)), ..default() },
+            ));
+            spawn_settings_btn(parent, "Show Tutorial", SettingsButton::ShowTutorial);
+            spawn_settings_btn(parent, &pvp_flip_label(&settings), SettingsButton::TogglePvpFlip);
+            spawn_settings_btn(parent, &tick_rate_label(&settings), SettingsButton::CycleTickRate);
+            spawn_settings_btn(parent, &top_trails_label(&settings), SettingsButton::ToggleTopTrails);
+            spawn_settings_btn(parent, &camera_zoom_label(&settings), SettingsButton::CycleCameraZoom);
+            spawn_stepper_row(parent, &volume_label(&settings), SettingKind::Volume, SettingsButton::VolumeDown, SettingsButton::VolumeUp);
+            spawn_settings_btn(parent, &mute_label(&settings), SettingsButton::ToggleMute);
+            spawn_settings_btn(parent, &map_colors_label(&settings), SettingsButton::CycleColorBlind);
+            spawn_stepper_row(parent, &text_size_label(&settings), SettingKind::TextSize, SettingsButton::TextSmaller, SettingsButton::TextLarger);
+            spawn_settings_btn(parent, "Controls", SettingsButton::Controls);
+            parent.spawn((
+                SettingsStatusText,
+                Text::new(""),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(COLOR_TEXT_DIM),
+            ));
+            spawn_settings_btn(parent, "Back", SettingsButton::Back);
+        });
+}
+
+fn settings_button_system(
+    mut q: Query<(&Interaction, &SettingsButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut status: Query<&mut Text, (With<SettingsStatusText>, Without<SettingLabel>)>,
+    mut labels: Query<(&SettingLabel, &mut Text), Without<SettingsStatusText>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    mut settings: ResMut<UserSettings>,
+    mut tutorial: ResMut<TutorialState>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg = BackgroundColor(COLOR_BTN_PRESS);
+                let mut changed = false;
+                match button {
+                    SettingsButton::ShowTutorial => {
+                        settings.seen_tutorial = false;
+                        tutorial.queue_all();
+                        changed = true;
+                        for mut text in &mut status {
+                            **text = "Tutorial will show in your next battle and design hub visit.".into();
+                        }
+                    }
+                    SettingsButton::TogglePvpFlip => {
+                        settings.pvp_camera_flip = !settings.pvp_camera_flip;
+                        changed = true;
+                    }
+                    SettingsButton::CycleTickRate => {
+                        let i = TICK_RATES.iter().position(|&hz| hz == settings.tick_hz).unwrap_or(0);
+                        settings.tick_hz = TICK_RATES[(i + 1) % TICK_RATES.len()];
+                        changed = true;
+                        for mut text in &mut status {
+                            **text = "Higher rates give smoother physics but use more CPU.".into();
+                        }
+                    }
+                    SettingsButton::ToggleTopTrails => {
+                        settings.top_trails = !settings.top_trails;
+                        changed = true;
+                    }
+                    SettingsButton::CycleCameraZoom => {
+                        let i = CAMERA_ZOOMS.iter().position(|&pct| pct == settings.camera_zoom).unwrap_or(0);
+                        settings.camera_zoom = CAMERA_ZOOMS[(i + 1) % CAMERA_ZOOMS.len()];
+                        changed = true;
+                        for mut text in &mut status {
+                            **text = "Press R in battle to return to this view.".into();
+                        }
+                    }
+                    SettingsButton::VolumeDown | SettingsButton::VolumeUp => {
+                        let step = if matches!(button, SettingsButton::VolumeUp) { VOLUME_STEP } else { -VOLUME_STEP };
+                        // Round to whole steps so repeated presses don't drift
+                        let master = ((settings.audio.master + step) / VOLUME_STEP).round() * VOLUME_STEP;
+                        settings.audio.master = master.clamp(0.0, 1.0);
+                        changed = true;
+                    }
+                    SettingsButton::ToggleMute => {
+                        settings.audio.muted = !settings.audio.muted;
+                        changed = true;
+                    }
+                    SettingsButton::TextSmaller | SettingsButton::TextLarger => {
+                        let pct = if matches!(button, SettingsButton::TextLarger) {
+                            settings.ui_scale_percent() + UI_SCALE_STEP
+                        } else {
+                            settings.ui_scale_percent().saturating_sub(UI_SCALE_STEP)
+                        };
+                        settings.ui_scale = pct.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+                        changed = true;
+                    }
+                    SettingsButton::CycleColorBlind => {
+                        let i = ColorBlindMode::ALL.iter().position(|&m| m == settings.color_blind).unwrap_or(0);
+                        settings.color_blind = ColorBlindMode::ALL[(i + 1) % ColorBlindMode::ALL.len()];
+                        changed = true;
+                        for mut text in &mut status {
+                            **text = "High contrast also labels map items with a letter in the map editor.".into();
+                        }
+                    }
+                    SettingsButton::Controls => next_state.set(GamePhase::Controls),
+                    SettingsButton::Back => next_state.set(GamePhase::MainMenu),
+                }
+                if !changed {
+                    continue;
+                }
+                if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                    settings.save(repo, &rt.0);
+                }
+                for (label, mut text) in &mut labels {
+                    let value = label.0.label(&settings);
+                    if **text != value {
+                        **text = value;
+                    }
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+        }
+    }
+}
+
+fn pvp_flip_label(settings: &UserSettings) -> String {
+    format!("PvP Camera Flip: {}", if settings.pvp_camera_flip { "On" } else { "Off" })
+}
+
+fn tick_rate_label(settings: &UserSettings) -> String {
+    match settings.tick_hz {
+        0 => "Physics Rate: tuning.ron".into(),
+        hz => format!("Physics Rate: {} Hz", hz),
+    }
+}
+
+fn top_trails_label(settings: &UserSettings) -> String {
+    format!("Motion Trails: {}", if settings.top_trails { "On" } else { "Off" })
+}
+
+fn camera_zoom_label(settings: &UserSettings) -> String {
+    match settings.camera_zoom {
+        0 => "Battle Zoom: Fit Arena".into(),
+        pct => format!("Battle Zoom: {}%", pct),
+    }
+}
+
+fn volume_label(settings: &UserSettings) -> String {
+    format!("Volume: {:.0}%", settings.audio.master * 100.0)
+}
+
+fn mute_label(settings: &UserSettings) -> String {
+    format!("Sound: {}", if settings.audio.muted { "Muted" } else { "On" })
+}
+
+fn map_colors_label(settings: &UserSettings) -> String {
+    format!("Map Colors: {}", settings.color_blind.label())
+}
+
+fn text_size_label(settings: &UserSettings) -> String {
+    format!("Text Size: {}%", settings.ui_scale_percent())
+}
+
+/// "-  Label  +" row, e.g. Volume; the label between the buttons is not clickable.
+fn spawn_stepper_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    setting: SettingKind,
+    down: SettingsButton,
+    up: SettingsButton,
+) {
+    parent.spawn(Node {
+        width: Val::Px(360.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|row| {
+        spawn_step_btn(row, "-", down);
+        row.spawn((
+            SettingLabel(setting),
+            Text::new(label),
+            TextFont { font_size: 24.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+        spawn_step_btn(row, "+", up);
+    });
+}
+
+fn spawn_step_btn(parent: &mut ChildSpawnerCommands, label: &str, marker: SettingsButton) {
+    parent.spawn((
+        marker,
+        Button,
+        Node {
+            width: Val::Px(56.0),
+            height: Val::Px(56.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BTN),
+    )).with_children(|btn| {
+        btn.spawn((
+            Text::new(label),
+            TextFont { font_size: 28.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+    });
+}
+
+fn spawn_settings_btn(parent: &mut ChildSpawnerCommands, label: &str, marker: SettingsButton) {
+    let setting = match marker {
+        SettingsButton::TogglePvpFlip => Some(SettingKind::PvpFlip),
+        SettingsButton::CycleTickRate => Some(SettingKind::TickRate),
+        SettingsButton::ToggleTopTrails => Some(SettingKind::TopTrails),
+        SettingsButton::CycleCameraZoom => Some(SettingKind::CameraZoom),
+        SettingsButton::ToggleMute => Some(SettingKind::Mute),
+        SettingsButton::CycleColorBlind => Some(SettingKind::MapColors),
+        _ => None,
+    };
+    parent.spawn((
+        marker,
+        Button,
+        Node {
+            width: Val::Px(360.0),
+            height: Val::Px(56.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BTN),
+    )).with_children(|btn| {
+        let mut text = btn.spawn((
+            Text::new(label),
+            TextFont { font_size: 24.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+        if let Some(setting) = setting {
+            text.insert(SettingLabel(setting));
+        }
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// CONTROLS SCREEN
+// ═══════════════════════════════════════════════════════════════════════
+
+fn spawn_controls_screen(mut commands: Commands, bindings: Res<KeyBindings>) {
+    commands
+        .spawn((
+            ControlsRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(COLOR_BG),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Controls"),
+                TextFont { font_size: 48.0, ..default() },
+                TextColor(COLOR_ACCENT),
+                Node { margin: UiRect::bottom(Val::Px(24.0)), ..default() },
+            ));
+            for action in BindAction::ALL {
+                spawn_controls_btn(parent, &binding_label(&bindings, action, None), ControlsButton::Rebind(action));
+            }
+            parent.spawn((
+                ControlsStatusText,
+                Text::new("Click an action, then press the key to use."),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(COLOR_TEXT_DIM),
+            ));
+            spawn_controls_btn(parent, "Reset Defaults", ControlsButton::ResetDefaults);
+            spawn_controls_btn(parent, "Back", ControlsButton::Back);
+        });
+}
+
+fn controls_button_system(
+    mut q: Query<(&Interaction, &ControlsButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    mut bindings: ResMut<KeyBindings>,
+    mut rebind: ResMut<RebindState>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    for (interaction, button, mut bg) in &mut q {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg = BackgroundColor(COLOR_BTN_PRESS);
+                match button {
+                    ControlsButton::Rebind(action) => rebind.waiting = Some(*action),
+                    ControlsButton::ResetDefaults => {
+                        rebind.waiting = None;
+                        *bindings = KeyBindings::default();
+                        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                            bindings.save(repo, &rt.0);
+                        }
+                    }
+                    ControlsButton::Back => next_state.set(GamePhase::Settings),
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+        }
+    }
+}
+
+/// While an action is selected, the next bindable key press becomes its
+/// binding; Escape cancels. Without a selection, Escape leaves the screen.
+fn capture_rebind_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut rebind: ResMut<RebindState>,
+    mut bindings: ResMut<KeyBindings>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    let Some(action) = rebind.waiting else {
+        if keyboard.just_pressed(KeyCode::Escape) {
+            next_state.set(GamePhase::Settings);
+        }
+        return;
+    };
+    if keyboard.just_pressed(KeyCode::Escape) {
+        rebind.waiting = None;
+        return;
+    }
+    let Some(key) = keyboard.get_just_pressed().copied().find(|k| BINDABLE_KEYS.contains(k)) else {
+        return;
+    };
+    bindings.bind(action, key);
+    rebind.waiting = None;
+    if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+        bindings.save(repo, &rt.0);
+    }
+}
+
+fn update_binding_labels(
+    bindings: Res<KeyBindings>,
+    rebind: Res<RebindState>,
+    mut labels: Query<(&BindingLabel, &mut Text), Without<ControlsStatusText>>,
+    mut status: Query<&mut Text, (With<ControlsStatusText>, Without<BindingLabel>)>,
+) {
+    if !bindings.is_changed() && !rebind.is_changed() {
+        return;
+    }
+    for (label, mut text) in &mut labels {
+        **text = binding_label(&bindings, label.0, rebind.waiting);
+    }
+    for mut text in &mut status {
+        **text = match rebind.waiting {
+            Some(action) => format!("Press a key for {} (Esc to cancel).", action.label()),
+            None => "Click an action, then press the key to use.".into(),
+        };
+    }
+}
+
+fn cancel_rebind(mut rebind: ResMut<RebindState>) {
+    rebind.waiting = None;
+}
+
+fn binding_label(bindings: &KeyBindings, action: BindAction, waiting: Option<BindAction>) -> String {
+    if waiting == Some(action) {
+        format!("{}: ...", action.label())
+    } else {
+        format!("{}: {}", action.label(), key_label(bindings.get(action)))
+    }
+}
+
+fn spawn_controls_btn(parent: &mut ChildSpawnerCommands, label: &str, marker: ControlsButton) {
+    let action = match marker {
+        ControlsButton::Rebind(action) => Some(action),
+        _ => None,
+    };
+    parent.spawn((
+        marker,
+        Button,
+        Node {
+            width: Val::Px(360.0),
+            height: Val::Px(48.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BTN),
+    )).with_children(|btn| {
+        let mut text = btn.spawn((
+            Text::new(label),
+            TextFont { font_size: 22.0, ..default() },
+            TextColor(COLOR_TEXT),
+        ));
+        if let Some(action) = action {
+            text.insert(BindingLabel(action));
+        }
+    });
+}
@@ -0,0 +1,282 @@
+use bevy::prelude::*;
+
+use crate::game::components::GamePhase;
+use crate::plugins::settings_plugin::{key_label, KeyBindings, UserSettings};
+use crate::plugins::storage_plugin::TokioRuntime;
+use crate::storage::sqlite_repo::SqliteRepo;
+
+// ── Data types ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TutorialTopic {
+    Aiming,
+    DesignHub,
+}
+
+/// (title, body) pairs shown one at a time for each topic.
+/// The first Aiming body is left empty: `card_text` fills it from `KeyBindings`.
+const AIM_CARDS: &[(&str, &str)] = &[
+    ("Aiming", ""),
+    (
+        "Launch",
+        "Once every top has locked in, they launch along their arrows.\n\
+         Hits, weapons and the arena wall drain spin.\n\
+         The first top to run out of spin loses.",
+    ),
+];
+
+const DESIGN_CARDS: &[(&str, &str)] = &[
+    (
+        "Design Hub",
+        "Create your own parts here. Wheels set HP and size, weapons deal damage,\n\
+         shafts, chassis and screws tune stability, speed and traits.",
+    ),
+    (
+        "Builds",
+        "Open \"My Parts & Builds\" to assemble one part per slot into a build.\n\
+         Saved builds show up in the game setup picker.",
+    ),
+];
+
+fn cards(topic: TutorialTopic) -> &'static [(&'static str, &'static str)] {
+    match topic {
+        TutorialTopic::Aiming => AIM_CARDS,
+        TutorialTopic::DesignHub => DESIGN_CARDS,
+    }
+}
+
+/// Title and body of one card, with the aiming controls named from the current bindings.
+fn card_text(topic: TutorialTopic, card: usize, keys: &KeyBindings) -> Option<(&'static str, String)> {
+    let &(title, body) = cards(topic).get(card)?;
+    if topic == TutorialTopic::Aiming && card == 0 {
+        let body = format!(
+            "Player 1: rotate with {} / {}, press {} to lock in.\n\
+             Player 2 (PvP): rotate with {} / {}, press {} to lock in.",
+            key_label(keys.p1_left),
+            key_label(keys.p1_right),
+            key_label(keys.p1_confirm),
+            key_label(keys.p2_left),
+            key_label(keys.p2_right),
+            key_label(keys.p2_confirm),
+        );
+        return Some((title, body));
+    }
+    Some((title, body.into()))
+}
+
+/// Which tutorial topics still need to be shown this session.
+/// Seeded from `UserSettings::seen_tutorial` at startup.
+#[derive(Resource, Default)]
+pub struct TutorialState {
+    aim_pending: bool,
+    design_pending: bool,
+    /// Index into the current topic's cards while an overlay is open.
+    card: usize,
+}
+
+impl TutorialState {
+    /// Queue every topic (first run, or "Show Tutorial" in settings).
+    pub fn queue_all(&mut self) {
+        self.aim_pending = true;
+        self.design_pending = true;
+    }
+
+    fn finish(&mut self, topic: TutorialTopic) {
+        match topic {
+            TutorialTopic::Aiming => self.aim_pending = false,
+            TutorialTopic::DesignHub => self.design_pending = false,
+        }
+    }
+
+    fn all_seen(&self) -> bool {
+        !self.aim_pending && !self.design_pending
+    }
+}
+
+// ── Marker components ────────────────────────────────────────────────
+
+#[derive(Component)]
+pub struct TutorialOverlay(TutorialTopic);
+
+#[derive(Component)]
+struct TutorialTitle;
+
+#[derive(Component)]
+struct TutorialBody;
+
+#[derive(Component)]
+struct TutorialNextLabel;
+
+#[derive(Component)]
+struct TutorialNextButton;
+
+// ── Colors ───────────────────────────────────────────────────────────
+
+const COLOR_CARD: Color = Color::srgba(0.12, 0.14, 0.20, 0.97);
+const COLOR_BTN: Color = Color::srgba(0.18, 0.20, 0.28, 1.0);
+const COLOR_BTN_HOVER: Color = Color::srgba(0.28, 0.32, 0.42, 1.0);
+const COLOR_TEXT: Color = Color::WHITE;
+const COLOR_TEXT_DIM: Color = Color::srgba(0.7, 0.7, 0.75, 1.0);
+const COLOR_ACCENT: Color = Color::srgba(0.2, 0.7, 1.0, 1.0);
+
+// ── Plugin ───────────────────────────────────────────────────────────
+
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialState>();
+
+        app.add_systems(OnEnter(GamePhase::Aiming), spawn_aim_tutorial);
+        app.add_systems(OnExit(GamePhase::Aiming), despawn_tutorial);
+        app.add_systems(OnEnter(GamePhase::DesignHub), spawn_design_tutorial);
+        app.add_systems(OnExit(GamePhase::DesignHub), despawn_tutorial);
+        app.add_systems(Update, tutorial_button_system);
+    }
+}
+
+/// Run condition: true while no tutorial card is on screen.
+/// Used to hold aiming input until the player has read the card.
+pub fn tutorial_closed(overlays: Query<(), With<TutorialOverlay>>) -> bool {
+    overlays.is_empty()
+}
+
+fn despawn_tutorial(mut commands: Commands, query: Query<Entity, With<TutorialOverlay>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+// ── Spawning ─────────────────────────────────────────────────────────
+
+fn spawn_aim_tutorial(mut commands: Commands, mut state: ResMut<TutorialState>, bindings: Res<KeyBindings>) {
+    if state.aim_pending {
+        state.card = 0;
+        spawn_tutorial_card(&mut commands, TutorialTopic::Aiming, &bindings);
+    }
+}
+
+fn spawn_design_tutorial(mut commands: Commands, mut state: ResMut<TutorialState>, bindings: Res<KeyBindings>) {
+    if state.design_pending {
+        state.card = 0;
+        spawn_tutorial_card(&mut commands, TutorialTopic::DesignHub, &bindings);
+    }
+}
+
+fn spawn_tutorial_card(commands: &mut Commands, topic: TutorialTopic, keys: &KeyBindings) {
+    let deck = cards(topic);
+    let Some((title, body)) = card_text(topic, 0, keys) else { return };
+    commands
+        .spawn((
+            TutorialOverlay(topic),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GlobalZIndex(20),
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                Node {
+                    width: Val::Px(640.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    border_radius: BorderRadius::all(Val::Px(10.0)),
+                    ..default()
+                },
+                BackgroundColor(COLOR_CARD),
+            )).with_children(|card| {
+                card.spawn((
+                    TutorialTitle,
+                    Text::new(title),
+                    TextFont { font_size: 32.0, ..default() },
+                    TextColor(COLOR_ACCENT),
+                ));
+                card.spawn((
+                    TutorialBody,
+                    Text::new(body),
+                    TextFont { font_size: 18.0, ..default() },
+                    TextColor(COLOR_TEXT_DIM),
+                ));
+                card.spawn((
+                    TutorialNextButton,
+                    Button,
+                    Node {
+                        width: Val::Px(160.0),
+                        height: Val::Px(44.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(COLOR_BTN),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        TutorialNextLabel,
+                        Text::new(next_label(deck, 0)),
+                        TextFont { font_size: 20.0, ..default() },
+                        TextColor(COLOR_TEXT),
+                    ));
+                });
+            });
+        });
+}
+
+fn next_label(deck: &[(&str, &str)], card: usize) -> &'static str {
+    if card + 1 >= deck.len() { "Got it" } else { "Next" }
+}
+
+// ── Interaction ──────────────────────────────────────────────────────
+
+fn tutorial_button_system(
+    mut commands: Commands,
+    mut q: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<TutorialNextButton>)>,
+    overlays: Query<(Entity, &TutorialOverlay)>,
+    mut title: Query<&mut Text, (With<TutorialTitle>, Without<TutorialBody>, Without<TutorialNextLabel>)>,
+    mut body: Query<&mut Text, (With<TutorialBody>, Without<TutorialTitle>, Without<TutorialNextLabel>)>,
+    mut label: Query<&mut Text, (With<TutorialNextLabel>, Without<TutorialTitle>, Without<TutorialBody>)>,
+    mut state: ResMut<TutorialState>,
+    mut settings: ResMut<UserSettings>,
+    bindings: Res<KeyBindings>,
+    repo: Option<Res<SqliteRepo>>,
+    rt: Option<Res<TokioRuntime>>,
+) {
+    for (interaction, mut bg) in &mut q {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some((entity, overlay)) = overlays.iter().next() else { continue };
+                let deck = cards(overlay.0);
+                state.card += 1;
+                if let Some((t, b)) = card_text(overlay.0, state.card, &bindings) {
+                    for mut text in &mut title {
+                        **text = t.into();
+                    }
+                    for mut text in &mut body {
+                        **text = b.clone();
+                    }
+                    for mut text in &mut label {
+                        **text = next_label(deck, state.card).into();
+                    }
+                } else {
+                    commands.entity(entity).despawn();
+                    state.finish(overlay.0);
+                    if state.all_seen() && !settings.seen_tutorial {
+                        settings.seen_tutorial = true;
+                        if let (Some(repo), Some(rt)) = (repo.as_ref(), rt.as_ref()) {
+                            settings.save(repo, &rt.0);
+                        }
+                    }
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(COLOR_BTN_HOVER),
+            Interaction::None => *bg = BackgroundColor(COLOR_BTN),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
+use std::collections::HashSet;
 
 use crate::game::components::*;
+use crate::plugins::menu_plugin::GameSelection;
+use crate::plugins::settings_plugin::{key_label, KeyBindings};
 
 pub struct UiPlugin;
 
@@ -9,7 +12,7 @@ impl Plugin for UiPlugin {
         app.add_systems(OnEnter(GamePhase::Aiming), setup_ui);
         app.add_systems(
             Update,
-            (update_hp_display, update_phase_display)
+            (update_hp_display, update_phase_display, update_minimap, update_pause_overlay)
                 .run_if(in_state(GamePhase::Aiming).or(in_state(GamePhase::Battle)).or(in_state(GamePhase::GameOver))),
         );
     }
@@ -21,6 +24,20 @@ struct HpText;
 #[derive(Component)]
 struct PhaseText;
 
+/// "PAUSED" banner shown while a battle has `Time<Virtual>` paused.
+#[derive(Component)]
+struct PauseOverlay;
+
+/// Corner minimap frame (arena outline). Dots are spawned as its children.
+#[derive(Component)]
+struct Minimap;
+
+/// Minimap dot mirroring the position of a top or map item.
+#[derive(Component)]
+struct MinimapDot(Entity);
+
+pub(crate) const MINIMAP_SIZE: f32 = 150.0;
+
 fn setup_ui(mut commands: Commands) {
     commands
         .spawn((
@@ -56,6 +73,132 @@ fn setup_ui(mut commands: Commands) {
                 TextColor(Color::srgb(0.8, 0.8, 0.2)),
             ));
         });
+
+    commands
+        .spawn((
+            InGame,
+            PauseOverlay,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+            GlobalZIndex(10),
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("PAUSED"),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 1.0, 0.0)),
+            ));
+            parent.spawn((
+                Text::new("P: resume    N: step one tick"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+
+    commands.spawn((
+        InGame,
+        Minimap,
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(10.0),
+            width: Val::Px(MINIMAP_SIZE),
+            height: Val::Px(MINIMAP_SIZE),
+            border: UiRect::all(Val::Px(2.0)),
+            border_radius: BorderRadius::MAX,
+            ..default()
+        },
+        BorderColor::all(Color::srgba(0.6, 0.6, 0.7, 0.8)),
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.6)),
+    ));
+}
+
+/// Keep one dot per top / map item, positioned from its world transform.
+fn update_minimap(
+    mut commands: Commands,
+    arena: Option<Res<ArenaRadius>>,
+    minimap: Query<Entity, With<Minimap>>,
+    tracked: Query<
+        (
+            Entity,
+            &Transform,
+            Has<Top>,
+            Has<PlayerControlled>,
+            Option<&MapItemTag>,
+        ),
+        Or<(With<Top>, With<MapItemTag>)>,
+    >,
+    mut dots: Query<(Entity, &MinimapDot, &mut Node)>,
+) {
+    let (Some(arena), Ok(frame)) = (arena, minimap.single()) else { return };
+    let half = MINIMAP_SIZE * 0.5;
+    let to_px = |pos: Vec2, size: f32| {
+        let p = pos / arena.0.max(0.01) * half;
+        (half + p.x - size * 0.5, half - p.y - size * 0.5)
+    };
+
+    let mut has_dot = HashSet::new();
+    for (dot_entity, dot, mut node) in &mut dots {
+        let Ok((_, tf, ..)) = tracked.get(dot.0) else {
+            commands.entity(dot_entity).despawn();
+            continue;
+        };
+        has_dot.insert(dot.0);
+        // Only touch the node when the dot actually moved
+        let size = if let Val::Px(w) = node.width { w } else { 0.0 };
+        let (left, top) = to_px(tf.translation.truncate(), size);
+        let (left, top) = (Val::Px(left), Val::Px(top));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+    }
+
+    for (entity, tf, is_top, is_p1, item) in &tracked {
+        if has_dot.contains(&entity) {
+            continue;
+        }
+        let (size, color) = match item {
+            _ if is_top => {
+                let color = if is_p1 { Color::srgb(0.2, 1.0, 0.2) } else { Color::srgb(1.0, 0.4, 0.2) };
+                (8.0, color)
+            }
+            Some(tag) => (4.0, tag.0.color()),
+            None => continue,
+        };
+        let (left, top) = to_px(tf.translation.truncate(), size);
+        let dot = commands.spawn((
+            MinimapDot(entity),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(left),
+                top: Val::Px(top),
+                width: Val::Px(size),
+                height: Val::Px(size),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            BackgroundColor(color),
+            ZIndex(if is_top { 1 } else { 0 }),
+        )).id();
+        commands.entity(frame).add_child(dot);
+    }
 }
 
 fn update_hp_display(
@@ -118,15 +261,59 @@ fn update_hp_display(
 
 fn update_phase_display(
     state: Res<State<GamePhase>>,
+    selection: Option<Res<GameSelection>>,
+    score: Option<Res<RoundScore>>,
+    timer: Option<Res<MatchTimer>>,
+    tuning: Option<Res<crate::config::tuning::Tuning>>,
+    bindings: Option<Res<KeyBindings>>,
     mut text_query: Query<&mut Text, With<PhaseText>>,
 ) {
     let phase_str = match state.get() {
-        GamePhase::Aiming => "Arrows to aim, Space to launch (P2: A/D + Enter)",
-        GamePhase::Battle => "Battle!",
-        GamePhase::GameOver => "Game Over",
-        _ => "",
+        GamePhase::Aiming => {
+            let keys = bindings.map(|b| *b).unwrap_or_default();
+            format!(
+                "{}/{} to aim, {} to launch (P2: {}/{} + {})",
+                key_label(keys.p1_left),
+                key_label(keys.p1_right),
+                key_label(keys.p1_confirm),
+                key_label(keys.p2_left),
+                key_label(keys.p2_right),
+                key_label(keys.p2_confirm),
+            )
+        }
+        GamePhase::Battle => {
+            let limited = tuning.is_some_and(|t| t.match_time_limit > 0.0);
+            match timer.filter(|_| limited) {
+                Some(timer) if timer.sudden_death => "SUDDEN DEATH!  (P to pause)".to_string(),
+                Some(timer) => {
+                    let secs = timer.remaining.ceil() as u32;
+                    format!("Battle!  {}:{:02}  (P to pause)", secs / 60, secs % 60)
+                }
+                None => "Battle!  (P to pause)".to_string(),
+            }
+        }
+        GamePhase::GameOver => "Game Over".to_string(),
+        _ => String::new(),
+    };
+    // Best-of series: prefix the round and running score
+    let round = match (selection, score) {
+        (Some(selection), Some(score)) if selection.best_of > 1 && *state.get() != GamePhase::GameOver => {
+            format!("Round {} ({} - {})  ", score.round(), score.p1, score.p2)
+        }
+        _ => String::new(),
     };
     for mut text in &mut text_query {
-        **text = phase_str.to_string();
+        **text = format!("{}{}", round, phase_str);
+    }
+}
+
+fn update_pause_overlay(
+    state: Res<State<GamePhase>>,
+    virtual_time: Res<Time<Virtual>>,
+    mut overlay: Query<&mut Visibility, With<PauseOverlay>>,
+) {
+    let shown = virtual_time.is_paused() && *state.get() == GamePhase::Battle;
+    for mut visibility in &mut overlay {
+        *visibility = if shown { Visibility::Visible } else { Visibility::Hidden };
     }
 }
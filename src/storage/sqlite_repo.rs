@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
 use std::path::PathBuf;
 
+use crate::game::parts::registry::SlotLocks;
 use crate::game::parts::Build;
 
 /// SQLite-backed repository (Bevy Resource).
@@ -28,16 +29,18 @@ impl SqliteRepo {
         Ok(Self { pool })
     }
 
-    pub async fn save_build_async(&self, build: &Build) -> Result<(), sqlx::Error> {
+    /// Insert or replace a build together with its slot locks (stored as JSON).
+    pub async fn save_build_async(&self, build: &Build, locks: &SlotLocks) -> Result<(), sqlx::Error> {
         let weapon_id = &build.weapon.id;
         let shaft_id = &build.shaft.id;
         let chassis_id = &build.chassis.id;
         let screw_id = &build.screw.id;
         let note = build.note.as_deref().unwrap_or("");
+        let locks_json = serde_json::to_string(locks).unwrap_or_else(|_| "{}".into());
 
         sqlx::query(
-            r#"INSERT OR REPLACE INTO builds (id, top_id, weapon_id, shaft_id, chassis_id, screw_id, note)
-               VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+            r#"INSERT OR REPLACE INTO builds (id, top_id, weapon_id, shaft_id, chassis_id, screw_id, note, locks_json)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(&build.id)
         .bind(&build.wheel.id)
@@ -46,12 +49,24 @@ impl SqliteRepo {
         .bind(chassis_id)
         .bind(screw_id)
         .bind(note)
+        .bind(locks_json)
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
+    /// Update only a build's display name (stored in `note`).
+    pub async fn rename_build_async(&self, id: &str, name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE builds SET note = ? WHERE id = ?")
+            .bind(name)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     // ── Part CRUD (async) ──────────────────────────────────────────────
 
     pub async fn save_part_async(
@@ -96,9 +111,9 @@ impl SqliteRepo {
 
     pub async fn load_all_builds_async(
         &self,
-    ) -> Result<Vec<(String, String, String, String, String, String, String)>, sqlx::Error> {
-        let rows: Vec<(String, String, String, String, String, String, String)> = sqlx::query_as(
-            "SELECT id, top_id, weapon_id, shaft_id, chassis_id, screw_id, COALESCE(note, '') FROM builds",
+    ) -> Result<Vec<(String, String, String, String, String, String, String, String)>, sqlx::Error> {
+        let rows: Vec<(String, String, String, String, String, String, String, String)> = sqlx::query_as(
+            "SELECT id, top_id, weapon_id, shaft_id, chassis_id, screw_id, COALESCE(note, ''), locks_json FROM builds",
         )
         .fetch_all(&self.pool)
         .await?;
@@ -113,6 +128,18 @@ impl SqliteRepo {
         Ok(())
     }
 
+    /// Delete several parts and builds in one transaction.
+    pub async fn delete_many_async(&self, part_ids: &[String], build_ids: &[String]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        for id in build_ids {
+            sqlx::query("DELETE FROM builds WHERE id = ?").bind(id).execute(&mut *tx).await?;
+        }
+        for id in part_ids {
+            sqlx::query("DELETE FROM parts WHERE id = ?").bind(id).execute(&mut *tx).await?;
+        }
+        tx.commit().await
+    }
+
     // ── Map CRUD (async) ──────────────────────────────────────────────
 
     pub async fn save_map_async(
@@ -121,14 +148,16 @@ impl SqliteRepo {
         name: &str,
         arena_radius: f32,
         placements_json: &str,
+        options_json: &str,
     ) -> Result<(), sqlx::Error> {
         sqlx::query(
-            "INSERT OR REPLACE INTO maps (id, name, arena_radius, placements_json) VALUES (?, ?, ?, ?)",
+            "INSERT OR REPLACE INTO maps (id, name, arena_radius, placements_json, options_json) VALUES (?, ?, ?, ?, ?)",
         )
         .bind(id)
         .bind(name)
         .bind(arena_radius as f64)
         .bind(placements_json)
+        .bind(options_json)
         .execute(&self.pool)
         .await?;
         Ok(())
@@ -136,9 +165,9 @@ impl SqliteRepo {
 
     pub async fn load_all_maps_async(
         &self,
-    ) -> Result<Vec<(String, String, f64, String)>, sqlx::Error> {
-        let rows: Vec<(String, String, f64, String)> = sqlx::query_as(
-            "SELECT id, name, arena_radius, placements_json FROM maps",
+    ) -> Result<Vec<(String, String, f64, String, String)>, sqlx::Error> {
+        let rows: Vec<(String, String, f64, String, String)> = sqlx::query_as(
+            "SELECT id, name, arena_radius, placements_json, options_json FROM maps",
         )
         .fetch_all(&self.pool)
         .await?;
@@ -153,6 +182,24 @@ impl SqliteRepo {
         Ok(())
     }
 
+    // ── Settings (async) ──────────────────────────────────────────────
+
+    pub async fn save_setting_async(&self, key: &str, value: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("INSERT OR REPLACE INTO settings (key, value) VALUES (?, ?)")
+            .bind(key)
+            .bind(value)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn load_all_settings_async(&self) -> Result<Vec<(String, String)>, sqlx::Error> {
+        let rows: Vec<(String, String)> = sqlx::query_as("SELECT key, value FROM settings")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows)
+    }
+
     // ── Sync wrappers (use TokioRuntime resource) ──────────────────────
 
     pub fn save_part_sync(
@@ -189,15 +236,26 @@ impl SqliteRepo {
         &self,
         rt: &tokio::runtime::Runtime,
         build: &Build,
+        locks: &SlotLocks,
+    ) -> Result<(), String> {
+        rt.block_on(self.save_build_async(build, locks))
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn rename_build_sync(
+        &self,
+        rt: &tokio::runtime::Runtime,
+        id: &str,
+        name: &str,
     ) -> Result<(), String> {
-        rt.block_on(self.save_build_async(build))
+        rt.block_on(self.rename_build_async(id, name))
             .map_err(|e| e.to_string())
     }
 
     pub fn load_all_builds_sync(
         &self,
         rt: &tokio::runtime::Runtime,
-    ) -> Result<Vec<(String, String, String, String, String, String, String)>, String> {
+    ) -> Result<Vec<(String, String, String, String, String, String, String, String)>, String> {
         rt.block_on(self.load_all_builds_async())
             .map_err(|e| e.to_string())
     }
@@ -211,6 +269,16 @@ impl SqliteRepo {
             .map_err(|e| e.to_string())
     }
 
+    pub fn delete_many_sync(
+        &self,
+        rt: &tokio::runtime::Runtime,
+        part_ids: &[String],
+        build_ids: &[String],
+    ) -> Result<(), String> {
+        rt.block_on(self.delete_many_async(part_ids, build_ids))
+            .map_err(|e| e.to_string())
+    }
+
     pub fn save_map_sync(
         &self,
         rt: &tokio::runtime::Runtime,
@@ -218,15 +286,16 @@ impl SqliteRepo {
         name: &str,
         arena_radius: f32,
         placements_json: &str,
+        options_json: &str,
     ) -> Result<(), String> {
-        rt.block_on(self.save_map_async(id, name, arena_radius, placements_json))
+        rt.block_on(self.save_map_async(id, name, arena_radius, placements_json, options_json))
             .map_err(|e| e.to_string())
     }
 
     pub fn load_all_maps_sync(
         &self,
         rt: &tokio::runtime::Runtime,
-    ) -> Result<Vec<(String, String, f64, String)>, String> {
+    ) -> Result<Vec<(String, String, f64, String, String)>, String> {
         rt.block_on(self.load_all_maps_async())
             .map_err(|e| e.to_string())
     }
@@ -239,4 +308,22 @@ impl SqliteRepo {
         rt.block_on(self.delete_map_async(id))
             .map_err(|e| e.to_string())
     }
+
+    pub fn save_setting_sync(
+        &self,
+        rt: &tokio::runtime::Runtime,
+        key: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        rt.block_on(self.save_setting_async(key, value))
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn load_all_settings_sync(
+        &self,
+        rt: &tokio::runtime::Runtime,
+    ) -> Result<Vec<(String, String)>, String> {
+        rt.block_on(self.load_all_settings_async())
+            .map_err(|e| e.to_string())
+    }
 }